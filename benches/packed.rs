@@ -0,0 +1,87 @@
+// cargo bench --bench packed
+// 比较 Vec<Number> 与 Vec<PackedNumber> 的内存占用，以及在两种存储上求和、逐项相乘的耗时。
+// PackedNumber 只改变存储，运算仍由 Number 完成，两列的耗时应当接近
+#![cfg_attr(
+    not(any(feature = "bigint", feature = "extended")),
+    allow(clippy::clone_on_copy)
+)]
+use std::hint::black_box;
+use std::mem::size_of;
+use std::time::{Duration, Instant};
+
+use my_r_num::{Number, PackedNumber};
+
+const LEN: usize = 1_000_000;
+const ROUNDS: u32 = 10;
+
+fn time(mut f: impl FnMut()) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    // 整数、浮点数和小数混合的一列
+    let numbers: Vec<Number> = (0..LEN)
+        .map(|i| match i % 3 {
+            0 => Number::from(i as i32 - 500_000),
+            1 => Number::from(i as f32 * 0.5),
+            _ => Number::decimal(i as i128, 2),
+        })
+        .collect();
+    let packed: Vec<PackedNumber> = numbers
+        .iter()
+        .map(|n| PackedNumber::try_from(n.clone()).unwrap())
+        .collect();
+
+    println!(
+        "memory per value: Number {} bytes, PackedNumber {} bytes",
+        size_of::<Number>(),
+        size_of::<PackedNumber>()
+    );
+    println!(
+        "memory for {} values: Number {} KiB, PackedNumber {} KiB",
+        LEN,
+        LEN * size_of::<Number>() / 1024,
+        LEN * size_of::<PackedNumber>() / 1024
+    );
+
+    let sum_numbers = time(|| {
+        let total = numbers
+            .iter()
+            .fold(Number::from(0), |sum, v| sum + v.clone());
+        black_box(total);
+    });
+    let sum_packed = time(|| {
+        let total = packed
+            .iter()
+            .fold(Number::from(0), |sum, v| sum + v.to_number());
+        black_box(total);
+    });
+    let mul_numbers = time(|| {
+        let products: Vec<Number> = numbers
+            .iter()
+            .map(|v| v.clone() * Number::from(3))
+            .collect();
+        black_box(products);
+    });
+    let mul_packed = time(|| {
+        let products: Vec<PackedNumber> =
+            packed.iter().map(|&v| v * PackedNumber::from(3)).collect();
+        black_box(products);
+    });
+    let per_value = |d: Duration| d.as_nanos() as f64 / LEN as f64;
+    println!(
+        "sum:      Vec<Number> {:.2} ns/value, Vec<PackedNumber> {:.2} ns/value",
+        per_value(sum_numbers),
+        per_value(sum_packed)
+    );
+    println!(
+        "multiply: Vec<Number> {:.2} ns/value, Vec<PackedNumber> {:.2} ns/value",
+        per_value(mul_numbers),
+        per_value(mul_packed)
+    );
+}
@@ -0,0 +1,69 @@
+// repr-c 布局说明：Number 是 #[repr(C, u8)]，即首字节为标签的 C 结构体，
+// 后跟按最大对齐排布的各变体字段联合体。布局变化时递增 LAYOUT_VERSION。
+// 标签 0..=13 固定；启用 half 时 Float16/BFloat16 为 14/15。small 构建没有 Integer64，
+// 9 及之后的标签依次减 1。BigInteger 和 Extended 持有堆指针，不应跨动态库边界传递。
+
+use crate::Number;
+
+pub const LAYOUT_VERSION: u32 = 1;
+
+pub const TAG_POSITIVE_INFINITY: u8 = 0;
+pub const TAG_NEGATIVE_INFINITY: u8 = 1;
+pub const TAG_NAN: u8 = 2;
+pub const TAG_RATIONAL: u8 = 3;
+pub const TAG_DECIMAL: u8 = 4;
+pub const TAG_FIXED64: u8 = 5;
+pub const TAG_INTEGER128: u8 = 6;
+pub const TAG_UNSIGNED_INTEGER64: u8 = 7;
+#[cfg(not(feature = "small"))]
+pub const TAG_INTEGER64: u8 = 8;
+pub const TAG_INTEGER32: u8 = 9 - SMALL_SHIFT;
+pub const TAG_INTEGER16: u8 = 10 - SMALL_SHIFT;
+pub const TAG_INTEGER8: u8 = 11 - SMALL_SHIFT;
+pub const TAG_FLOAT64: u8 = 12 - SMALL_SHIFT;
+pub const TAG_FLOAT32: u8 = 13 - SMALL_SHIFT;
+#[cfg(feature = "half")]
+pub const TAG_FLOAT16: u8 = 14 - SMALL_SHIFT;
+#[cfg(feature = "half")]
+pub const TAG_BFLOAT16: u8 = 15 - SMALL_SHIFT;
+
+const SMALL_SHIFT: u8 = if cfg!(feature = "small") { 1 } else { 0 };
+
+impl Number {
+    pub const fn abi_tag(&self) -> u8 {
+        // repr(C, u8) 保证首字节就是标签
+        unsafe { *(self as *const Number as *const u8) }
+    }
+}
+
+const _: () = {
+    assert!(Number::PositiveInfinity.abi_tag() == TAG_POSITIVE_INFINITY);
+    assert!(Number::NegativeInfinity.abi_tag() == TAG_NEGATIVE_INFINITY);
+    assert!(Number::NaN.abi_tag() == TAG_NAN);
+    assert!(Number::Rational(0, 1).abi_tag() == TAG_RATIONAL);
+    assert!(Number::Decimal(0, 0).abi_tag() == TAG_DECIMAL);
+    assert!(
+        Number::Fixed64 {
+            raw: 0,
+            frac_bits: 0
+        }
+        .abi_tag()
+            == TAG_FIXED64
+    );
+    assert!(Number::Integer128(0).abi_tag() == TAG_INTEGER128);
+    assert!(Number::UnsignedInteger64(0).abi_tag() == TAG_UNSIGNED_INTEGER64);
+    #[cfg(not(feature = "small"))]
+    assert!(Number::Integer64(0).abi_tag() == TAG_INTEGER64);
+    assert!(Number::Integer32(0).abi_tag() == TAG_INTEGER32);
+    assert!(Number::Integer16(0).abi_tag() == TAG_INTEGER16);
+    assert!(Number::Integer8(0).abi_tag() == TAG_INTEGER8);
+    assert!(Number::Float64(0.0).abi_tag() == TAG_FLOAT64);
+    assert!(Number::Float32(0.0).abi_tag() == TAG_FLOAT32);
+    #[cfg(feature = "half")]
+    assert!(Number::Float16(half::f16::ZERO).abi_tag() == TAG_FLOAT16);
+    #[cfg(feature = "half")]
+    assert!(Number::BFloat16(half::bf16::ZERO).abi_tag() == TAG_BFLOAT16);
+    // 标签按 i128 对齐占 16 字节，最大的变体 Decimal(i128, u32) 占 32 字节
+    #[cfg(not(any(feature = "bigint", feature = "extended")))]
+    assert!(std::mem::size_of::<Number>() == 48 && std::mem::align_of::<Number>() == 16);
+};
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+
+use crate::Number;
+
+// 定长的 Number 向量（RGB、XYZ、四元数分量等），全部运算都在栈上完成
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(any(feature = "bigint", feature = "extended")), derive(Copy))]
+pub struct NumberArray<const N: usize>(pub [Number; N]);
+
+impl<const N: usize> NumberArray<N> {
+    pub fn new(values: [Number; N]) -> Self {
+        NumberArray(values)
+    }
+    pub fn splat(value: Number) -> Self {
+        NumberArray(std::array::from_fn(|_| value.clone()))
+    }
+    pub fn as_slice(&self) -> &[Number] {
+        &self.0
+    }
+    pub fn into_inner(self) -> [Number; N] {
+        self.0
+    }
+    pub fn map(&self, f: impl FnMut(&Number) -> Number) -> Self {
+        NumberArray(self.0.each_ref().map(f))
+    }
+    pub fn scale(&self, factor: &Number) -> Self {
+        self.map(|value| value.clone() * factor.clone())
+    }
+    pub fn dot(&self, other: &Self) -> Number {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(Number::from(0), |sum, (a, b)| sum + a.clone() * b.clone())
+    }
+    pub fn sum(&self) -> Number {
+        self.0
+            .iter()
+            .fold(Number::from(0), |sum, value| sum + value.clone())
+    }
+    // 与 f64::min 一致忽略 NaN；N 为 0 时返回 None，全部是 NaN 时返回 NaN
+    pub fn min(&self) -> Option<Number> {
+        self.pick(|candidate, best| candidate < best)
+    }
+    pub fn max(&self) -> Option<Number> {
+        self.pick(|candidate, best| candidate > best)
+    }
+    fn pick(&self, better: impl Fn(&Number, &Number) -> bool) -> Option<Number> {
+        let mut values = self.0.iter();
+        let mut best = values.next()?;
+        for value in values {
+            if best.is_nan() || (!value.is_nan() && better(value, best)) {
+                best = value;
+            }
+        }
+        Some(best.clone())
+    }
+    fn zip_with(&self, other: &Self, f: impl Fn(Number, Number) -> Number) -> Self {
+        NumberArray(std::array::from_fn(|i| {
+            f(self.0[i].clone(), other.0[i].clone())
+        }))
+    }
+}
+
+impl<const N: usize> From<[Number; N]> for NumberArray<N> {
+    fn from(values: [Number; N]) -> Self {
+        NumberArray(values)
+    }
+}
+
+impl<const N: usize> Index<usize> for NumberArray<N> {
+    type Output = Number;
+
+    fn index(&self, index: usize) -> &Number {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for NumberArray<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Number {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> fmt::Display for NumberArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<const N: usize> Add for NumberArray<N> {
+    type Output = NumberArray<N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize> Sub for NumberArray<N> {
+    type Output = NumberArray<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize> Mul for NumberArray<N> {
+    type Output = NumberArray<N>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a * b)
+    }
+}
+
+impl<const N: usize> Div for NumberArray<N> {
+    type Output = NumberArray<N>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.zip_with(&rhs, |a, b| a / b)
+    }
+}
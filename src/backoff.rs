@@ -0,0 +1,41 @@
+use crate::Number;
+
+// 超过这个次数就不再逐次精确相乘，直接用 f64 结果
+const EXACT_ATTEMPTS: u32 = 64;
+
+// 计算第 attempt 次重试的延迟 base * factor^attempt，结果饱和在 max。
+// jitter 为调用方采样的 [0, 1] 均匀随机数，给出时按 full jitter 缩放延迟。
+pub fn backoff(
+    base: &Number,
+    factor: &Number,
+    attempt: u32,
+    max: &Number,
+    jitter: Option<&Number>,
+) -> Number {
+    let (b, f, m) = (base.to_f64(), factor.to_f64(), max.to_f64());
+    if b.is_nan() || f.is_nan() || m.is_nan() || b < 0.0 || f < 0.0 || m < 0.0 {
+        return Number::NaN;
+    }
+    let estimate = b * f.powf(attempt as f64);
+    let delay = if estimate.is_nan() || estimate >= m {
+        max.clone()
+    } else if attempt <= EXACT_ATTEMPTS {
+        let mut delay = base.clone();
+        for _ in 0..attempt {
+            delay *= factor.clone();
+        }
+        if delay > *max { max.clone() } else { delay }
+    } else {
+        Number::from_f64(estimate)
+    };
+    match jitter {
+        Some(u) => {
+            let u = u.to_f64();
+            if !(0.0..=1.0).contains(&u) {
+                return Number::NaN;
+            }
+            Number::from_f64(delay.to_f64() * u)
+        }
+        None => delay,
+    }
+}
@@ -0,0 +1,51 @@
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::{BinOp, Number};
+
+impl Number {
+    pub fn to_big_int(&self) -> Option<BigInt> {
+        match self {
+            Number::BigInteger(v) => Some(v.clone()),
+            _ => self.int_value().map(BigInt::from),
+        }
+    }
+    pub(crate) fn big_integer_op(&self, rhs: &Self, op: BinOp) -> Option<Number> {
+        let (a, b) = (self.to_big_int()?, rhs.to_big_int()?);
+        let result = match op {
+            BinOp::Add => a + b,
+            BinOp::Sub => a - b,
+            BinOp::Mul => a * b,
+            BinOp::Div => {
+                if b.is_zero() || !(&a % &b).is_zero() {
+                    return None;
+                }
+                a / b
+            }
+            BinOp::Rem => {
+                if b.is_zero() {
+                    return None;
+                }
+                a % b
+            }
+        };
+        Some(Number::from(result))
+    }
+    pub(crate) fn big_integer_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !matches!(self, Number::BigInteger(_)) && !matches!(other, Number::BigInteger(_)) {
+            return None;
+        }
+        Some(self.to_big_int()?.cmp(&other.to_big_int()?))
+    }
+}
+
+impl From<BigInt> for Number {
+    fn from(value: BigInt) -> Self {
+        match value.to_i128() {
+            Some(v) => Number::from_wide_int(v),
+            None => Number::BigInteger(value),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+// Number 只有在未启用 bigint 和 extended 时才是 Copy
+#![cfg_attr(
+    not(any(feature = "bigint", feature = "extended")),
+    allow(clippy::clone_on_copy)
+)]
+
+use std::collections::HashMap;
+
+use my_r_num::expr::Expr;
+use my_r_num::{FormatConfig, Number, NumberConfig};
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+
+const HELP: &str = "\
+<expr>              evaluate, e.g. 1/3 + 0.5, sqrt(2), SUM(1, 2, 3)
+<name> = <expr>     assign a variable; the last result is also stored as `ans`
+:type <expr>        show the variant the result is stored as
+:explain <expr>     show parsing, simplification and how the value is represented
+:fmt <digits> <expr>
+                    print floats with a fixed number of decimals
+:vars               list variables
+:help               show this help
+:quit               exit";
+
+struct Session {
+    vars: HashMap<String, Number>,
+}
+
+impl Session {
+    fn eval(&self, source: &str) -> Result<Number, String> {
+        let expr = Expr::parse(source)?;
+        expr.eval(&self.vars).map_err(|err| err.to_string())
+    }
+    // 返回 None 表示退出
+    fn run(&mut self, line: &str) -> Option<Result<String, String>> {
+        let line = line.trim();
+        let (command, rest) = match line.split_once(char::is_whitespace) {
+            Some((command, rest)) => (command, rest.trim()),
+            None => (line, ""),
+        };
+        let output = match command {
+            "" => Ok(String::new()),
+            ":quit" | ":q" | ":exit" => return None,
+            ":help" | ":h" => Ok(HELP.to_string()),
+            ":vars" => {
+                let mut names: Vec<_> = self.vars.iter().collect();
+                names.sort_by(|a, b| a.0.cmp(b.0));
+                Ok(names
+                    .iter()
+                    .map(|(name, value)| format!("{} = {} ({})", name, value, value.type_name()))
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+            ":type" | ":t" => self.eval(rest).map(|n| n.type_name().to_string()),
+            ":explain" | ":e" => self.explain(rest),
+            ":fmt" => self.format(rest),
+            _ if command.starts_with(':') => {
+                Err(format!("unknown command '{}', try :help", command))
+            }
+            _ => self.assign_or_eval(line),
+        };
+        Some(output)
+    }
+    fn assign_or_eval(&mut self, line: &str) -> Result<String, String> {
+        // name = expr 是赋值；name == expr、name <= expr 等仍按比较处理
+        let (name, source) = match line.split_once('=') {
+            Some((name, source)) if is_identifier(name.trim()) && !source.starts_with('=') => {
+                (Some(name.trim()), source)
+            }
+            _ => (None, line),
+        };
+        let value = self.eval(source)?;
+        let shown = format!("{} ({})", value, value.type_name());
+        if let Some(name) = name {
+            self.vars.insert(name.to_string(), value.clone());
+        }
+        self.vars.insert("ans".to_string(), value);
+        Ok(shown)
+    }
+    fn explain(&self, source: &str) -> Result<String, String> {
+        let expr = Expr::parse(source)?;
+        // explain 需要显示 NaN 的来源
+        let config = NumberConfig {
+            nan_origins: true,
+            ..NumberConfig::current()
+        };
+        let value =
+            Number::with_config(config, || expr.eval(&self.vars)).map_err(|err| err.to_string())?;
+        let mut lines = vec![
+            format!("parsed:     {}", expr),
+            format!("simplified: {}", expr.simplify()),
+            format!("value:      {}", value),
+            format!("variant:    {:?}", value),
+        ];
+        if let Some(exact) = value.to_rational()
+            && exact != value
+        {
+            lines.push(format!("exact:      {}", exact));
+        }
+        if let Some(origin) = value.nan_origin() {
+            lines.push(format!("nan origin: {:?}", origin));
+        }
+        if value.is_finite() {
+            lines.push(format!("as f64:     {:?}", value.to_f64()));
+        }
+        Ok(lines.join("\n"))
+    }
+    fn format(&self, rest: &str) -> Result<String, String> {
+        let (digits, source) = rest
+            .split_once(char::is_whitespace)
+            .ok_or("usage: :fmt <digits> <expr>")?;
+        let digits = digits
+            .parse::<usize>()
+            .map_err(|_| format!("'{}' is not a number of digits", digits))?;
+        let value = self.eval(source)?;
+        let config = NumberConfig {
+            format: FormatConfig {
+                float_precision: Some(digits),
+                ..FormatConfig::default()
+            },
+            ..NumberConfig::default()
+        };
+        Ok(Number::with_config(config, || value.to_string()))
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn main() {
+    let mut session = Session {
+        vars: HashMap::new(),
+    };
+    // 带参数时逐个求值后退出，便于在脚本中使用
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        let mut failed = false;
+        for arg in &args {
+            match session.run(arg) {
+                Some(Ok(output)) => println!("{}", output),
+                Some(Err(err)) => {
+                    eprintln!("error: {}", err);
+                    failed = true;
+                }
+                None => break,
+            }
+        }
+        std::process::exit(i32::from(failed));
+    }
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    };
+    println!(
+        "my_r_num {} — :help for commands",
+        env!("CARGO_PKG_VERSION")
+    );
+    loop {
+        match editor.readline("> ") {
+            Ok(line) => {
+                let _ = editor.add_history_entry(line.as_str());
+                match session.run(&line) {
+                    Some(Ok(output)) if output.is_empty() => {}
+                    Some(Ok(output)) => println!("{}", output),
+                    Some(Err(err)) => eprintln!("error: {}", err),
+                    None => break,
+                }
+            }
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                break;
+            }
+        }
+    }
+}
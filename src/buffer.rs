@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt::{self, Write};
+
+use crate::Number;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    // 完整输出需要的字节数
+    pub required: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer too small: {} bytes required", self.required)
+    }
+}
+
+impl Error for BufferTooSmall {}
+
+impl Number {
+    // 把 Display 的结果写进调用方提供的缓冲区，返回写入的字节数。
+    // 保证不分配堆内存：未启用 bigint / extended 且没有设置区域格式（FormatConfig::options）时，
+    // 格式化全程直接写入 buf，四则运算同样不分配，tests/buffer.rs 用计数分配器检查这一点。
+    // 缓冲区不够时内容未定义，错误里给出所需长度
+    pub fn write_to_buf(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        if write!(writer, "{}", self).is_ok() {
+            return Ok(writer.len);
+        }
+        let mut counter = Counter(0);
+        let _ = write!(counter, "{}", self);
+        Err(BufferTooSmall {
+            required: counter.0,
+        })
+    }
+}
+
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let target = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::display::fixed_digits;
+use crate::{Number, ParseErrorKind, ParseNumberError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteUnits {
+    // 1024 进：KiB、MiB、GiB……
+    #[default]
+    Binary,
+    // 1000 进：kB、MB、GB……
+    Decimal,
+}
+
+const BINARY_UNITS: [&str; 9] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+const DECIMAL_UNITS: [&str; 9] = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+
+impl ByteUnits {
+    fn base(self) -> i128 {
+        match self {
+            ByteUnits::Binary => 1024,
+            ByteUnits::Decimal => 1000,
+        }
+    }
+    fn names(self) -> &'static [&'static str; 9] {
+        match self {
+            ByteUnits::Binary => &BINARY_UNITS,
+            ByteUnits::Decimal => &DECIMAL_UNITS,
+        }
+    }
+}
+
+impl Number {
+    // 把值当作字节数输出，保留至多两位小数并去掉末尾的 0，例如 1536 为 "1.5 KiB"，
+    // 1_234_567 按 Decimal 为 "1.23 MB"。舍入后达到下一级时进位，如 1_048_575 为 "1 MiB"
+    pub fn to_bytes_string(&self, units: ByteUnits) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+        let base = units.base();
+        let names = units.names();
+        let magnitude = self.to_f64().abs();
+        let mut tier = 0;
+        while tier + 1 < names.len() && magnitude >= (base as f64).powi(tier as i32 + 1) {
+            tier += 1;
+        }
+        loop {
+            let text = self.scaled_bytes(base.pow(tier as u32));
+            let carried = text
+                .trim_start_matches('-')
+                .parse::<f64>()
+                .is_ok_and(|v| v >= base as f64);
+            if carried && tier + 1 < names.len() {
+                tier += 1;
+                continue;
+            }
+            return format!("{} {}", text, names[tier]);
+        }
+    }
+    // to_bytes_string 的逆运算："1.5 GiB"、"100MB"、"512" 等。单位不区分大小写，
+    // KiB 等为 1024 进，kB、KB 和单个字母 K、M、G 等为 1000 进。
+    // 结果是整数时返回整数变体，否则为浮点数
+    pub fn parse_bytes(s: &str) -> Result<Number, ParseNumberError> {
+        let text = s.trim_end();
+        let unit_start = text
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_ascii_alphabetic())
+            .last()
+            .map_or(text.len(), |(i, _)| i);
+        let number_text = &text[..unit_start];
+        let factor = byte_factor(&text[unit_start..]).ok_or(ParseNumberError::new(
+            ParseErrorKind::TrailingGarbage,
+            unit_start,
+        ))?;
+        if number_text.trim().is_empty() {
+            return Err(ParseNumberError::new(ParseErrorKind::Empty, 0));
+        }
+        // 先按精确十进制解析，"1.1 kB" 才能得到整数 1100
+        let value = Number::parse_decimal(number_text.trim_end())
+            .or_else(|_| Number::parse(number_text))?;
+        if let Some((n, d)) = value.exact_ratio()
+            && let Some(scaled) = n.checked_mul(factor)
+            && scaled % d == 0
+        {
+            return Ok(Number::from_wide_int(scaled / d));
+        }
+        Ok(Number::Float64(value.to_f64() * factor as f64))
+    }
+    fn scaled_bytes(&self, divisor: i128) -> String {
+        let text = self
+            .exact_ratio()
+            .and_then(|(n, d)| fixed_digits(n, d.checked_mul(divisor)?, 2))
+            .unwrap_or_else(|| format!("{:.2}", self.to_f64() / divisor as f64));
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        if text == "-0" {
+            "0".to_string()
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+fn byte_factor(unit: &str) -> Option<i128> {
+    let unit = unit.to_ascii_lowercase();
+    let (prefix, binary) = match unit.as_str() {
+        "" | "b" => return Some(1),
+        _ => match unit.strip_suffix("ib") {
+            Some(prefix) => (prefix, true),
+            None => (unit.strip_suffix('b').unwrap_or(&unit), false),
+        },
+    };
+    let power = ["k", "m", "g", "t", "p", "e", "z", "y"]
+        .iter()
+        .position(|p| *p == prefix)? as u32
+        + 1;
+    let base: i128 = if binary { 1024 } else { 1000 };
+    Some(base.pow(power))
+}
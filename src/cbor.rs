@@ -0,0 +1,350 @@
+// CBOR（RFC 8949）编码。整数按最短的头部写出，超出 64 位的整数用标签 2/3 的大整数；
+// Float64、Float32、Float16 按各自宽度写出，BFloat16 写成 f32；NaN 和 ±∞ 写成半精度，
+// 带载荷的 NaN 按 f64 保留位模式。没有原生数字类型的变体使用标准标签：
+// Rational 为标签 30 [分子, 分母]，Decimal 为标签 4 [-标度, 尾数]，
+// Fixed64 为标签 5 [-小数位数, 原始值]。Extended 按 f64 写出。
+// 解码时浮点数还原为对应宽度的变体，整数按大小选择最窄的整数变体。
+
+#[cfg(feature = "half")]
+use half::f16;
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, Sign};
+
+use crate::Number;
+use crate::codec::{self, DecodeError, Reader};
+use crate::nan;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+const TAG_DECIMAL_FRACTION: u64 = 4;
+const TAG_BIGFLOAT: u64 = 5;
+const TAG_RATIONAL: u64 = 30;
+
+// 二元数组 [a, b] 的头部
+const ARRAY_OF_TWO: u8 = 0x82;
+
+const HALF_NAN: u16 = 0x7e00;
+const HALF_INFINITY: u16 = 0x7c00;
+const HALF_NEG_INFINITY: u16 = 0xfc00;
+
+impl Number {
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_number(&mut out, self);
+        out
+    }
+    // bytes 必须恰好是一个数字，多余的字节报 TrailingBytes
+    pub fn from_cbor(bytes: &[u8]) -> Result<Number, DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let number = read_number(&mut reader)?;
+        reader.finish()?;
+        Ok(number)
+    }
+    // 从 bytes 开头读出一个数字，同时返回占用的字节数
+    pub fn read_cbor(bytes: &[u8]) -> Result<(Number, usize), DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let number = read_number(&mut reader)?;
+        Ok((number, reader.position()))
+    }
+}
+
+fn write_number(out: &mut Vec<u8>, number: &Number) {
+    match number {
+        Number::PositiveInfinity => write_half(out, HALF_INFINITY),
+        Number::NegativeInfinity => write_half(out, HALF_NEG_INFINITY),
+        Number::NaN => write_half(out, HALF_NAN),
+        Number::Rational(n, d) => {
+            write_head(out, MAJOR_TAG, TAG_RATIONAL);
+            out.push(ARRAY_OF_TWO);
+            write_int(out, *n as i128);
+            write_int(out, *d as i128);
+        }
+        Number::Decimal(m, scale) => {
+            write_head(out, MAJOR_TAG, TAG_DECIMAL_FRACTION);
+            out.push(ARRAY_OF_TWO);
+            write_int(out, -(*scale as i128));
+            write_int(out, *m);
+        }
+        Number::Fixed64 { raw, frac_bits } => {
+            write_head(out, MAJOR_TAG, TAG_BIGFLOAT);
+            out.push(ARRAY_OF_TWO);
+            write_int(out, -(*frac_bits as i128));
+            write_int(out, *raw as i128);
+        }
+        Number::Float64(v) => write_f64(out, *v),
+        Number::Float32(v) => write_f32(out, *v),
+        #[cfg(feature = "half")]
+        Number::Float16(v) => write_half(out, v.to_bits()),
+        #[cfg(feature = "half")]
+        Number::BFloat16(v) => write_f32(out, v.to_f32()),
+        #[cfg(feature = "bigint")]
+        Number::BigInteger(v) => write_big_int(out, v),
+        #[cfg(feature = "extended")]
+        Number::Extended(v) => write_f64(out, v.to_f64()),
+        _ => write_int(out, number.int_value().unwrap_or_default()),
+    }
+}
+
+// 参数小于 24 时直接放在首字节，否则按 1、2、4、8 字节中最短的一种写出
+fn write_head(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    if value < 24 {
+        out.push(major | value as u8);
+    } else if let Ok(v) = u8::try_from(value) {
+        out.extend_from_slice(&[major | 24, v]);
+    } else if let Ok(v) = u16::try_from(value) {
+        out.push(major | 25);
+        out.extend_from_slice(&v.to_be_bytes());
+    } else if let Ok(v) = u32::try_from(value) {
+        out.push(major | 26);
+        out.extend_from_slice(&v.to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+// 负数 v 按 -1 - v 编码
+fn write_int(out: &mut Vec<u8>, value: i128) {
+    let (major, tag, magnitude) = if value >= 0 {
+        (MAJOR_UNSIGNED, TAG_POSITIVE_BIGNUM, value as u128)
+    } else {
+        (MAJOR_NEGATIVE, TAG_NEGATIVE_BIGNUM, (-1 - value) as u128)
+    };
+    match u64::try_from(magnitude) {
+        Ok(v) => write_head(out, major, v),
+        Err(_) => write_bignum(out, tag, &magnitude.to_be_bytes()),
+    }
+}
+
+#[cfg(feature = "bigint")]
+fn write_big_int(out: &mut Vec<u8>, value: &BigInt) {
+    use num_traits::ToPrimitive;
+
+    if let Some(v) = value.to_i128() {
+        return write_int(out, v);
+    }
+    if value.sign() == Sign::Minus {
+        let magnitude = -value - BigInt::from(1);
+        write_bignum(out, TAG_NEGATIVE_BIGNUM, &magnitude.to_bytes_be().1);
+    } else {
+        write_bignum(out, TAG_POSITIVE_BIGNUM, &value.to_bytes_be().1);
+    }
+}
+
+// 大整数的字节串为大端序的绝对值，去掉前导零
+fn write_bignum(out: &mut Vec<u8>, tag: u64, magnitude: &[u8]) {
+    let start = magnitude
+        .iter()
+        .position(|&b| b != 0)
+        .unwrap_or(magnitude.len());
+    write_head(out, MAJOR_TAG, tag);
+    write_head(out, MAJOR_BYTES, (magnitude.len() - start) as u64);
+    out.extend_from_slice(&magnitude[start..]);
+}
+
+fn write_half(out: &mut Vec<u8>, bits: u16) {
+    out.push(MAJOR_SIMPLE << 5 | 25);
+    out.extend_from_slice(&bits.to_be_bytes());
+}
+
+fn write_f32(out: &mut Vec<u8>, value: f32) {
+    if !value.is_finite() {
+        return write_f64(out, value as f64);
+    }
+    out.push(MAJOR_SIMPLE << 5 | 26);
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn write_f64(out: &mut Vec<u8>, value: f64) {
+    if value.is_nan() && !nan::has_payload(value) {
+        write_half(out, HALF_NAN);
+    } else if value == f64::INFINITY {
+        write_half(out, HALF_INFINITY);
+    } else if value == f64::NEG_INFINITY {
+        write_half(out, HALF_NEG_INFINITY);
+    } else {
+        out.push(MAJOR_SIMPLE << 5 | 27);
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+fn read_number(reader: &mut Reader<'_>) -> Result<Number, DecodeError> {
+    let initial = reader.byte()?;
+    match initial >> 5 {
+        MAJOR_UNSIGNED | MAJOR_NEGATIVE => read_int(reader, initial),
+        MAJOR_TAG => match read_argument(reader, initial)? {
+            tag @ (TAG_POSITIVE_BIGNUM | TAG_NEGATIVE_BIGNUM) => {
+                read_bignum(reader, tag == TAG_NEGATIVE_BIGNUM)
+            }
+            TAG_DECIMAL_FRACTION => {
+                let (exponent, mantissa) = read_pair(reader)?;
+                if exponent <= 0 {
+                    let scale = u32::try_from(-exponent).map_err(|_| DecodeError::OutOfRange)?;
+                    codec::decimal(mantissa, scale)
+                } else {
+                    let scale = u32::try_from(exponent).map_err(|_| DecodeError::OutOfRange)?;
+                    10i128
+                        .checked_pow(scale)
+                        .and_then(|power| mantissa.checked_mul(power))
+                        .map(|v| Number::Decimal(v, 0))
+                        .ok_or(DecodeError::OutOfRange)
+                }
+            }
+            TAG_BIGFLOAT => {
+                let (exponent, mantissa) = read_pair(reader)?;
+                let raw = i64::try_from(mantissa).map_err(|_| DecodeError::OutOfRange)?;
+                if exponent <= 0 {
+                    let frac_bits = u8::try_from(-exponent).map_err(|_| DecodeError::OutOfRange)?;
+                    codec::fixed(raw, frac_bits)
+                } else {
+                    let shift = u32::try_from(exponent).map_err(|_| DecodeError::OutOfRange)?;
+                    2i128
+                        .checked_pow(shift)
+                        .and_then(|power| mantissa.checked_mul(power))
+                        .map(Number::from_wide_int)
+                        .ok_or(DecodeError::OutOfRange)
+                }
+            }
+            TAG_RATIONAL => {
+                let (numerator, denominator) = read_pair(reader)?;
+                match (i64::try_from(numerator), i64::try_from(denominator)) {
+                    (Ok(n), Ok(d)) if d != 0 => Ok(Number::rational(n, d)),
+                    _ => Err(DecodeError::OutOfRange),
+                }
+            }
+            _ => Err(DecodeError::UnsupportedType(initial)),
+        },
+        MAJOR_SIMPLE => match initial & 0x1f {
+            25 => Ok(from_half(u16::from_be_bytes(reader.array()?))),
+            26 => {
+                let v = f32::from_be_bytes(reader.array()?);
+                Ok(if v.is_finite() {
+                    Number::Float32(v)
+                } else {
+                    Number::from_f64(v as f64)
+                })
+            }
+            27 => Ok(Number::from_f64(f64::from_be_bytes(reader.array()?))),
+            _ => Err(DecodeError::UnsupportedType(initial)),
+        },
+        _ => Err(DecodeError::UnsupportedType(initial)),
+    }
+}
+
+fn read_argument(reader: &mut Reader<'_>, initial: u8) -> Result<u64, DecodeError> {
+    Ok(match initial & 0x1f {
+        v @ 0..=23 => v as u64,
+        24 => reader.byte()? as u64,
+        25 => u16::from_be_bytes(reader.array()?) as u64,
+        26 => u32::from_be_bytes(reader.array()?) as u64,
+        27 => u64::from_be_bytes(reader.array()?),
+        _ => return Err(DecodeError::UnsupportedType(initial)),
+    })
+}
+
+// initial 为主类型 0 或 1 的首字节；标签 2/3 按解码出的标签号交给 read_bignum，
+// 标签号也可能用 d8 03 这样的长格式编码
+fn read_int(reader: &mut Reader<'_>, initial: u8) -> Result<Number, DecodeError> {
+    let negative = initial >> 5 == MAJOR_NEGATIVE;
+    let magnitude = read_argument(reader, initial)? as i128;
+    Ok(Number::from_wide_int(if negative {
+        -1 - magnitude
+    } else {
+        magnitude
+    }))
+}
+
+fn read_bignum(reader: &mut Reader<'_>, negative: bool) -> Result<Number, DecodeError> {
+    let initial = reader.byte()?;
+    if initial >> 5 != MAJOR_BYTES {
+        return Err(DecodeError::UnsupportedType(initial));
+    }
+    let len = read_argument(reader, initial)?;
+    let len = usize::try_from(len).map_err(|_| DecodeError::UnexpectedEnd)?;
+    let bytes = reader.take(len)?;
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    let bytes = &bytes[start..];
+    if bytes.len() <= 16 {
+        let mut buf = [0u8; 16];
+        buf[16 - bytes.len()..].copy_from_slice(bytes);
+        if let Ok(magnitude) = i128::try_from(u128::from_be_bytes(buf)) {
+            return Ok(Number::from_wide_int(if negative {
+                -1 - magnitude
+            } else {
+                magnitude
+            }));
+        }
+    }
+    #[cfg(feature = "bigint")]
+    {
+        let magnitude = BigInt::from_bytes_be(Sign::Plus, bytes);
+        Ok(Number::from(if negative {
+            -magnitude - BigInt::from(1)
+        } else {
+            magnitude
+        }))
+    }
+    #[cfg(not(feature = "bigint"))]
+    Err(DecodeError::OutOfRange)
+}
+
+// 标签 4、5、30 的内容：两个整数组成的数组
+fn read_pair(reader: &mut Reader<'_>) -> Result<(i128, i128), DecodeError> {
+    let initial = reader.byte()?;
+    if initial != ARRAY_OF_TWO {
+        return Err(DecodeError::UnsupportedType(initial));
+    }
+    Ok((read_integer(reader)?, read_integer(reader)?))
+}
+
+fn read_integer(reader: &mut Reader<'_>) -> Result<i128, DecodeError> {
+    let initial = reader.byte()?;
+    let number = match initial >> 5 {
+        MAJOR_UNSIGNED | MAJOR_NEGATIVE => read_int(reader, initial)?,
+        MAJOR_TAG => match read_argument(reader, initial)? {
+            tag @ (TAG_POSITIVE_BIGNUM | TAG_NEGATIVE_BIGNUM) => {
+                read_bignum(reader, tag == TAG_NEGATIVE_BIGNUM)?
+            }
+            _ => return Err(DecodeError::UnsupportedType(initial)),
+        },
+        _ => return Err(DecodeError::UnsupportedType(initial)),
+    };
+    number.int_value().ok_or(DecodeError::OutOfRange)
+}
+
+#[cfg(feature = "half")]
+fn from_half(bits: u16) -> Number {
+    let v = f16::from_bits(bits);
+    if v.is_finite() {
+        Number::Float16(v)
+    } else {
+        Number::from_f64(v.to_f64())
+    }
+}
+
+// 没有 half 时半精度读成 Float32，f32 能精确表示所有半精度值
+#[cfg(not(feature = "half"))]
+fn from_half(bits: u16) -> Number {
+    let sign = (bits as u32 & 0x8000) << 16;
+    let exponent = (bits >> 10) & 0x1f;
+    let mantissa = bits as u32 & 0x3ff;
+    let magnitude = match exponent {
+        0 => mantissa as f32 * f32::powi(2.0, -24),
+        0x1f if mantissa == 0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => f32::from_bits((exponent as u32 + 112) << 23 | mantissa << 13),
+    };
+    let v = f32::from_bits(magnitude.to_bits() | sign);
+    if v.is_finite() {
+        Number::Float32(v)
+    } else {
+        Number::from_f64(v as f64)
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt;
+
+use crate::Number;
+use crate::expr::{EvalError, Expr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CellError {
+    Parse(String),
+    // 循环引用的路径，首尾是同一个单元格，例如 A1 -> B1 -> A1
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellError::Parse(message) => write!(f, "{}", message),
+            CellError::Cycle(path) => write!(f, "circular reference: {}", path.join(" -> ")),
+        }
+    }
+}
+
+impl Error for CellError {}
+
+// 命名单元格到表达式的依赖图：修改一个单元格后只按拓扑顺序重算受影响的单元格，
+// 形成循环引用的修改会被拒绝，图保持不变
+#[derive(Debug, Clone, Default)]
+pub struct CellGraph {
+    formulas: BTreeMap<String, Expr>,
+    values: BTreeMap<String, Result<Number, EvalError>>,
+    // 反向边：单元格 -> 引用它的单元格
+    dependents: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl CellGraph {
+    pub fn new() -> Self {
+        CellGraph::default()
+    }
+    pub fn set(&mut self, name: &str, expr: Expr) -> Result<(), CellError> {
+        if let Some(path) = self.find_cycle(name, &expr) {
+            return Err(CellError::Cycle(path));
+        }
+        self.unlink(name);
+        for dependency in expr.variables() {
+            self.dependents
+                .entry(dependency)
+                .or_default()
+                .insert(name.to_string());
+        }
+        self.formulas.insert(name.to_string(), expr);
+        self.recalculate_from(name);
+        Ok(())
+    }
+    pub fn set_formula(&mut self, name: &str, formula: &str) -> Result<(), CellError> {
+        let expr = Expr::parse(formula).map_err(CellError::Parse)?;
+        self.set(name, expr)
+    }
+    pub fn set_value(&mut self, name: &str, value: Number) {
+        self.set(name, Expr::Number(value))
+            .expect("a constant cannot form a cycle");
+    }
+    // 删除后引用它的单元格得到 UnknownVariable
+    pub fn remove(&mut self, name: &str) -> Option<Expr> {
+        if !self.formulas.contains_key(name) {
+            return None;
+        }
+        self.unlink(name);
+        let expr = self.formulas.remove(name);
+        self.values.remove(name);
+        self.recalculate_from(name);
+        expr
+    }
+    pub fn value(&self, name: &str) -> Option<&Result<Number, EvalError>> {
+        self.values.get(name)
+    }
+    pub fn get(&self, name: &str) -> Option<&Number> {
+        self.values.get(name)?.as_ref().ok()
+    }
+    pub fn formula(&self, name: &str) -> Option<&Expr> {
+        self.formulas.get(name)
+    }
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.formulas.keys().map(String::as_str)
+    }
+    // 直接引用的单元格（包括尚未定义的）
+    pub fn precedents(&self, name: &str) -> Vec<String> {
+        self.formulas
+            .get(name)
+            .map(Expr::variables)
+            .unwrap_or_default()
+    }
+    // 直接引用 name 的单元格
+    pub fn dependents(&self, name: &str) -> Vec<String> {
+        self.dependents
+            .get(name)
+            .map(|set| set.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+    // 全部单元格按拓扑顺序重算
+    pub fn recalculate(&mut self) {
+        let all: BTreeSet<String> = self.formulas.keys().cloned().collect();
+        self.evaluate(all);
+    }
+    fn recalculate_from(&mut self, name: &str) {
+        let mut affected = BTreeSet::new();
+        let mut pending = vec![name.to_string()];
+        while let Some(cell) = pending.pop() {
+            if self.formulas.contains_key(&cell) && !affected.insert(cell.clone()) {
+                continue;
+            }
+            if let Some(dependents) = self.dependents.get(&cell) {
+                pending.extend(dependents.iter().cloned());
+            }
+        }
+        self.evaluate(affected);
+    }
+    // Kahn 算法：只在 cells 内部计算入度，依赖都算完的单元格先求值
+    fn evaluate(&mut self, cells: BTreeSet<String>) {
+        let mut indegree: BTreeMap<&String, usize> = cells
+            .iter()
+            .map(|cell| {
+                let count = self.formulas[cell]
+                    .variables()
+                    .iter()
+                    .filter(|d| cells.contains(*d))
+                    .count();
+                (cell, count)
+            })
+            .collect();
+        let mut ready: Vec<&String> = indegree
+            .iter()
+            .filter(|(_, n)| **n == 0)
+            .map(|(cell, _)| *cell)
+            .collect();
+        let mut order = Vec::new();
+        while let Some(cell) = ready.pop() {
+            order.push(cell.clone());
+            for dependent in self.dependents.get(cell).into_iter().flatten() {
+                if let Some(n) = indegree.get_mut(dependent) {
+                    *n -= 1;
+                    if *n == 0 {
+                        ready.push(dependent);
+                    }
+                }
+            }
+        }
+        for cell in order {
+            let value = self.compute(&cell);
+            self.values.insert(cell, value);
+        }
+    }
+    // 引用的单元格出错时沿用它的错误，和电子表格的错误传播一致
+    fn compute(&self, name: &str) -> Result<Number, EvalError> {
+        let expr = &self.formulas[name];
+        let mut vars = HashMap::new();
+        for dependency in expr.variables() {
+            match self.values.get(&dependency) {
+                Some(Ok(value)) => {
+                    vars.insert(dependency, value.clone());
+                }
+                Some(Err(err)) => return Err(err.clone()),
+                None => {}
+            }
+        }
+        expr.eval(&vars)
+    }
+    fn unlink(&mut self, name: &str) {
+        for dependency in self.precedents(name) {
+            if let Some(set) = self.dependents.get_mut(&dependency) {
+                set.remove(name);
+                if set.is_empty() {
+                    self.dependents.remove(&dependency);
+                }
+            }
+        }
+    }
+    // 从新表达式的依赖出发沿现有公式深度优先搜索，能回到 name 即成环
+    fn find_cycle(&self, name: &str, expr: &Expr) -> Option<Vec<String>> {
+        let mut visited = BTreeSet::new();
+        let mut path = vec![name.to_string()];
+        for dependency in expr.variables() {
+            if self.reaches(&dependency, name, &mut visited, &mut path) {
+                return Some(path);
+            }
+        }
+        None
+    }
+    fn reaches(
+        &self,
+        cell: &str,
+        target: &str,
+        visited: &mut BTreeSet<String>,
+        path: &mut Vec<String>,
+    ) -> bool {
+        path.push(cell.to_string());
+        if cell == target {
+            return true;
+        }
+        if visited.insert(cell.to_string())
+            && let Some(expr) = self.formulas.get(cell)
+        {
+            for dependency in expr.variables() {
+                if self.reaches(&dependency, target, visited, path) {
+                    return true;
+                }
+            }
+        }
+        path.pop();
+        false
+    }
+}
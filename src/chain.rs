@@ -0,0 +1,135 @@
+use crate::{MathError, Number};
+
+// 链式数学运算，任一步出错后后续步骤都被跳过，done() 返回第一个错误
+#[derive(Debug, Clone)]
+pub struct MathChain {
+    value: Result<Number, MathError>,
+}
+
+impl Number {
+    pub fn math(&self) -> MathChain {
+        MathChain {
+            value: Ok(self.clone()),
+        }
+    }
+}
+
+impl MathChain {
+    pub fn and_then(self, f: impl FnOnce(&Number) -> Result<Number, MathError>) -> Self {
+        MathChain {
+            value: self.value.and_then(|n| f(&n)),
+        }
+    }
+    pub fn map(self, f: impl FnOnce(&Number) -> Number) -> Self {
+        self.and_then(|n| Ok(f(n)))
+    }
+    pub fn done(self) -> Result<Number, MathError> {
+        self.value
+    }
+    pub fn abs(self) -> Self {
+        self.map(Number::abs)
+    }
+    pub fn negate(self) -> Self {
+        // 乘 -1 而不是 0 - n，保留 -0.0 的符号
+        self.map(|n| Number::from(-1) * n.clone())
+    }
+    pub fn plus(self, rhs: Number) -> Self {
+        self.map(|n| n.clone() + rhs)
+    }
+    pub fn minus(self, rhs: Number) -> Self {
+        self.map(|n| n.clone() - rhs)
+    }
+    pub fn times(self, rhs: Number) -> Self {
+        self.map(|n| n.clone() * rhs)
+    }
+    pub fn divide_by(self, rhs: Number) -> Self {
+        self.and_then(|n| {
+            if rhs == Number::from(0) {
+                Err(MathError::PoleError)
+            } else {
+                Ok(n.clone() / rhs)
+            }
+        })
+    }
+    pub fn sqrt(self) -> Self {
+        self.and_then(Number::try_sqrt)
+    }
+    pub fn ln(self) -> Self {
+        self.and_then(Number::try_ln)
+    }
+    pub fn log2(self) -> Self {
+        self.and_then(Number::try_log2)
+    }
+    pub fn log10(self) -> Self {
+        self.and_then(Number::try_log10)
+    }
+    pub fn exp(self) -> Self {
+        self.and_then(Number::try_exp)
+    }
+    pub fn asin(self) -> Self {
+        self.and_then(Number::try_asin)
+    }
+    pub fn acos(self) -> Self {
+        self.and_then(Number::try_acos)
+    }
+    pub fn acosh(self) -> Self {
+        self.and_then(Number::try_acosh)
+    }
+    pub fn atanh(self) -> Self {
+        self.and_then(Number::try_atanh)
+    }
+    // 四舍五入（远离零）到 digits 位小数，结果为十进制小数；浮点数按其二进制值舍入
+    pub fn round_to(self, digits: u32) -> Self {
+        self.and_then(|n| round_to(n, digits))
+    }
+}
+
+fn round_to(n: &Number, digits: u32) -> Result<Number, MathError> {
+    if n.is_nan() {
+        return Err(MathError::DomainError);
+    }
+    if n.is_infinite() || n.is_integer_variant() {
+        return Ok(n.clone());
+    }
+    if let Number::Decimal(m, scale) = n {
+        if *scale <= digits {
+            return Ok(n.clone());
+        }
+        let divisor = 10i128.pow(*scale - digits);
+        let (quotient, remainder) = (m / divisor, m % divisor);
+        let rounded = if remainder.unsigned_abs() * 2 >= divisor.unsigned_abs() {
+            quotient + m.signum()
+        } else {
+            quotient
+        };
+        return Ok(Number::decimal(rounded, digits));
+    }
+    if matches!(n, Number::Rational(..) | Number::Fixed64 { .. }) {
+        let (num, den) = n.exact_ratio().ok_or(MathError::LossOfPrecision)?;
+        return round_ratio(num, den, digits).ok_or(MathError::LossOfPrecision);
+    }
+    // 浮点数按其精确二进制展开舍入
+    Number::parse_decimal(&format!("{:.*}", digits as usize, n.to_f64()))
+        .map_err(|_| MathError::LossOfPrecision)
+}
+
+// 对精确分数做长除法，逐位求出小数，余数为零时提前结束；最后一位四舍五入（远离零）
+fn round_ratio(num: i128, den: i128, digits: u32) -> Option<Number> {
+    let negative = (num < 0) != (den < 0);
+    let (num, den) = (num.unsigned_abs(), den.unsigned_abs());
+    let (mut mantissa, mut remainder, mut scale) = (num / den, num % den, 0);
+    while scale < digits && remainder != 0 {
+        remainder = remainder.checked_mul(10)?;
+        mantissa = mantissa.checked_mul(10)?.checked_add(remainder / den)?;
+        remainder %= den;
+        scale += 1;
+    }
+    if remainder >= den - remainder {
+        mantissa = mantissa.checked_add(1)?;
+    }
+    let mantissa = i128::try_from(mantissa).ok()?;
+    Some(Number::decimal(
+        if negative { -mantissa } else { mantissa },
+        scale,
+    ))
+}
@@ -0,0 +1,253 @@
+// 中文数字。整数按万进制分节（万、亿、兆、京……涧），小数部分用“点”后逐位读出，
+// 例如 1234.5 为 一千二百三十四点五，大写为 壹仟贰佰叁拾肆点伍
+use crate::words::Language;
+use crate::{Number, NumberConfig, ParseErrorKind, ParseNumberError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChineseStyle {
+    #[default]
+    Normal,
+    // 大写，用于票据和金额
+    Financial,
+}
+
+const NORMAL_DIGITS: [char; 10] = ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+const FINANCIAL_DIGITS: [char; 10] = ['零', '壹', '贰', '叁', '肆', '伍', '陆', '柒', '捌', '玖'];
+const NORMAL_UNITS: [char; 3] = ['十', '百', '千'];
+const FINANCIAL_UNITS: [char; 3] = ['拾', '佰', '仟'];
+// 第 i 个元素对应 10^(4i)，u128 的最大值不到 10^39
+const SECTION_UNITS: [&str; 10] = ["", "万", "亿", "兆", "京", "垓", "秭", "穰", "沟", "涧"];
+
+impl Number {
+    // 非有限值和整数部分超出 i128 的值返回 None。非整数按其十进制写法逐位转换，
+    // 浮点数使用最短的往返表示，例如 0.1f32 为 零点一
+    pub fn to_chinese(&self, style: ChineseStyle) -> Option<String> {
+        self.to_words(&style)
+    }
+    // 同时接受普通写法和大写，也接受繁体字、“两”、“〇”、省略首位“一”的“十五”，
+    // 以及“二〇二四”这样不带单位的逐位写法。小数按默认配置下 Number::parse 的规则得到结果，
+    // 不受当前线程的区域设置影响
+    pub fn parse_chinese(s: &str) -> Result<Self, ParseNumberError> {
+        let text = s.trim();
+        let start = s.len() - s.trim_start().len();
+        if text.is_empty() {
+            return Err(ParseNumberError::new(ParseErrorKind::Empty, 0));
+        }
+        let (negative, body, body_start) = match text.strip_prefix(['负', '負']) {
+            Some(rest) => (true, rest, start + '负'.len_utf8()),
+            None => (false, text, start),
+        };
+        let (int_part, frac_part) = match body.find(['点', '點']) {
+            Some(i) => (
+                &body[..i],
+                Some((&body[i + '点'.len_utf8()..], i + '点'.len_utf8())),
+            ),
+            None => (body, None),
+        };
+        if int_part.is_empty() && frac_part.is_none() {
+            return Err(ParseNumberError::new(ParseErrorKind::Empty, body_start));
+        }
+        let magnitude = parse_int(int_part).map_err(|err| err.offset(body_start))?;
+        let Some((frac_part, frac_start)) = frac_part else {
+            let value = if negative { -magnitude } else { magnitude };
+            return Ok(Number::from_wide_int(value));
+        };
+        let mut ascii = format!("{}{}.", if negative { "-" } else { "" }, magnitude);
+        for (i, c) in frac_part.char_indices() {
+            match digit_value(c) {
+                Some(d) => ascii.push((b'0' + d as u8) as char),
+                None => {
+                    return Err(ParseNumberError::new(
+                        ParseErrorKind::InvalidDigit,
+                        body_start + frac_start + i,
+                    ));
+                }
+            }
+        }
+        if frac_part.is_empty() {
+            return Err(ParseNumberError::new(
+                ParseErrorKind::Empty,
+                body_start + frac_start,
+            ));
+        }
+        Number::parse_with_config(&ascii, &NumberConfig::default())
+            .map_err(|err| ParseNumberError::new(err.kind, start))
+    }
+}
+
+fn digits(style: ChineseStyle) -> &'static [char; 10] {
+    match style {
+        ChineseStyle::Normal => &NORMAL_DIGITS,
+        ChineseStyle::Financial => &FINANCIAL_DIGITS,
+    }
+}
+
+impl Language for ChineseStyle {
+    fn integer(&self, magnitude: u128) -> String {
+        format_int(magnitude, *self)
+    }
+    fn digit(&self, digit: u8) -> String {
+        digits(*self)[digit as usize].to_string()
+    }
+    fn minus(&self) -> &str {
+        "负"
+    }
+    fn point(&self) -> &str {
+        "点"
+    }
+    fn separator(&self) -> &str {
+        ""
+    }
+}
+
+fn format_int(mut magnitude: u128, style: ChineseStyle) -> String {
+    let digits = digits(style);
+    let units = match style {
+        ChineseStyle::Normal => &NORMAL_UNITS,
+        ChineseStyle::Financial => &FINANCIAL_UNITS,
+    };
+    if magnitude == 0 {
+        return digits[0].to_string();
+    }
+    let mut sections = Vec::new();
+    while magnitude > 0 {
+        sections.push((magnitude % 10_000) as usize);
+        magnitude /= 10_000;
+    }
+    let mut out = String::new();
+    // started：已经写出过数字；pending_zero：两个写出的数字之间有被跳过的 0
+    let mut started = false;
+    let mut pending_zero = false;
+    for (index, &section) in sections.iter().enumerate().rev() {
+        if section == 0 {
+            pending_zero |= started;
+            continue;
+        }
+        pending_zero |= started && section < 1000;
+        let place_values = [
+            section / 1000,
+            section / 100 % 10,
+            section / 10 % 10,
+            section % 10,
+        ];
+        for (place, &d) in place_values.iter().enumerate() {
+            if d == 0 {
+                pending_zero |= started;
+                continue;
+            }
+            if pending_zero {
+                out.push(digits[0]);
+                pending_zero = false;
+            }
+            let unit = 3 - place;
+            // 普通写法中以 10 到 19 开头时省略“一”，如 十五、十二万
+            if !(style == ChineseStyle::Normal && unit == 1 && d == 1 && !started) {
+                out.push(digits[d]);
+            }
+            if unit > 0 {
+                out.push(units[unit - 1]);
+            }
+            started = true;
+        }
+        // 节末尾的 0 不读，如 二千万一千
+        pending_zero = false;
+        out.push_str(SECTION_UNITS[index]);
+    }
+    out
+}
+
+fn digit_value(c: char) -> Option<u32> {
+    Some(match c {
+        '零' | '〇' | '0' => 0,
+        '一' | '壹' => 1,
+        '二' | '贰' | '貳' | '两' | '兩' => 2,
+        '三' | '叁' | '參' => 3,
+        '四' | '肆' => 4,
+        '五' | '伍' => 5,
+        '六' | '陆' | '陸' => 6,
+        '七' | '柒' => 7,
+        '八' | '捌' => 8,
+        '九' | '玖' => 9,
+        _ => return None,
+    })
+}
+
+fn small_unit(c: char) -> Option<i128> {
+    Some(match c {
+        '十' | '拾' => 10,
+        '百' | '佰' => 100,
+        '千' | '仟' => 1000,
+        _ => return None,
+    })
+}
+
+fn section_unit(c: char) -> Option<u32> {
+    Some(match c {
+        '万' | '萬' => 4,
+        '亿' | '億' => 8,
+        '兆' => 12,
+        '京' => 16,
+        '垓' => 20,
+        '秭' => 24,
+        '穰' => 28,
+        '沟' | '溝' => 32,
+        '涧' | '澗' => 36,
+        _ => return None,
+    })
+}
+
+// 返回绝对值；position 相对于 s
+fn parse_int(s: &str) -> Result<i128, ParseNumberError> {
+    if s.is_empty() {
+        return Ok(0);
+    }
+    let overflow = |i| ParseNumberError::new(ParseErrorKind::Overflow, i);
+    // 没有任何单位时逐位读出
+    if !s
+        .chars()
+        .any(|c| small_unit(c).is_some() || section_unit(c).is_some())
+    {
+        let mut value: i128 = 0;
+        for (i, c) in s.char_indices() {
+            let d = digit_value(c).ok_or(ParseNumberError::new(ParseErrorKind::InvalidDigit, i))?;
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(d as i128))
+                .ok_or(overflow(i))?;
+        }
+        return Ok(value);
+    }
+    // total 为已经乘上节单位的部分，section 为当前节内的值，digit 为尚未遇到单位的数字
+    let mut total: i128 = 0;
+    let mut section: i128 = 0;
+    let mut digit: Option<i128> = None;
+    for (i, c) in s.char_indices() {
+        if let Some(d) = digit_value(c) {
+            if d != 0 && digit.is_some_and(|d| d != 0) {
+                return Err(ParseNumberError::new(ParseErrorKind::InvalidDigit, i));
+            }
+            digit = Some(d as i128);
+        } else if let Some(unit) = small_unit(c) {
+            section += digit.take().unwrap_or(1) * unit;
+        } else if let Some(exponent) = section_unit(c) {
+            let unit = 10i128.checked_pow(exponent).ok_or(overflow(i))?;
+            section += digit.take().unwrap_or(0);
+            if section == 0 && total == 0 {
+                return Err(ParseNumberError::new(ParseErrorKind::InvalidDigit, i));
+            }
+            // 如“一万亿”：已有部分小于新的节单位时整体乘上去
+            total = if total < unit {
+                total.checked_add(section).and_then(|v| v.checked_mul(unit))
+            } else {
+                section.checked_mul(unit).and_then(|v| v.checked_add(total))
+            }
+            .ok_or(overflow(i))?;
+            section = 0;
+        } else {
+            return Err(ParseNumberError::new(ParseErrorKind::InvalidDigit, i));
+        }
+    }
+    total
+        .checked_add(section + digit.unwrap_or(0))
+        .ok_or(overflow(0))
+}
@@ -0,0 +1,252 @@
+// 二进制编码：1 字节标签 + 小端负载，标签与 abi 模块的 TAG_* 相同，属于稳定格式，不随版本改变。
+// 负载：Rational 为 i64 分子 + i64 分母，Decimal 为 i128 尾数 + u32 标度，
+// Fixed64 为 i64 原始值 + u8 小数位数，其余定宽变体为各自的小端字节；
+// Float16/BFloat16 为 u16 位模式，BigInteger（标签 16）为 u32 长度 + 小端补码字节。
+// Extended 无法还原，按其 f64 值编码为 Float64。
+// 解码时 Rational 会约分，分母为 0、Decimal 标度超过 38 或 Fixed64 小数位数超过 63 时报 OutOfRange。
+
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+
+use crate::Number;
+
+#[cfg(feature = "bigint")]
+const TAG_BIG_INTEGER: u8 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    UnexpectedEnd,
+    UnknownTag(u8),
+    InvalidUtf8,
+    UnsupportedVersion(u8),
+    TrailingBytes,
+    UnsupportedType(u8),
+    OutOfRange,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "input ended before the value was complete"),
+            DecodeError::UnknownTag(tag) => write!(f, "unknown or unsupported tag {}", tag),
+            DecodeError::InvalidUtf8 => write!(f, "string is not valid UTF-8"),
+            DecodeError::UnsupportedVersion(v) => write!(f, "unsupported format version {}", v),
+            DecodeError::TrailingBytes => write!(f, "unexpected bytes after the value"),
+            DecodeError::UnsupportedType(byte) => {
+                write!(f, "value starting with 0x{:02x} is not a number", byte)
+            }
+            DecodeError::OutOfRange => write!(f, "value does not fit any Number variant"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+pub(crate) fn write_number(out: &mut Vec<u8>, number: &Number) {
+    match number {
+        Number::PositiveInfinity => out.push(0),
+        Number::NegativeInfinity => out.push(1),
+        Number::NaN => out.push(2),
+        Number::Rational(n, d) => {
+            out.push(3);
+            out.extend_from_slice(&n.to_le_bytes());
+            out.extend_from_slice(&d.to_le_bytes());
+        }
+        Number::Decimal(m, scale) => {
+            out.push(4);
+            out.extend_from_slice(&m.to_le_bytes());
+            out.extend_from_slice(&scale.to_le_bytes());
+        }
+        Number::Fixed64 { raw, frac_bits } => {
+            out.push(5);
+            out.extend_from_slice(&raw.to_le_bytes());
+            out.push(*frac_bits);
+        }
+        Number::Integer128(v) => {
+            out.push(6);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        Number::UnsignedInteger64(v) => {
+            out.push(7);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        #[cfg(not(feature = "small"))]
+        Number::Integer64(v) => {
+            out.push(8);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        Number::Integer32(v) => {
+            out.push(9);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        Number::Integer16(v) => {
+            out.push(10);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        Number::Integer8(v) => {
+            out.push(11);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        Number::Float64(v) => {
+            out.push(12);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        Number::Float32(v) => {
+            out.push(13);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        #[cfg(feature = "half")]
+        Number::Float16(v) => {
+            out.push(14);
+            out.extend_from_slice(&v.to_bits().to_le_bytes());
+        }
+        #[cfg(feature = "half")]
+        Number::BFloat16(v) => {
+            out.push(15);
+            out.extend_from_slice(&v.to_bits().to_le_bytes());
+        }
+        #[cfg(feature = "bigint")]
+        Number::BigInteger(v) => {
+            let bytes = v.to_signed_bytes_le();
+            out.push(TAG_BIG_INTEGER);
+            out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            out.extend_from_slice(&bytes);
+        }
+        #[cfg(feature = "extended")]
+        Number::Extended(v) => {
+            out.push(12);
+            out.extend_from_slice(&v.to_f64().to_le_bytes());
+        }
+    }
+}
+
+impl Number {
+    // 按文件开头说明的布局编码，不依赖 serde，可以直接写入缓存、映射文件或网络协议
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_number(&mut out, self);
+        out
+    }
+    // bytes 必须恰好是一个值，多余的字节报 TrailingBytes
+    pub fn from_le_bytes(bytes: &[u8]) -> Result<Number, DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let number = reader.number()?;
+        reader.finish()?;
+        Ok(number)
+    }
+    // 从 bytes 开头读出一个值，同时返回占用的字节数，便于读取连续存放的多个值
+    pub fn read_le_bytes(bytes: &[u8]) -> Result<(Number, usize), DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let number = reader.number()?;
+        Ok((number, reader.position()))
+    }
+}
+
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, pos: 0 }
+    }
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        self.pos = end;
+        Ok(slice)
+    }
+    pub(crate) fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+    pub(crate) fn byte(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.array::<1>()?[0])
+    }
+    #[cfg(any(feature = "bigint", feature = "expr"))]
+    pub(crate) fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+    pub(crate) fn finish(&self) -> Result<(), DecodeError> {
+        if self.pos == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(DecodeError::TrailingBytes)
+        }
+    }
+    pub(crate) fn number(&mut self) -> Result<Number, DecodeError> {
+        let tag = self.byte()?;
+        Ok(match tag {
+            0 => Number::PositiveInfinity,
+            1 => Number::NegativeInfinity,
+            2 => Number::NaN,
+            3 => {
+                let numerator = i64::from_le_bytes(self.array()?);
+                let denominator = i64::from_le_bytes(self.array()?);
+                if denominator == 0 {
+                    return Err(DecodeError::OutOfRange);
+                }
+                Number::from_ratio(numerator as i128, denominator as i128)
+                    .ok_or(DecodeError::OutOfRange)?
+            }
+            4 => decimal(
+                i128::from_le_bytes(self.array()?),
+                u32::from_le_bytes(self.array()?),
+            )?,
+            5 => fixed(i64::from_le_bytes(self.array()?), self.byte()?)?,
+            6 => Number::Integer128(i128::from_le_bytes(self.array()?)),
+            7 => Number::UnsignedInteger64(u64::from_le_bytes(self.array()?)),
+            #[cfg(not(feature = "small"))]
+            8 => Number::Integer64(i64::from_le_bytes(self.array()?)),
+            // small 构建没有 Integer64，按 from_int 的规则收窄
+            #[cfg(feature = "small")]
+            8 => Number::from_i64(i64::from_le_bytes(self.array()?)),
+            9 => Number::Integer32(i32::from_le_bytes(self.array()?)),
+            10 => Number::Integer16(i16::from_le_bytes(self.array()?)),
+            11 => Number::Integer8(i8::from_le_bytes(self.array()?)),
+            12 => Number::Float64(f64::from_le_bytes(self.array()?)),
+            13 => Number::Float32(f32::from_le_bytes(self.array()?)),
+            #[cfg(feature = "half")]
+            14 => Number::Float16(f16::from_bits(u16::from_le_bytes(self.array()?))),
+            #[cfg(feature = "half")]
+            15 => Number::BFloat16(bf16::from_bits(u16::from_le_bytes(self.array()?))),
+            #[cfg(feature = "bigint")]
+            TAG_BIG_INTEGER => {
+                let len = self.u32()? as usize;
+                Number::BigInteger(BigInt::from_signed_bytes_le(self.take(len)?))
+            }
+            _ => return Err(DecodeError::UnknownTag(tag)),
+        })
+    }
+}
+
+// 解码得到的标度和小数位数必须在各自运算代码支持的范围内，否则格式化和运算会溢出
+pub(crate) fn decimal(mantissa: i128, scale: u32) -> Result<Number, DecodeError> {
+    if scale > crate::decimal::MAX_SCALE {
+        return Err(DecodeError::OutOfRange);
+    }
+    Ok(Number::Decimal(mantissa, scale))
+}
+
+pub(crate) fn fixed(raw: i64, frac_bits: u8) -> Result<Number, DecodeError> {
+    if frac_bits > crate::fixed::MAX_FRAC_BITS {
+        return Err(DecodeError::OutOfRange);
+    }
+    Ok(Number::Fixed64 { raw, frac_bits })
+}
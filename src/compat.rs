@@ -0,0 +1,75 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::Number;
+
+// f64 和 Number 共有的算术接口，Formula 针对它编写一次即可分别在两种类型上运行
+pub trait Scalar:
+    Clone + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(&self) -> f64;
+}
+
+impl Scalar for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+}
+
+impl Scalar for Number {
+    fn from_f64(value: f64) -> Self {
+        Number::wrap(value)
+    }
+    fn to_f64(&self) -> f64 {
+        Number::to_f64(self)
+    }
+}
+
+pub trait Formula {
+    fn eval<T: Scalar>(&self, inputs: &[T]) -> T;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub inputs: Vec<f64>,
+    pub expected: f64,
+    pub actual: Number,
+    pub difference: f64,
+}
+
+// 分别用 f64 和 Number 计算 formula，相对误差超过 tolerance 时返回差异
+pub fn compare<F: Formula>(formula: &F, inputs: &[f64], tolerance: f64) -> Option<Divergence> {
+    let expected = formula.eval(inputs);
+    let actual = formula.eval(&Number::wrap_slice(inputs));
+    let value = actual.to_f64();
+    let agrees = if expected.is_nan() || value.is_nan() {
+        expected.is_nan() && value.is_nan()
+    } else if expected.is_infinite() || value.is_infinite() {
+        expected == value
+    } else {
+        (expected - value).abs() <= tolerance * expected.abs().max(1.0)
+    };
+    if agrees {
+        return None;
+    }
+    Some(Divergence {
+        inputs: inputs.to_vec(),
+        expected,
+        actual,
+        difference: (expected - value).abs(),
+    })
+}
+
+pub fn compare_cases<F: Formula>(
+    formula: &F,
+    cases: &[Vec<f64>],
+    tolerance: f64,
+) -> Vec<Divergence> {
+    cases
+        .iter()
+        .filter_map(|inputs| compare(formula, inputs, tolerance))
+        .collect()
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Number, ParseNumberError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexNumber {
+    pub re: Number,
+    pub im: Number,
+}
+
+impl ComplexNumber {
+    pub fn new(re: Number, im: Number) -> Self {
+        ComplexNumber { re, im }
+    }
+    pub fn from_polar(modulus: &Number, argument: &Number) -> Self {
+        let (r, theta) = (modulus.to_f64(), argument.to_f64());
+        ComplexNumber {
+            re: Number::from_f64(r * theta.cos()),
+            im: Number::from_f64(r * theta.sin()),
+        }
+    }
+    // 错误的 position 是在整个输入 s 中的字节偏移
+    pub fn parse(s: &str) -> Result<Self, ParseNumberError> {
+        let lead = s.len() - s.trim_start().len();
+        let trimmed = s.trim();
+        let part = |text: &str, at: usize| Number::parse(text).map_err(|err| err.offset(lead + at));
+        let Some(body) = trimmed.strip_suffix('i') else {
+            return Ok(ComplexNumber::new(part(trimmed, 0)?, Number::from(0)));
+        };
+        // 虚部的符号是最后一个不紧跟在指数记号 e/E 之后的 +/-
+        let split = body
+            .char_indices()
+            .skip(1)
+            .filter(|&(i, c)| {
+                (c == '+' || c == '-') && !matches!(body.as_bytes()[i - 1], b'e' | b'E')
+            })
+            .map(|(i, _)| i)
+            .last();
+        let (re, im, at) = match split {
+            Some(i) => (&body[..i], &body[i..], i),
+            None => ("", body, 0),
+        };
+        let re = if re.is_empty() {
+            Number::from(0)
+        } else {
+            part(re, 0)?
+        };
+        let im = match im {
+            "" | "+" => Number::from(1),
+            "-" => Number::from(-1),
+            _ => part(im, at)?,
+        };
+        Ok(ComplexNumber::new(re, im))
+    }
+    pub fn abs(&self) -> Number {
+        Number::from_f64(self.re.to_f64().hypot(self.im.to_f64()))
+    }
+    pub fn arg(&self) -> Number {
+        Number::from_f64(self.im.to_f64().atan2(self.re.to_f64()))
+    }
+    pub fn conj(&self) -> Self {
+        ComplexNumber::new(self.re.clone(), Number::from(-1) * self.im.clone())
+    }
+    pub fn is_real(&self) -> bool {
+        self.im == Number::from(0)
+    }
+}
+
+impl Number {
+    pub fn sqrt_complex(&self) -> ComplexNumber {
+        let x = self.to_f64();
+        if x < 0.0 {
+            ComplexNumber::new(Number::from(0), Number::from_f64((-x).sqrt()))
+        } else {
+            ComplexNumber::new(Number::from_f64(x.sqrt()), Number::from(0))
+        }
+    }
+    pub fn ln_complex(&self) -> ComplexNumber {
+        let x = self.to_f64();
+        if x < 0.0 {
+            ComplexNumber::new(
+                Number::from_f64((-x).ln()),
+                Number::from_f64(std::f64::consts::PI),
+            )
+        } else {
+            ComplexNumber::new(Number::from_f64(x.ln()), Number::from(0))
+        }
+    }
+    // 负底数的非整数次幂取主值 |x|^y·(cos πy + i·sin πy)，其余情况的实部与 pow 相同
+    pub fn pow_complex(&self, exponent: impl Into<Number>) -> ComplexNumber {
+        let exponent = exponent.into();
+        let (x, y) = (self.to_f64(), exponent.to_f64());
+        if x < 0.0 && y.fract() != 0.0 && y.is_finite() {
+            let modulus = (-x).powf(y);
+            let (sin, cos) = (std::f64::consts::PI * y).sin_cos();
+            ComplexNumber::new(
+                Number::from_f64(modulus * cos),
+                Number::from_f64(modulus * sin),
+            )
+        } else {
+            ComplexNumber::new(self.pow(exponent), Number::from(0))
+        }
+    }
+}
+
+impl fmt::Display for ComplexNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < Number::from(0) {
+            write!(f, "{}-{}i", self.re, Number::from(0) - self.im.clone())
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl From<Number> for ComplexNumber {
+    fn from(re: Number) -> Self {
+        ComplexNumber::new(re, Number::from(0))
+    }
+}
+
+impl Neg for ComplexNumber {
+    type Output = ComplexNumber;
+
+    fn neg(self) -> ComplexNumber {
+        ComplexNumber::new(Number::from(-1) * self.re, Number::from(-1) * self.im)
+    }
+}
+
+impl Add for ComplexNumber {
+    type Output = ComplexNumber;
+
+    fn add(self, rhs: ComplexNumber) -> ComplexNumber {
+        ComplexNumber::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for ComplexNumber {
+    type Output = ComplexNumber;
+
+    fn sub(self, rhs: ComplexNumber) -> ComplexNumber {
+        ComplexNumber::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for ComplexNumber {
+    type Output = ComplexNumber;
+
+    fn mul(self, rhs: ComplexNumber) -> ComplexNumber {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        ComplexNumber::new(a.clone() * c.clone() - b.clone() * d.clone(), a * d + b * c)
+    }
+}
+
+impl Div for ComplexNumber {
+    type Output = ComplexNumber;
+
+    fn div(self, rhs: ComplexNumber) -> ComplexNumber {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        let denom = c.clone() * c.clone() + d.clone() * d.clone();
+        ComplexNumber::new(
+            (a.clone() * c.clone() + b.clone() * d.clone()) / denom.clone(),
+            (b * c - a * d) / denom,
+        )
+    }
+}
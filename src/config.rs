@@ -0,0 +1,324 @@
+use std::cell::RefCell;
+use std::fmt;
+#[cfg(feature = "format")]
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+use crate::Number;
+use crate::display::RoundTrip;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumberConfig {
+    pub parse: ParseConfig,
+    pub format: FormatConfig,
+    pub overflow: OverflowPolicy,
+    // 为 true 时 0/0、∞-∞ 等无效运算返回记录来源的 NaN（见 Number::nan_origin），默认返回 Number::NaN
+    pub nan_origins: bool,
+}
+
+// 整数加减乘超出 64 位（Integer128 参与时为 128 位）范围时的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    // 转为 f64，可能丢失精度
+    PromoteFloat,
+    // 升级到 Integer128，再不够时升级到 BigInteger（需启用 bigint）
+    #[default]
+    PromoteWide,
+    // 结果为 NaN
+    Error,
+    // 截断到范围的最小值或最大值
+    Saturate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConfig {
+    // 带小数点或指数的字面量解析为精确的 Decimal 而不是浮点数
+    pub exact_decimals: bool,
+    // 整数部分的千位分组符，分组必须是首组 1 到 3 位、其后每组 3 位；None 表示不接受分组。
+    // 空格与不换行空格、' 与 ’ 视为同一种分组符。'_' 总是可以出现在数字之间
+    pub group_separator: Option<char>,
+    // 小数点，改为 ',' 时 "3,14" 为 3.14，此时分组符通常设为 Some('.')
+    pub decimal_separator: char,
+    // 接受 k、M、G、T、m、µ（或 u）、n 数量级后缀，例如 "10k" 为 10000，"7µ" 为 0.000007
+    pub si_suffixes: bool,
+    // 接受百分数，"15%" 为 0.15
+    pub percent: bool,
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        ParseConfig {
+            exact_decimals: false,
+            group_separator: Some(','),
+            decimal_separator: '.',
+            si_suffixes: false,
+            percent: false,
+        }
+    }
+}
+
+impl ParseConfig {
+    // 按区域标签（"de"、"fr-FR"、"de_CH" 等，不区分大小写）选择小数点和分组符，
+    // 不认识的区域返回 None
+    pub fn locale(tag: &str) -> Option<ParseConfig> {
+        let tag = tag.to_ascii_lowercase().replace('_', "-");
+        let language = tag.split('-').next().unwrap_or_default();
+        let (decimal_separator, group_separator) = match tag.as_str() {
+            "de-ch" | "fr-ch" | "it-ch" | "rm-ch" | "de-li" => ('.', '\''),
+            "es-mx" | "es-us" => ('.', ','),
+            "pt-pt" => (',', ' '),
+            _ => match language {
+                "en" | "ja" | "zh" | "ko" | "he" | "th" | "ms" => ('.', ','),
+                "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr"
+                | "sl" | "sr" | "vi" => (',', '.'),
+                "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "nn" | "no" | "uk"
+                | "hu" | "bg" | "et" | "lv" | "lt" | "kk" => (',', ' '),
+                _ => return None,
+            },
+        };
+        Some(ParseConfig {
+            group_separator: Some(group_separator),
+            decimal_separator,
+            ..ParseConfig::default()
+        })
+    }
+}
+
+// Number::format_with 的单次选项，区域表与 ParseConfig::locale 相同
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    // 整数部分每三位插入的分组符，None 表示不分组
+    pub group_separator: Option<char>,
+    pub decimal_separator: char,
+    // 至少保留的小数位数，不足时补 0
+    pub min_fraction_digits: usize,
+    // 至多保留的小数位数，超出时按精确值舍入；None 表示不限制
+    pub max_fraction_digits: Option<usize>,
+    // NaN 和正负无穷的文本，None 表示与 Display 相同
+    pub non_finite_tokens: Option<NonFiniteTokens>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            group_separator: Some(','),
+            decimal_separator: '.',
+            min_fraction_digits: 0,
+            max_fraction_digits: None,
+            non_finite_tokens: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub fn locale(tag: &str) -> Option<FormatOptions> {
+        let parse = ParseConfig::locale(tag)?;
+        Some(FormatOptions {
+            group_separator: parse.group_separator,
+            decimal_separator: parse.decimal_separator,
+            ..FormatOptions::default()
+        })
+    }
+    // 能读回 format_with 输出的解析选项
+    pub fn parse_config(&self) -> ParseConfig {
+        ParseConfig {
+            group_separator: self.group_separator,
+            decimal_separator: self.decimal_separator,
+            ..ParseConfig::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatConfig {
+    // 浮点变体输出时固定保留的小数位数
+    pub float_precision: Option<usize>,
+    // 序列化时 NaN 和正负无穷的表示方式
+    pub non_finite: NonFiniteRepr,
+    // Display 输出 NaN 和正负无穷时使用的文本
+    pub non_finite_tokens: NonFiniteTokens,
+    // 浮点变体改用指数形式的数量级界限，None 表示总是写成普通小数
+    pub scientific: Option<ScientificThreshold>,
+    // 设置后 Display 按 format_with 的规则输出所有变体，见 Number::set_default_format。
+    // 装箱以免 NumberConfig 过大
+    #[cfg(feature = "format")]
+    pub options: Option<Box<FormatOptions>>,
+}
+
+// 十进制指数 e（1.5e20 的 e 为 20）满足 e >= upper 或 e < lower 时 Display 输出指数形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScientificThreshold {
+    pub upper: i16,
+    pub lower: i16,
+}
+
+impl ScientificThreshold {
+    // 与 Python 的 repr 相同：1e16 及以上、小于 1e-4 时用指数形式
+    pub const PYTHON: ScientificThreshold = ScientificThreshold {
+        upper: 16,
+        lower: -4,
+    };
+}
+
+impl Default for ScientificThreshold {
+    fn default() -> Self {
+        ScientificThreshold::PYTHON
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum NonFiniteTokens {
+    // ∞、-∞、NaN
+    #[default]
+    Unicode,
+    // Infinity、-Infinity、NaN，只含 ASCII，适合终端、日志和 CSV
+    Ascii,
+    Custom {
+        infinity: String,
+        negative_infinity: String,
+        nan: String,
+    },
+}
+
+impl NonFiniteTokens {
+    pub fn infinity(&self) -> &str {
+        match self {
+            NonFiniteTokens::Unicode => "∞",
+            NonFiniteTokens::Ascii => "Infinity",
+            NonFiniteTokens::Custom { infinity, .. } => infinity,
+        }
+    }
+    pub fn negative_infinity(&self) -> &str {
+        match self {
+            NonFiniteTokens::Unicode => "-∞",
+            NonFiniteTokens::Ascii => "-Infinity",
+            NonFiniteTokens::Custom {
+                negative_infinity, ..
+            } => negative_infinity,
+        }
+    }
+    pub fn nan(&self) -> &str {
+        match self {
+            NonFiniteTokens::Unicode | NonFiniteTokens::Ascii => "NaN",
+            NonFiniteTokens::Custom { nan, .. } => nan,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NonFiniteRepr {
+    // 字符串 "NaN"、"Infinity"、"-Infinity"，与 JavaScript 的写法相同
+    #[default]
+    Strings,
+    // null，反序列化时读作 NaN
+    Null,
+}
+
+static GLOBAL: OnceLock<NumberConfig> = OnceLock::new();
+
+thread_local! {
+    // with_config 压入的局部配置，栈顶优先于全局配置
+    static SCOPED: RefCell<Vec<NumberConfig>> = const { RefCell::new(Vec::new()) };
+}
+
+// 闭包结束（包括 panic 展开）时弹出局部配置
+struct ScopeGuard;
+
+// set_default_format 返回的守卫，离开作用域时恢复之前的配置。
+// 配置保存在线程局部的栈中，所以守卫不能移到其他线程，嵌套时应按相反的顺序释放
+#[cfg(feature = "format")]
+pub struct DefaultFormatGuard {
+    _scope: ScopeGuard,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|stack| stack.borrow_mut().pop());
+    }
+}
+
+impl NumberConfig {
+    // 进程级默认配置只能设置一次，应在启动时调用；已设置过时原样返回传入的配置
+    pub fn set_global(config: NumberConfig) -> Result<(), NumberConfig> {
+        GLOBAL.set(config)
+    }
+    pub fn global() -> &'static NumberConfig {
+        GLOBAL.get_or_init(NumberConfig::default)
+    }
+    // 当前线程生效的配置：最内层 with_config 的配置，否则为全局配置
+    pub fn current() -> NumberConfig {
+        with_current(NumberConfig::clone)
+    }
+}
+
+pub(crate) fn with_current<R>(f: impl FnOnce(&NumberConfig) -> R) -> R {
+    SCOPED.with(|stack| match stack.borrow().last() {
+        Some(config) => f(config),
+        None => f(NumberConfig::global()),
+    })
+}
+
+impl Number {
+    // 在闭包执行期间让当前线程使用 config，可以嵌套
+    pub fn with_config<R>(config: NumberConfig, f: impl FnOnce() -> R) -> R {
+        SCOPED.with(|stack| stack.borrow_mut().push(config));
+        let _guard = ScopeGuard;
+        f()
+    }
+    pub fn to_string_with_config(&self, config: &NumberConfig) -> String {
+        Configured(self, config).to_string()
+    }
+    // 在守卫存活期间让当前线程的 Display 按 options 输出，其余配置沿用当前配置。
+    // 需要整个进程生效时，把 FormatConfig::options 放进 NumberConfig::set_global 的配置
+    #[cfg(feature = "format")]
+    pub fn set_default_format(options: FormatOptions) -> DefaultFormatGuard {
+        let mut config = NumberConfig::current();
+        config.format.options = Some(Box::new(options));
+        SCOPED.with(|stack| stack.borrow_mut().push(config));
+        DefaultFormatGuard {
+            _scope: ScopeGuard,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+struct Configured<'a>(&'a Number, &'a NumberConfig);
+
+impl fmt::Display for Configured<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_with_config(f, self.1)
+    }
+}
+
+// 不应用 FormatConfig::options 的 Display
+pub(crate) struct Plain<'a>(pub(crate) &'a Number, pub(crate) &'a NumberConfig);
+
+impl fmt::Display for Plain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_plain(f, self.1)
+    }
+}
+
+impl Number {
+    // 需要再解析数字文本的内部转换使用，不受 set_default_format 的分组符等影响
+    pub(crate) fn plain_string(&self) -> String {
+        with_current(|config| Plain(self, config).to_string())
+    }
+}
+
+pub(crate) fn fmt_float(
+    f: &mut fmt::Formatter<'_>,
+    value: RoundTrip,
+    config: &NumberConfig,
+) -> fmt::Result {
+    if let Some(threshold) = config.format.scientific
+        && let Some(result) = value.write_scientific(f, threshold, config.format.float_precision)
+    {
+        return result;
+    }
+    match config.format.float_precision {
+        Some(precision) => write!(f, "{:.*}", precision, value),
+        None => write!(f, "{}", value),
+    }
+}
@@ -0,0 +1,162 @@
+use crate::rates::{ZeroDivisionPolicy, divide_by_zero};
+use crate::{ArithmeticError, BinOp, Number, OverflowPolicy};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    #[default]
+    HalfEven,
+    HalfUp,
+    Down,
+    Floor,
+    Ceiling,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IntegerDivision {
+    // 不能整除时给出有理数或浮点数（与 / 运算符相同）
+    #[default]
+    Promote,
+    // 向零取整，如 C 和 Rust
+    Truncate,
+    // 向负无穷取整，如 Python 的 //
+    Floor,
+}
+
+// 一组完整的运算语义：溢出、除零、整数除法和结果舍入都由上下文决定
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberContext {
+    pub overflow: OverflowPolicy,
+    pub division_by_zero: ZeroDivisionPolicy,
+    pub integer_division: IntegerDivision,
+    pub rounding: RoundingMode,
+    // 非整数结果保留的小数位数，None 表示不舍入
+    pub scale: Option<u32>,
+}
+
+impl NumberContext {
+    pub fn add(&self, a: &Number, b: &Number) -> Result<Number, ArithmeticError> {
+        self.integer_step(a, b, BinOp::Add)
+    }
+    pub fn sub(&self, a: &Number, b: &Number) -> Result<Number, ArithmeticError> {
+        self.integer_step(a, b, BinOp::Sub)
+    }
+    pub fn mul(&self, a: &Number, b: &Number) -> Result<Number, ArithmeticError> {
+        self.integer_step(a, b, BinOp::Mul)
+    }
+    pub fn div(&self, a: &Number, b: &Number) -> Result<Number, ArithmeticError> {
+        if a.is_nan() || b.is_nan() {
+            return Ok(Number::NaN);
+        }
+        if *b == Number::from(0) {
+            return divide_by_zero(a, self.division_by_zero);
+        }
+        if let (Some(x), Some(y)) = (a.int_value(), b.int_value())
+            && self.integer_division != IntegerDivision::Promote
+        {
+            let quotient = match self.integer_division {
+                IntegerDivision::Floor => floor_div(x, y),
+                _ => x.checked_div(y),
+            };
+            return quotient
+                .map(Number::from_wide_int)
+                .ok_or(ArithmeticError::Overflow);
+        }
+        if let Some(scale) = self.scale
+            && let (Some((an, ad)), Some((bn, bd))) = (a.exact_ratio(), b.exact_ratio())
+            && let (Some(n), Some(d)) = (an.checked_mul(bd), ad.checked_mul(bn))
+            && let Some(result) = round_ratio(n, d, scale, self.rounding)
+        {
+            return Ok(result);
+        }
+        self.round(&(a.clone() / b.clone()))
+    }
+    pub fn rem(&self, a: &Number, b: &Number) -> Result<Number, ArithmeticError> {
+        if a.is_nan() || b.is_nan() {
+            return Ok(Number::NaN);
+        }
+        if *b == Number::from(0) {
+            return divide_by_zero(a, self.division_by_zero);
+        }
+        let mut result = a.clone();
+        result %= b.clone();
+        self.round(&result)
+    }
+    // 按 rounding 和 scale 舍入；整数、非有限值和未设置 scale 时原样返回
+    pub fn round(&self, n: &Number) -> Result<Number, ArithmeticError> {
+        let Some(scale) = self.scale else {
+            return Ok(n.clone());
+        };
+        if !n.is_finite() || n.is_integer_variant() {
+            return Ok(n.clone());
+        }
+        if let Some((numerator, denominator)) = n.exact_ratio()
+            && let Some(result) = round_ratio(numerator, denominator, scale, self.rounding)
+        {
+            return Ok(result);
+        }
+        Err(ArithmeticError::InexactResult)
+    }
+    fn integer_step(&self, a: &Number, b: &Number, op: BinOp) -> Result<Number, ArithmeticError> {
+        if a.is_nan() || b.is_nan() {
+            return Ok(Number::NaN);
+        }
+        if let Some(result) = a.op_with_policy(b, op, self.overflow) {
+            // 整数操作数不是 NaN，策略给出 NaN 只可能是溢出
+            return if result.is_nan() {
+                Err(ArithmeticError::Overflow)
+            } else {
+                Ok(result)
+            };
+        }
+        let result = match op {
+            BinOp::Add => a.clone() + b.clone(),
+            BinOp::Sub => a.clone() - b.clone(),
+            _ => a.clone() * b.clone(),
+        };
+        self.round(&result)
+    }
+}
+
+fn floor_div(a: i128, b: i128) -> Option<i128> {
+    let quotient = a.checked_div(b)?;
+    if a % b != 0 && ((a < 0) != (b < 0)) {
+        Some(quotient - 1)
+    } else {
+        Some(quotient)
+    }
+}
+
+fn round_ratio(
+    numerator: i128,
+    denominator: i128,
+    scale: u32,
+    mode: RoundingMode,
+) -> Option<Number> {
+    let (numerator, denominator) = if denominator < 0 {
+        (numerator.checked_neg()?, denominator.checked_neg()?)
+    } else {
+        (numerator, denominator)
+    };
+    let scaled = numerator.checked_mul(10i128.checked_pow(scale)?)?;
+    let quotient = scaled / denominator;
+    let remainder = scaled % denominator;
+    if remainder == 0 {
+        return Some(Number::decimal(quotient, scale));
+    }
+    let negative = scaled < 0;
+    let twice = remainder.unsigned_abs() * 2;
+    let magnitude = denominator as u128;
+    let away = match mode {
+        RoundingMode::Down => false,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::HalfUp => twice >= magnitude,
+        RoundingMode::HalfEven => twice > magnitude || (twice == magnitude && quotient % 2 != 0),
+    };
+    let rounded = match (away, negative) {
+        (false, _) => quotient,
+        (true, false) => quotient + 1,
+        (true, true) => quotient - 1,
+    };
+    Some(Number::decimal(rounded, scale))
+}
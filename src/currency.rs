@@ -0,0 +1,203 @@
+use crate::{Number, ParseErrorKind, ParseNumberError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyAmount {
+    pub value: Number,
+    // ISO 4217 代码；没有货币符号时为 None
+    pub currency: Option<String>,
+}
+
+// 较长的符号放在前面，保证 "US$" 先于 "$" 匹配。¥ 在日元和人民币中都有使用，
+// 这里按 Unicode 的惯例把半角 ¥ 当作日元，全角 ￥ 和“元”当作人民币
+const SYMBOLS: [(&str, &str); 24] = [
+    ("US$", "USD"),
+    ("HK$", "HKD"),
+    ("NT$", "TWD"),
+    ("NZ$", "NZD"),
+    ("R$", "BRL"),
+    ("C$", "CAD"),
+    ("A$", "AUD"),
+    ("S$", "SGD"),
+    ("$", "USD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("¥", "JPY"),
+    ("￥", "CNY"),
+    ("元", "CNY"),
+    ("₹", "INR"),
+    ("₩", "KRW"),
+    ("₽", "RUB"),
+    ("₺", "TRY"),
+    ("₫", "VND"),
+    ("₪", "ILS"),
+    ("₴", "UAH"),
+    ("₱", "PHP"),
+    ("฿", "THB"),
+    ("zł", "PLN"),
+];
+
+impl Number {
+    // 去掉货币符号（或 "EUR" 这样的三字母代码）、符号和分组符后解析金额，例如
+    // "$1,234.56"、"€-12,30"、"¥1000"、"1.234,50 EUR"、"(45.00)"。
+    // 同时出现 '.' 和 ',' 时靠后的是小数点；只出现一种且恰好出现一次时，其后不是三位数字才当作小数点。
+    // 金额为 Decimal，"12,30" 为 Decimal(1230, 2)
+    pub fn parse_currency(s: &str) -> Result<CurrencyAmount, ParseNumberError> {
+        let mut start = s.len() - s.trim_start().len();
+        let mut end = s.trim_end().len();
+        if start >= end {
+            return Err(ParseNumberError::new(ParseErrorKind::Empty, 0));
+        }
+        let mut negative = false;
+        let mut currency = None;
+        // 会计写法用括号表示负数
+        if s[start..end].starts_with('(') && s[start..end].ends_with(')') {
+            negative = true;
+            start += 1;
+            end -= 1;
+        }
+        loop {
+            let rest = &s[start..end];
+            let trimmed = rest.trim_start();
+            if trimmed.len() < rest.len() {
+                start += rest.len() - trimmed.len();
+            } else if let Some(c) = rest.chars().next().filter(|&c| is_sign(c)) {
+                negative ^= c != '+';
+                start += c.len_utf8();
+            } else if currency.is_none()
+                && let Some((len, code)) = prefix_currency(rest)
+            {
+                currency = Some(code);
+                start += len;
+            } else {
+                break;
+            }
+        }
+        loop {
+            let rest = &s[start..end];
+            let trimmed = rest.trim_end();
+            if trimmed.len() < rest.len() {
+                end -= rest.len() - trimmed.len();
+            } else if let Some(c) = rest.chars().next_back().filter(|&c| c == '-' || c == '−') {
+                negative = !negative;
+                end -= c.len_utf8();
+            } else if currency.is_none()
+                && let Some((len, code)) = suffix_currency(rest)
+            {
+                currency = Some(code);
+                end -= len;
+            } else {
+                break;
+            }
+        }
+        let body = &s[start..end];
+        if body.is_empty() {
+            return Err(ParseNumberError::new(ParseErrorKind::Empty, start));
+        }
+        let decimal = decimal_separator(body);
+        let mut ascii = String::with_capacity(body.len() + 1);
+        if negative {
+            ascii.push('-');
+        }
+        // 小数点字符不能同时用作分组符
+        let decimal_char = decimal.and_then(|i| body[i..].chars().next());
+        let mut seen_decimal = false;
+        // 与 sniff 相同的分组规则：第一组 1–3 位，之后每组恰好 3 位
+        let (mut group_digits, mut last_separator) = (0, None);
+        let bad_last_group = |last_separator: Option<usize>, group_digits| {
+            last_separator
+                .filter(|_| group_digits != 3)
+                .map(|i| ParseNumberError::new(ParseErrorKind::InvalidDigit, start + i))
+        };
+        for (i, c) in body.char_indices() {
+            if c.is_ascii_digit() {
+                ascii.push(c);
+                group_digits += 1;
+            } else if Some(i) == decimal {
+                if let Some(err) = bad_last_group(last_separator, group_digits) {
+                    return Err(err);
+                }
+                ascii.push('.');
+                seen_decimal = true;
+            } else if !seen_decimal
+                && Some(c) != decimal_char
+                && matches!(c, '.' | ',' | ' ' | '\u{a0}' | '\u{202f}' | '\'' | '’')
+            {
+                // 分组符
+                let valid = match last_separator {
+                    None => (1..=3).contains(&group_digits),
+                    Some(_) => group_digits == 3,
+                };
+                if !valid {
+                    return Err(ParseNumberError::new(
+                        ParseErrorKind::InvalidDigit,
+                        start + i,
+                    ));
+                }
+                (group_digits, last_separator) = (0, Some(i));
+            } else {
+                return Err(ParseNumberError::new(
+                    ParseErrorKind::InvalidDigit,
+                    start + i,
+                ));
+            }
+        }
+        if !seen_decimal && let Some(err) = bad_last_group(last_separator, group_digits) {
+            return Err(err);
+        }
+        if !body.bytes().any(|b| b.is_ascii_digit()) {
+            return Err(ParseNumberError::new(ParseErrorKind::InvalidDigit, start));
+        }
+        // ascii 已经规范为 '.' 小数点，按精确的 Decimal 解析，不受当前线程的区域设置影响
+        let value =
+            Number::parse_decimal(&ascii).map_err(|err| ParseNumberError::new(err.kind, start))?;
+        Ok(CurrencyAmount { value, currency })
+    }
+}
+
+fn is_sign(c: char) -> bool {
+    matches!(c, '-' | '+' | '−')
+}
+
+fn prefix_currency(s: &str) -> Option<(usize, String)> {
+    if let Some((symbol, code)) = SYMBOLS.iter().find(|(symbol, _)| s.starts_with(symbol)) {
+        return Some((symbol.len(), code.to_string()));
+    }
+    let code = s.get(..3)?;
+    let next = s[3..].chars().next();
+    (is_iso_code(code) && !next.is_some_and(|c| c.is_ascii_alphabetic()))
+        .then(|| (3, code.to_string()))
+}
+
+fn suffix_currency(s: &str) -> Option<(usize, String)> {
+    if let Some((symbol, code)) = SYMBOLS.iter().find(|(symbol, _)| s.ends_with(symbol)) {
+        return Some((symbol.len(), code.to_string()));
+    }
+    let code = s.get(s.len().checked_sub(3)?..)?;
+    let before = s[..s.len() - 3].chars().next_back();
+    (is_iso_code(code) && !before.is_some_and(|c| c.is_ascii_alphabetic()))
+        .then(|| (3, code.to_string()))
+}
+
+fn is_iso_code(s: &str) -> bool {
+    s.len() == 3 && s.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+// 返回小数点在 body 中的字节位置
+fn decimal_separator(body: &str) -> Option<usize> {
+    let last_dot = body.rfind('.');
+    let last_comma = body.rfind(',');
+    let position = match (last_dot, last_comma) {
+        (None, None) => return None,
+        (Some(dot), Some(comma)) => return Some(dot.max(comma)),
+        (Some(position), None) | (None, Some(position)) => position,
+    };
+    let separator = &body[position..position + 1];
+    let digits_after = body[position + 1..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+    let single = body.matches(separator).count() == 1;
+    // "0,125" 之类整数部分为 0 的写法不可能是分组
+    let leading_zero = body[..position].trim_start_matches('0').is_empty();
+    (single && (digits_after != 3 || leading_zero)).then_some(position)
+}
@@ -0,0 +1,87 @@
+// {:?} 与派生的 Debug 相同；{:#?} 在一行内额外写出存储细节，便于排查类型提升问题：
+// 浮点数附带位模式，如 Number::Float32(1.5) [0x3FC00000]，整数附带宽度，
+// 精确类型附带十进制值
+use std::fmt;
+
+use crate::Number;
+
+impl fmt::Debug for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return self.fmt_detailed(f);
+        }
+        match self {
+            Number::PositiveInfinity => f.write_str("PositiveInfinity"),
+            Number::NegativeInfinity => f.write_str("NegativeInfinity"),
+            Number::NaN => f.write_str("NaN"),
+            Number::Rational(n, d) => f.debug_tuple("Rational").field(n).field(d).finish(),
+            Number::Decimal(m, scale) => f.debug_tuple("Decimal").field(m).field(scale).finish(),
+            Number::Fixed64 { raw, frac_bits } => f
+                .debug_struct("Fixed64")
+                .field("raw", raw)
+                .field("frac_bits", frac_bits)
+                .finish(),
+            Number::Integer128(v) => f.debug_tuple("Integer128").field(v).finish(),
+            Number::UnsignedInteger64(v) => f.debug_tuple("UnsignedInteger64").field(v).finish(),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => f.debug_tuple("Integer64").field(v).finish(),
+            Number::Integer32(v) => f.debug_tuple("Integer32").field(v).finish(),
+            Number::Integer16(v) => f.debug_tuple("Integer16").field(v).finish(),
+            Number::Integer8(v) => f.debug_tuple("Integer8").field(v).finish(),
+            Number::Float64(v) => f.debug_tuple("Float64").field(v).finish(),
+            Number::Float32(v) => f.debug_tuple("Float32").field(v).finish(),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => f.debug_tuple("Float16").field(v).finish(),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => f.debug_tuple("BFloat16").field(v).finish(),
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => f.debug_tuple("BigInteger").field(v).finish(),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => f.debug_tuple("Extended").field(v).finish(),
+        }
+    }
+}
+
+impl Number {
+    fn fmt_detailed(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::PositiveInfinity => f.write_str("Number::PositiveInfinity"),
+            Number::NegativeInfinity => f.write_str("Number::NegativeInfinity"),
+            Number::NaN => f.write_str("Number::NaN"),
+            Number::Rational(n, d) => {
+                write!(f, "Number::Rational({}, {}) [{}]", n, d, self.to_f64())
+            }
+            Number::Decimal(m, scale) => {
+                write!(f, "Number::Decimal({}, {}) [{}]", m, scale, self)
+            }
+            Number::Fixed64 { raw, frac_bits } => write!(
+                f,
+                "Number::Fixed64 {{ raw: {}, frac_bits: {} }} [Q{}.{}, {}]",
+                raw,
+                frac_bits,
+                64 - *frac_bits as u32,
+                frac_bits,
+                self
+            ),
+            Number::Integer128(v) => write!(f, "Number::Integer128({}) [i128]", v),
+            Number::UnsignedInteger64(v) => write!(f, "Number::UnsignedInteger64({}) [u64]", v),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => write!(f, "Number::Integer64({}) [i64]", v),
+            Number::Integer32(v) => write!(f, "Number::Integer32({}) [i32]", v),
+            Number::Integer16(v) => write!(f, "Number::Integer16({}) [i16]", v),
+            Number::Integer8(v) => write!(f, "Number::Integer8({}) [i8]", v),
+            Number::Float64(v) => write!(f, "Number::Float64({:?}) [{:#018X}]", v, v.to_bits()),
+            Number::Float32(v) => write!(f, "Number::Float32({:?}) [{:#010X}]", v, v.to_bits()),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => write!(f, "Number::Float16({:?}) [{:#06X}]", v, v.to_bits()),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => write!(f, "Number::BFloat16({:?}) [{:#06X}]", v, v.to_bits()),
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => {
+                write!(f, "Number::BigInteger({}) [{} bits]", v, v.bits())
+            }
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => write!(f, "Number::Extended({:?}) [{}]", v, v.type_name()),
+        }
+    }
+}
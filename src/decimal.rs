@@ -0,0 +1,159 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::num::IntErrorKind;
+
+use crate::parse::{self, ParseErrorKind};
+use crate::{BinOp, Number, ParseNumberError};
+
+pub(crate) const MAX_SCALE: u32 = 38;
+const DIV_SCALE: u32 = 28;
+
+impl Number {
+    pub fn decimal(mantissa: i128, scale: u32) -> Number {
+        if scale > MAX_SCALE {
+            return Number::from_float(mantissa as f64 / 10f64.powi(scale as i32));
+        }
+        Number::Decimal(mantissa, scale)
+    }
+    pub fn parse_decimal(s: &str) -> Result<Self, ParseNumberError> {
+        let text = s.trim();
+        let leading = s.len() - s.trim_start().len();
+        let overflow = ParseNumberError::new(ParseErrorKind::Overflow, leading);
+        let syntax = || parse::syntax_error(text).offset(leading);
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (significand, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((significand, exponent)) => match exponent.parse::<i32>() {
+                Ok(exponent) => (significand, exponent),
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+                    ) =>
+                {
+                    return Err(overflow);
+                }
+                Err(_) => return Err(syntax()),
+            },
+            None => (unsigned, 0),
+        };
+        let (int_part, frac_part) = significand.split_once('.').unwrap_or((significand, ""));
+        let digits = format!("{}{}", int_part, frac_part);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            // 非数字输入（inf、nan 等）交给普通解析
+            return Number::parse(text).map_err(|err| err.offset(leading));
+        }
+        let mut mantissa = digits.parse::<i128>().map_err(|_| overflow)?;
+        let mut scale = frac_part.len() as i64 - exponent as i64;
+        if scale < 0 {
+            mantissa = 10i128
+                .checked_pow((-scale) as u32)
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or(overflow)?;
+            scale = 0;
+        }
+        if scale > MAX_SCALE as i64 {
+            return Err(overflow);
+        }
+        Ok(Number::Decimal(
+            if negative { -mantissa } else { mantissa },
+            scale as u32,
+        ))
+    }
+    pub(crate) fn decimal_op(&self, rhs: &Self, op: BinOp) -> Option<Number> {
+        if !matches!(self, Number::Decimal(..)) && !matches!(rhs, Number::Decimal(..)) {
+            return None;
+        }
+        let (a, a_scale) = self.decimal_parts()?;
+        let (b, b_scale) = rhs.decimal_parts()?;
+        let (mantissa, scale) = match op {
+            BinOp::Add => {
+                let (x, y, scale) = align(a, a_scale, b, b_scale)?;
+                (x.checked_add(y)?, scale)
+            }
+            BinOp::Sub => {
+                let (x, y, scale) = align(a, a_scale, b, b_scale)?;
+                (x.checked_sub(y)?, scale)
+            }
+            BinOp::Mul => strip_zeros(a.checked_mul(b)?, a_scale + b_scale, MAX_SCALE)?,
+            BinOp::Div => divide(a, a_scale, b, b_scale)?,
+            BinOp::Rem => {
+                let (x, y, scale) = align(a, a_scale, b, b_scale)?;
+                (x.checked_rem(y)?, scale)
+            }
+        };
+        Some(Number::Decimal(mantissa, scale))
+    }
+    pub(crate) fn decimal_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !matches!(self, Number::Decimal(..)) && !matches!(other, Number::Decimal(..)) {
+            return None;
+        }
+        let (a, a_scale) = self.decimal_parts()?;
+        let (b, b_scale) = other.decimal_parts()?;
+        let (x, y, _) = align(a, a_scale, b, b_scale)?;
+        Some(x.cmp(&y))
+    }
+    fn decimal_parts(&self) -> Option<(i128, u32)> {
+        match self {
+            Number::Decimal(m, scale) => Some((*m, *scale)),
+            _ => self.int_value().map(|v| (v, 0)),
+        }
+    }
+}
+
+pub(crate) fn fmt_decimal(f: &mut fmt::Formatter<'_>, mantissa: i128, scale: u32) -> fmt::Result {
+    let sign = if mantissa < 0 { "-" } else { "" };
+    let magnitude = mantissa.unsigned_abs();
+    if scale == 0 {
+        return write!(f, "{}{}", sign, magnitude);
+    }
+    let factor = 10u128.pow(scale);
+    write!(
+        f,
+        "{}{}.{:0width$}",
+        sign,
+        magnitude / factor,
+        magnitude % factor,
+        width = scale as usize
+    )
+}
+
+fn align(a: i128, a_scale: u32, b: i128, b_scale: u32) -> Option<(i128, i128, u32)> {
+    match a_scale.cmp(&b_scale) {
+        Ordering::Equal => Some((a, b, a_scale)),
+        Ordering::Less => Some((rescale(a, b_scale - a_scale)?, b, b_scale)),
+        Ordering::Greater => Some((a, rescale(b, a_scale - b_scale)?, a_scale)),
+    }
+}
+
+fn rescale(mantissa: i128, extra: u32) -> Option<i128> {
+    10i128.checked_pow(extra)?.checked_mul(mantissa)
+}
+
+fn strip_zeros(mut mantissa: i128, mut scale: u32, min_scale: u32) -> Option<(i128, u32)> {
+    while scale > min_scale && mantissa % 10 == 0 {
+        mantissa /= 10;
+        scale -= 1;
+    }
+    (scale <= MAX_SCALE).then_some((mantissa, scale))
+}
+
+// 结果保留 DIV_SCALE 位小数（银行家舍入），再去掉多余的尾随零
+fn divide(a: i128, a_scale: u32, b: i128, b_scale: u32) -> Option<(i128, u32)> {
+    if b == 0 {
+        return None;
+    }
+    let min_scale = a_scale.max(b_scale);
+    let (numerator, target) = (min_scale..=DIV_SCALE.max(min_scale))
+        .rev()
+        .find_map(|t| Some((rescale(a, b_scale + t - a_scale)?, t)))?;
+    let mut quotient = numerator / b;
+    let twice = (numerator % b).unsigned_abs() * 2;
+    let divisor = b.unsigned_abs();
+    if twice > divisor || (twice == divisor && quotient % 2 != 0) {
+        quotient += if (numerator < 0) != (b < 0) { -1 } else { 1 };
+    }
+    strip_zeros(quotient, target, min_scale)
+}
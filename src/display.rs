@@ -0,0 +1,590 @@
+// Display 的格式化标志：宽度、填充与对齐、精度、'+' 和 '0'。
+// 精度对所有有限值都表示小数位数，整数补 0，精确类型（Rational、Decimal、Fixed64）
+// 按精确值舍入，逢中间值取偶，与浮点数的 {:.N} 一致
+use std::fmt::{self, Write};
+
+use crate::Number;
+use crate::config::ScientificThreshold;
+
+impl Number {
+    pub(crate) fn fmt_with_flags(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut body = String::new();
+        match f.precision() {
+            Some(precision) => self.write_fixed(&mut body, precision)?,
+            None => write!(body, "{}", self)?,
+        }
+        self.pad(f, body)
+    }
+    // 按 '+'、宽度、对齐和 '0' 标志输出已经生成的文本
+    fn pad(&self, f: &mut fmt::Formatter<'_>, mut body: String) -> fmt::Result {
+        if f.sign_plus() && !body.starts_with('-') && !self.is_nan() {
+            body.insert(0, '+');
+        }
+        let len = body.chars().count();
+        let width = f.width().unwrap_or(0);
+        if len >= width {
+            return f.write_str(&body);
+        }
+        let padding = width - len;
+        // '0' 标志在符号之后补 0；NaN 和 ∞ 仍用空格
+        if f.sign_aware_zero_pad() && self.is_finite() {
+            let sign_len = if body.starts_with(['+', '-']) { 1 } else { 0 };
+            let (sign, digits) = body.split_at(sign_len);
+            f.write_str(sign)?;
+            for _ in 0..padding {
+                f.write_char('0')?;
+            }
+            return f.write_str(digits);
+        }
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(&body)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+    fn write_fixed(&self, out: &mut String, precision: usize) -> fmt::Result {
+        match self {
+            Number::PositiveInfinity | Number::NegativeInfinity | Number::NaN => {
+                write!(out, "{}", self)
+            }
+            Number::Float64(v) => write!(out, "{:.*}", precision, v),
+            Number::Float32(v) => write!(out, "{:.*}", precision, v),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => write!(out, "{:.*}", precision, v.to_f32()),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => write!(out, "{:.*}", precision, v.to_f32()),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => write!(out, "{:.*}", precision, v),
+            _ if self.is_integer_variant() => {
+                out.push_str(&self.plain_string());
+                if precision > 0 {
+                    out.push('.');
+                    out.extend(std::iter::repeat_n('0', precision));
+                }
+                Ok(())
+            }
+            _ => match self
+                .exact_ratio()
+                .and_then(|(n, d)| fixed_digits(n, d, precision))
+            {
+                Some(text) => out.write_str(&text),
+                None => write!(out, "{:.*}", precision, self.to_f64()),
+            },
+        }
+    }
+}
+
+// n / d 保留 precision 位小数；中间结果溢出时返回 None
+pub(crate) fn fixed_digits(n: i128, d: i128, precision: usize) -> Option<String> {
+    let negative = (n < 0) != (d < 0);
+    let (n, d) = (n.unsigned_abs(), d.unsigned_abs());
+    let mut digits: Vec<u8> = (n / d).to_string().into_bytes();
+    let mut remainder = n % d;
+    for _ in 0..precision {
+        remainder = remainder.checked_mul(10)?;
+        digits.push(b'0' + (remainder / d) as u8);
+        remainder %= d;
+    }
+    let last_odd = digits.last().is_some_and(|b| (b - b'0') % 2 == 1);
+    let round_up = remainder > d - remainder || (remainder == d - remainder && last_odd);
+    if round_up {
+        let mut i = digits.len();
+        loop {
+            if i == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+            i -= 1;
+            if digits[i] == b'9' {
+                digits[i] = b'0';
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+    let int_len = digits.len() - precision;
+    let mut text = String::with_capacity(digits.len() + 2);
+    if negative {
+        text.push('-');
+    }
+    text.push_str(std::str::from_utf8(&digits[..int_len]).ok()?);
+    if precision > 0 {
+        text.push('.');
+        text.push_str(std::str::from_utf8(&digits[int_len..]).ok()?);
+    }
+    Some(text)
+}
+
+impl fmt::LowerExp for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let body = self.exp_string(f.precision(), 'e');
+        self.pad(f, body)
+    }
+}
+
+impl fmt::UpperExp for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let body = self.exp_string(f.precision(), 'E');
+        self.pad(f, body)
+    }
+}
+
+impl Number {
+    // 整数和可以有限展开的 Decimal、Fixed64、Rational 按十进制数字精确输出，逢中间值取偶；
+    // 浮点数与原生类型的 {:e} 相同。无法有限展开的 Rational 在未指定精度时按 f64 输出
+    pub(crate) fn exp_string(&self, precision: Option<usize>, marker: char) -> String {
+        match self {
+            Number::PositiveInfinity | Number::NegativeInfinity | Number::NaN => self.to_string(),
+            Number::Float64(v) => native_exp(*v, precision, marker),
+            Number::Float32(v) => native_exp(*v, precision, marker),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => native_exp(v.to_f32(), precision, marker),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => native_exp(v.to_f32(), precision, marker),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => native_exp(v.to_f64(), precision, marker),
+            _ if self.is_integer_variant() => {
+                let text = self.plain_string();
+                let digits = text.trim_start_matches('-');
+                let exponent = digits.len() as i32 - 1;
+                let digits = digits.as_bytes().to_vec();
+                exp_from_digits(
+                    text.starts_with('-'),
+                    digits,
+                    exponent,
+                    false,
+                    precision,
+                    marker,
+                )
+            }
+            _ => {
+                // 多生成一位用于舍入，其余部分记在 sticky 中
+                let limit = precision.map_or(128, |p| p.saturating_add(2));
+                let expanded = self.exact_ratio().and_then(|(n, d)| {
+                    ratio_digits(n.unsigned_abs(), d.unsigned_abs(), limit)
+                        .map(|parts| ((n < 0) != (d < 0), parts))
+                });
+                match expanded {
+                    Some((negative, (digits, exponent, sticky)))
+                        if !sticky || precision.is_some() =>
+                    {
+                        exp_from_digits(negative, digits, exponent, sticky, precision, marker)
+                    }
+                    _ => native_exp(self.to_f64(), precision, marker),
+                }
+            }
+        }
+    }
+}
+
+fn native_exp<T: fmt::LowerExp + fmt::UpperExp>(
+    value: T,
+    precision: Option<usize>,
+    marker: char,
+) -> String {
+    match precision {
+        Some(p) if marker == 'E' => format!("{:.*E}", p, value),
+        Some(p) => format!("{:.*e}", p, value),
+        None if marker == 'E' => format!("{:E}", value),
+        None => format!("{:e}", value),
+    }
+}
+
+// n / d 的前 limit 位有效数字（ASCII）、首位数字的十进制指数，以及其后是否还有非零部分。
+// n 为 0 时数字为空；中间结果溢出时返回 None
+fn ratio_digits(n: u128, d: u128, limit: usize) -> Option<(Vec<u8>, i32, bool)> {
+    if n == 0 {
+        return Some((Vec::new(), 0, false));
+    }
+    let quotient = n / d;
+    let mut remainder = n % d;
+    let (mut digits, mut exponent) = if quotient > 0 {
+        let digits = quotient.to_string().into_bytes();
+        let exponent = digits.len() as i32 - 1;
+        (digits, exponent)
+    } else {
+        (Vec::new(), -1)
+    };
+    while remainder != 0 && digits.len() < limit {
+        remainder = remainder.checked_mul(10)?;
+        let digit = (remainder / d) as u8;
+        remainder %= d;
+        if digits.is_empty() && digit == 0 {
+            exponent -= 1;
+        } else {
+            digits.push(b'0' + digit);
+        }
+    }
+    Some((digits, exponent, remainder != 0))
+}
+
+// digits 为不带前导 0 的有效数字，precision 为 None 时去掉末尾的 0
+fn exp_from_digits(
+    negative: bool,
+    mut digits: Vec<u8>,
+    mut exponent: i32,
+    sticky: bool,
+    precision: Option<usize>,
+    marker: char,
+) -> String {
+    if digits.is_empty() {
+        digits.push(b'0');
+        exponent = 0;
+    }
+    match precision {
+        Some(p) if digits.len() > p + 1 => {
+            let guard = digits[p + 1];
+            let rest_nonzero = sticky || digits[p + 2..].iter().any(|&b| b != b'0');
+            digits.truncate(p + 1);
+            let last_odd = (digits[p] - b'0') % 2 == 1;
+            let round_up = guard > b'5' || guard == b'5' && (rest_nonzero || last_odd);
+            if round_up {
+                let mut i = p + 1;
+                loop {
+                    if i == 0 {
+                        // 全部进位，如 9.99 变为 1.00e1
+                        digits.insert(0, b'1');
+                        digits.truncate(p + 1);
+                        exponent += 1;
+                        break;
+                    }
+                    i -= 1;
+                    if digits[i] == b'9' {
+                        digits[i] = b'0';
+                    } else {
+                        digits[i] += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        Some(p) => digits.resize(p + 1, b'0'),
+        None => {
+            while digits.len() > 1 && digits.last() == Some(&b'0') {
+                digits.pop();
+            }
+        }
+    }
+    let mut text = String::with_capacity(digits.len() + 8);
+    if negative {
+        text.push('-');
+    }
+    text.push(digits[0] as char);
+    if digits.len() > 1 {
+        text.push('.');
+        text.extend(digits[1..].iter().map(|&b| b as char));
+    }
+    text.push(marker);
+    text.push_str(&exponent.to_string());
+    text
+}
+
+// 整数按符号加绝对值输出（-255 为 -ff），不随变体宽度取补码；'#' 加上 0x、0o、0b 前缀。
+// 其他变体没有整数的位表示，按 Display 输出
+impl fmt::LowerHex for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.radix_parts(16) {
+            Some((non_negative, digits)) => f.pad_integral(non_negative, "0x", &digits),
+            None => fmt::Display::fmt(self, f),
+        }
+    }
+}
+
+impl fmt::UpperHex for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.radix_parts(16) {
+            Some((non_negative, digits)) => {
+                f.pad_integral(non_negative, "0x", &digits.to_ascii_uppercase())
+            }
+            None => fmt::Display::fmt(self, f),
+        }
+    }
+}
+
+impl fmt::Octal for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.radix_parts(8) {
+            Some((non_negative, digits)) => f.pad_integral(non_negative, "0o", &digits),
+            None => fmt::Display::fmt(self, f),
+        }
+    }
+}
+
+impl fmt::Binary for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.radix_parts(2) {
+            Some((non_negative, digits)) => f.pad_integral(non_negative, "0b", &digits),
+            None => fmt::Display::fmt(self, f),
+        }
+    }
+}
+
+impl Number {
+    // radix 进制（2 到 36，数字为 0-9 和小写 a-z）的文本，与 parse_radix 互逆。
+    // 非整数取整数部分（向零截断），非有限值和整数部分超出 i128 的浮点数返回 None。
+    // 与 i64::from_str_radix 一样，radix 超出范围时 panic
+    pub fn to_string_radix(&self, radix: u32) -> Option<String> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be between 2 and 36, got {}",
+            radix
+        );
+        let (non_negative, digits) = match self.radix_parts(radix) {
+            Some(parts) => parts,
+            None => {
+                let (n, d) = self.exact_ratio()?;
+                Number::from_wide_int(n / d).radix_parts(radix)?
+            }
+        };
+        Some(if non_negative {
+            digits
+        } else {
+            format!("-{}", digits)
+        })
+    }
+    // 整数变体的符号与绝对值的 radix 进制数字（小写）
+    fn radix_parts(&self, radix: u32) -> Option<(bool, String)> {
+        #[cfg(feature = "bigint")]
+        if let Number::BigInteger(v) = self {
+            let non_negative = v.sign() != num_bigint::Sign::Minus;
+            return Some((non_negative, v.magnitude().to_str_radix(radix)));
+        }
+        let value = self.int_value()?;
+        let mut magnitude = value.unsigned_abs();
+        let mut digits = Vec::new();
+        loop {
+            digits.push(char::from_digit((magnitude % radix as u128) as u32, radix)?);
+            magnitude /= radix as u128;
+            if magnitude == 0 {
+                break;
+            }
+        }
+        Some((value >= 0, digits.iter().rev().collect()))
+    }
+}
+
+// 分母只含因子 2 和 5 时小数展开是有限的，位数为两者个数的较大值
+pub(crate) fn terminating_places(mut d: i128) -> Option<usize> {
+    let mut counts = [0; 2];
+    for (count, factor) in counts.iter_mut().zip([2, 5]) {
+        while d % factor == 0 {
+            d /= factor;
+            *count += 1;
+        }
+    }
+    (d.abs() == 1).then_some(counts[0].max(counts[1]))
+}
+
+// 浮点数的 Display，保证 Number::parse 读回的值与原值相等。
+// f32 的最短表示按 f64 读回不是原值时（如 0.1f32）改用 f64 的最短表示；
+// 2^53 以上的整数值写出精确的整数，否则 1e23 的最短表示 100000000000000000000000 会被读成另一个整数
+pub(crate) enum RoundTrip {
+    Single(f32),
+    Double(f64),
+}
+
+impl fmt::Display for RoundTrip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match *self {
+            RoundTrip::Single(v) => v as f64,
+            RoundTrip::Double(v) => v,
+        };
+        if let Some(precision) = f.precision() {
+            return match *self {
+                RoundTrip::Single(v) => write!(f, "{:.*}", precision, v),
+                RoundTrip::Double(v) => write!(f, "{:.*}", precision, v),
+            };
+        }
+        let large_integer = value.fract() == 0.0 && value.abs() >= 2f64.powi(53);
+        if large_integer && value.abs() < 2f64.powi(127) {
+            return write!(f, "{}", value as i128);
+        }
+        // 启用 bigint 时更大的整数文本读回 BigInteger，同样需要精确值
+        #[cfg(feature = "bigint")]
+        if large_integer
+            && let Some(big) = <num_bigint::BigInt as num_traits::FromPrimitive>::from_f64(value)
+        {
+            return write!(f, "{}", big);
+        }
+        if let RoundTrip::Single(v) = *self {
+            let mut buf = StackBuf {
+                bytes: [0; 64],
+                len: 0,
+            };
+            if write!(buf, "{}", v).is_ok()
+                && let Ok(text) = std::str::from_utf8(&buf.bytes[..buf.len])
+                && text.parse::<f64>() == Ok(value)
+            {
+                return f.write_str(text);
+            }
+        }
+        write!(f, "{}", value)
+    }
+}
+
+impl RoundTrip {
+    // 十进制指数超出 threshold 时写成指数形式，未超出或为 0 时返回 None。
+    // 指数按最短表示计算，precision 为尾数的小数位数
+    pub(crate) fn write_scientific(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        threshold: ScientificThreshold,
+        precision: Option<usize>,
+    ) -> Option<fmt::Result> {
+        let mut buf = StackBuf {
+            bytes: [0; 64],
+            len: 0,
+        };
+        let value = match *self {
+            RoundTrip::Single(v) => {
+                write!(buf, "{:e}", v).ok()?;
+                let exact = std::str::from_utf8(&buf.bytes[..buf.len])
+                    .is_ok_and(|text| text.parse::<f64>() == Ok(v as f64));
+                if !exact {
+                    buf.len = 0;
+                    write!(buf, "{:e}", v as f64).ok()?;
+                }
+                v as f64
+            }
+            RoundTrip::Double(v) => {
+                write!(buf, "{:e}", v).ok()?;
+                v
+            }
+        };
+        if value == 0.0 || !value.is_finite() {
+            return None;
+        }
+        let text = std::str::from_utf8(&buf.bytes[..buf.len]).ok()?;
+        let exponent: i16 = text[text.find('e')? + 1..].parse().ok()?;
+        if (threshold.lower..threshold.upper).contains(&exponent) {
+            return None;
+        }
+        Some(match (precision, self) {
+            (None, _) => f.write_str(text),
+            (Some(precision), RoundTrip::Single(v)) => write!(f, "{:.*e}", precision, v),
+            (Some(precision), RoundTrip::Double(v)) => write!(f, "{:.*e}", precision, v),
+        })
+    }
+}
+
+struct StackBuf {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Number {
+    // 完整的十进制展开：浮点数和 Fixed64 按二进制的精确值写出全部数字（0.1 为
+    // 0.1000000000000000055511151231257827021181583404541015625），有限展开的 Rational 写成小数，
+    // 其余与 Display 相同
+    pub fn to_string_exact(&self) -> String {
+        let float = match self {
+            Number::Float64(v) => *v,
+            Number::Float32(v) => *v as f64,
+            #[cfg(feature = "half")]
+            Number::Float16(v) => v.to_f64(),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => v.to_f64(),
+            Number::Fixed64 { raw, frac_bits } => {
+                return exact_binary(*raw < 0, raw.unsigned_abs(), -(*frac_bits as i32));
+            }
+            Number::Rational(_, d) => {
+                return match terminating_places(*d as i128) {
+                    Some(places) => format!("{:.*}", places, self),
+                    None => self.plain_string(),
+                };
+            }
+            _ if !self.is_finite() => return self.to_string(),
+            _ => return self.plain_string(),
+        };
+        if !float.is_finite() {
+            return self.to_string();
+        }
+        let bits = float.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1u64 << 52) - 1);
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1u64 << 52), biased - 1075)
+        };
+        exact_binary(float.is_sign_negative(), mantissa, exponent)
+    }
+}
+
+// mantissa * 2^exponent 的精确十进制写法，用 10^9 进制的大整数计算
+fn exact_binary(negative: bool, mantissa: u64, exponent: i32) -> String {
+    const LIMB: u64 = 1_000_000_000;
+    let mut limbs = vec![
+        (mantissa % LIMB) as u32,
+        (mantissa / LIMB % LIMB) as u32,
+        (mantissa / LIMB / LIMB) as u32,
+    ];
+    let mul = |limbs: &mut Vec<u32>, factor: u32| {
+        let mut carry = 0u64;
+        for limb in limbs.iter_mut() {
+            let x = *limb as u64 * factor as u64 + carry;
+            *limb = (x % LIMB) as u32;
+            carry = x / LIMB;
+        }
+        while carry > 0 {
+            limbs.push((carry % LIMB) as u32);
+            carry /= LIMB;
+        }
+    };
+    // 2^-k = 5^k / 10^k，乘以 5^k 后小数点左移 k 位
+    let (base, chunk, mut remaining, scale) = if exponent >= 0 {
+        (2u32, 29u32, exponent as u32, 0)
+    } else {
+        (
+            5u32,
+            13u32,
+            exponent.unsigned_abs(),
+            exponent.unsigned_abs() as usize,
+        )
+    };
+    while remaining > 0 {
+        let step = remaining.min(chunk);
+        mul(&mut limbs, base.pow(step));
+        remaining -= step;
+    }
+    while limbs.len() > 1 && limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    let mut digits = limbs.last().map_or(String::new(), |top| top.to_string());
+    for limb in limbs.iter().rev().skip(1) {
+        digits.push_str(&format!("{:09}", limb));
+    }
+    if digits.len() <= scale {
+        digits.insert_str(0, &"0".repeat(scale + 1 - digits.len()));
+    }
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    let frac_part = frac_part.trim_end_matches('0');
+    let sign = if negative { "-" } else { "" };
+    if frac_part.is_empty() {
+        format!("{}{}", sign, int_part)
+    } else {
+        format!("{}{}.{}", sign, int_part, frac_part)
+    }
+}
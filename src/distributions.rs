@@ -0,0 +1,414 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use crate::{MathError, Number};
+
+pub trait Distribution {
+    fn pdf(&self, x: &Number) -> Number;
+    fn cdf(&self, x: &Number) -> Number;
+    fn inverse_cdf(&self, p: &Number) -> Number;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    pub fn new(mean: &Number, std_dev: &Number) -> Result<Self, MathError> {
+        let (mean, std_dev) = (mean.to_f64(), std_dev.to_f64());
+        if !mean.is_finite() || !std_dev.is_finite() || std_dev <= 0.0 {
+            return Err(MathError::DomainError);
+        }
+        Ok(Normal { mean, std_dev })
+    }
+    pub fn standard() -> Self {
+        Normal {
+            mean: 0.0,
+            std_dev: 1.0,
+        }
+    }
+}
+
+impl Distribution for Normal {
+    fn pdf(&self, x: &Number) -> Number {
+        let z = (x.to_f64() - self.mean) / self.std_dev;
+        result((-0.5 * z * z).exp() / (self.std_dev * (2.0 * PI).sqrt()))
+    }
+    fn cdf(&self, x: &Number) -> Number {
+        let z = (x.to_f64() - self.mean) / self.std_dev;
+        result(standard_normal_cdf(z))
+    }
+    fn inverse_cdf(&self, p: &Number) -> Number {
+        let p = p.to_f64();
+        if !(0.0..=1.0).contains(&p) {
+            return Number::NaN;
+        }
+        result(self.mean + self.std_dev * standard_normal_quantile(p))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Uniform {
+    low: f64,
+    high: f64,
+}
+
+impl Uniform {
+    pub fn new(low: &Number, high: &Number) -> Result<Self, MathError> {
+        let (low, high) = (low.to_f64(), high.to_f64());
+        if !low.is_finite() || !high.is_finite() || low >= high {
+            return Err(MathError::DomainError);
+        }
+        Ok(Uniform { low, high })
+    }
+}
+
+impl Distribution for Uniform {
+    fn pdf(&self, x: &Number) -> Number {
+        let x = x.to_f64();
+        if x.is_nan() {
+            Number::NaN
+        } else if x < self.low || x > self.high {
+            Number::from(0)
+        } else {
+            result(1.0 / (self.high - self.low))
+        }
+    }
+    fn cdf(&self, x: &Number) -> Number {
+        let x = x.to_f64();
+        if x.is_nan() {
+            Number::NaN
+        } else if x <= self.low {
+            Number::from(0)
+        } else if x >= self.high {
+            Number::from(1)
+        } else {
+            result((x - self.low) / (self.high - self.low))
+        }
+    }
+    fn inverse_cdf(&self, p: &Number) -> Number {
+        let p = p.to_f64();
+        if !(0.0..=1.0).contains(&p) {
+            return Number::NaN;
+        }
+        result(self.low + p * (self.high - self.low))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Binomial {
+    trials: u64,
+    probability: f64,
+}
+
+impl Binomial {
+    pub fn new(trials: &Number, probability: &Number) -> Result<Self, MathError> {
+        let n = trials.to_f64();
+        let p = probability.to_f64();
+        if n < 0.0 || n != n.trunc() || n > u32::MAX as f64 || !(0.0..=1.0).contains(&p) {
+            return Err(MathError::DomainError);
+        }
+        Ok(Binomial {
+            trials: n as u64,
+            probability: p,
+        })
+    }
+    fn pmf(&self, k: u64) -> f64 {
+        let (n, p) = (self.trials as f64, self.probability);
+        let k_f = k as f64;
+        if p == 0.0 || p == 1.0 {
+            let certain = if p == 0.0 { 0 } else { self.trials };
+            return if k == certain { 1.0 } else { 0.0 };
+        }
+        let ln_choose =
+            libm::lgamma(n + 1.0) - libm::lgamma(k_f + 1.0) - libm::lgamma(n - k_f + 1.0);
+        (ln_choose + k_f * p.ln() + (n - k_f) * (1.0 - p).ln()).exp()
+    }
+    // P(X <= k) = I_{1-p}(n - k, k + 1)，k < n
+    fn cdf_at(&self, k: u64) -> f64 {
+        let (n, p) = (self.trials as f64, self.probability);
+        regularized_beta(1.0 - p, n - k as f64, k as f64 + 1.0).clamp(0.0, 1.0)
+    }
+}
+
+impl Distribution for Binomial {
+    fn pdf(&self, x: &Number) -> Number {
+        match discrete_point(x) {
+            Some(k) if k <= self.trials => result(self.pmf(k)),
+            Some(_) => Number::from(0),
+            None if x.is_nan() => Number::NaN,
+            None => Number::from(0),
+        }
+    }
+    fn cdf(&self, x: &Number) -> Number {
+        let x = x.to_f64();
+        if x.is_nan() {
+            return Number::NaN;
+        }
+        if x < 0.0 {
+            return Number::from(0);
+        }
+        if x >= self.trials as f64 {
+            return Number::from(1);
+        }
+        result(self.cdf_at(x.floor() as u64))
+    }
+    fn inverse_cdf(&self, p: &Number) -> Number {
+        let p = p.to_f64();
+        if !(0.0..=1.0).contains(&p) {
+            return Number::NaN;
+        }
+        // 二分查找满足 P(X <= k) >= p 的最小 k
+        let (mut lo, mut hi) = (0, self.trials);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.cdf_at(mid) >= p {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Number::from_wide_int(lo as i128)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    pub fn new(lambda: &Number) -> Result<Self, MathError> {
+        let lambda = lambda.to_f64();
+        if !lambda.is_finite() || lambda <= 0.0 {
+            return Err(MathError::DomainError);
+        }
+        Ok(Poisson { lambda })
+    }
+    fn pmf(&self, k: u64) -> f64 {
+        let k = k as f64;
+        (k * self.lambda.ln() - self.lambda - libm::lgamma(k + 1.0)).exp()
+    }
+}
+
+impl Distribution for Poisson {
+    fn pdf(&self, x: &Number) -> Number {
+        match discrete_point(x) {
+            Some(k) => result(self.pmf(k)),
+            None if x.is_nan() => Number::NaN,
+            None => Number::from(0),
+        }
+    }
+    fn cdf(&self, x: &Number) -> Number {
+        let x = x.to_f64();
+        if x.is_nan() {
+            return Number::NaN;
+        }
+        if x < 0.0 {
+            return Number::from(0);
+        }
+        if x.is_infinite() {
+            return Number::from(1);
+        }
+        // P(X <= k) = Q(k + 1, λ)，不用逐项累加，x 很大时也是常数时间
+        result(regularized_gamma_upper(x.floor() + 1.0, self.lambda).clamp(0.0, 1.0))
+    }
+    fn inverse_cdf(&self, p: &Number) -> Number {
+        let p = p.to_f64();
+        if !(0.0..=1.0).contains(&p) {
+            return Number::NaN;
+        }
+        if p == 1.0 {
+            return Number::PositiveInfinity;
+        }
+        let mut total = 0.0;
+        let mut k = 0u64;
+        loop {
+            total += self.pmf(k);
+            // 累加误差可能让 total 永远到不了 p，远离均值后直接停止
+            if total >= p || k as f64 > self.lambda + 40.0 * self.lambda.sqrt() + 40.0 {
+                return Number::from_wide_int(k as i128);
+            }
+            k += 1;
+        }
+    }
+}
+
+pub(crate) fn standard_normal_cdf(z: f64) -> f64 {
+    0.5 * libm::erfc(-z / SQRT_2)
+}
+
+// Acklam 有理逼近，再做一步 Halley 修正
+pub(crate) fn standard_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+    let x = if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    let e = standard_normal_cdf(x) - p;
+    let u = e * (2.0 * PI).sqrt() * (x * x / 2.0).exp();
+    x - u / (1.0 + x * u / 2.0)
+}
+
+fn discrete_point(x: &Number) -> Option<u64> {
+    let x = x.to_f64();
+    (x >= 0.0 && x == x.trunc() && x <= u64::MAX as f64).then_some(x as u64)
+}
+
+fn result(value: f64) -> Number {
+    if value.is_finite() {
+        Number::from_float(value)
+    } else {
+        Number::from_f64(value)
+    }
+}
+
+// 正则化不完全 Beta 函数 I_x(a, b)，Lentz 连分式
+pub(crate) fn regularized_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let ln_front =
+        libm::lgamma(a + b) - libm::lgamma(a) - libm::lgamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    if x > (a + 1.0) / (a + b + 2.0) {
+        return 1.0 - regularized_beta(1.0 - x, b, a);
+    }
+    ln_front.exp() * beta_continued_fraction(x, a, b) / a
+}
+
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    // a、b 很大且 x 接近均值时约需 √max(a, b) 次迭代才收敛
+    let iterations = (20.0 * a.max(b).sqrt()).max(300.0) as usize;
+    for m in 1..=iterations {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        for aa in [
+            m * (b - m) * x / ((a + m2 - 1.0) * (a + m2)),
+            -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0)),
+        ] {
+            d = 1.0 + aa * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + aa / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+        }
+        if (d * c - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    h
+}
+
+// 正则化上不完全 Gamma 函数 Q(a, x)
+pub(crate) fn regularized_gamma_upper(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    // a 很大时 lgamma 的舍入误差和迭代次数都不可接受，改用 Wilson–Hilferty 近似：
+    // (x / a)^(1/3) 近似服从 N(1 - 1/(9a), 1/(9a))，误差为 O(1/a)
+    if a > 1e7 {
+        let z = ((x / a).cbrt() - 1.0 + 1.0 / (9.0 * a)) * (9.0 * a).sqrt();
+        return 0.5 * libm::erfc(z / SQRT_2);
+    }
+    let ln_front = a * x.ln() - x - libm::lgamma(a);
+    // x 接近 a 时两种展开都需要约 √a 次迭代才收敛
+    let iterations = (20.0 * a.sqrt()).max(1000.0) as usize;
+    if x < a + 1.0 {
+        let (mut term, mut sum, mut n) = (1.0 / a, 1.0 / a, a);
+        for _ in 0..iterations {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+            if term.abs() < sum.abs() * 1e-16 {
+                break;
+            }
+        }
+        return 1.0 - sum * ln_front.exp();
+    }
+    const TINY: f64 = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..=iterations {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    ln_front.exp() * h
+}
@@ -0,0 +1,138 @@
+use std::f64::consts::{LN_2, LN_10};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::Number;
+
+// 对偶数 value + deriv·ε（ε² = 0），用于前向自动微分：
+// 以 Dual::variable(x) 作为输入求值，结果的 deriv 就是 f'(x)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(any(feature = "bigint", feature = "extended")), derive(Copy))]
+pub struct Dual {
+    pub value: Number,
+    pub deriv: Number,
+}
+
+impl Dual {
+    pub fn new(value: Number, deriv: Number) -> Self {
+        Dual { value, deriv }
+    }
+    pub fn constant(value: Number) -> Self {
+        Dual::new(value, Number::from(0))
+    }
+    pub fn variable(value: Number) -> Self {
+        Dual::new(value, Number::from(1))
+    }
+    pub fn sqrt(&self) -> Self {
+        self.chain(f64::sqrt, |x| 0.5 / x.sqrt())
+    }
+    pub fn ln(&self) -> Self {
+        self.chain(f64::ln, |x| 1.0 / x)
+    }
+    pub fn log2(&self) -> Self {
+        self.chain(f64::log2, |x| 1.0 / (x * LN_2))
+    }
+    pub fn log10(&self) -> Self {
+        self.chain(f64::log10, |x| 1.0 / (x * LN_10))
+    }
+    pub fn exp(&self) -> Self {
+        self.chain(f64::exp, f64::exp)
+    }
+    pub fn sin(&self) -> Self {
+        self.chain(f64::sin, f64::cos)
+    }
+    pub fn cos(&self) -> Self {
+        self.chain(f64::cos, |x| -x.sin())
+    }
+    pub fn tan(&self) -> Self {
+        self.chain(f64::tan, |x| 1.0 / (x.cos() * x.cos()))
+    }
+    pub fn asin(&self) -> Self {
+        self.chain(f64::asin, |x| 1.0 / (1.0 - x * x).sqrt())
+    }
+    pub fn acos(&self) -> Self {
+        self.chain(f64::acos, |x| -1.0 / (1.0 - x * x).sqrt())
+    }
+    pub fn atan(&self) -> Self {
+        self.chain(f64::atan, |x| 1.0 / (1.0 + x * x))
+    }
+    pub fn sinh(&self) -> Self {
+        self.chain(f64::sinh, f64::cosh)
+    }
+    pub fn cosh(&self) -> Self {
+        self.chain(f64::cosh, f64::sinh)
+    }
+    pub fn tanh(&self) -> Self {
+        self.chain(f64::tanh, |x| 1.0 - x.tanh() * x.tanh())
+    }
+    pub fn powi(&self, n: i32) -> Self {
+        self.chain(
+            |x| x.powi(n),
+            |x| {
+                if n == 0 {
+                    0.0
+                } else {
+                    n as f64 * x.powi(n - 1)
+                }
+            },
+        )
+    }
+    pub fn powf(&self, exponent: &Number) -> Self {
+        let p = exponent.to_f64();
+        self.chain(|x| x.powf(p), |x| p * x.powf(p - 1.0))
+    }
+    // 链式法则：(f(a), f'(a)·a')
+    fn chain(&self, f: impl Fn(f64) -> f64, df: impl Fn(f64) -> f64) -> Self {
+        Dual::new(
+            self.value.apply(f),
+            self.deriv.clone() * self.value.apply(df),
+        )
+    }
+}
+
+impl From<Number> for Dual {
+    fn from(value: Number) -> Self {
+        Dual::constant(value)
+    }
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.deriv)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, rhs: Dual) -> Dual {
+        Dual::new(self.value + rhs.value, self.deriv + rhs.deriv)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, rhs: Dual) -> Dual {
+        Dual::new(self.value - rhs.value, self.deriv - rhs.deriv)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, rhs: Dual) -> Dual {
+        let deriv = self.deriv * rhs.value.clone() + self.value.clone() * rhs.deriv;
+        Dual::new(self.value * rhs.value, deriv)
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, rhs: Dual) -> Dual {
+        let deriv = (self.deriv * rhs.value.clone() - self.value.clone() * rhs.deriv)
+            / (rhs.value.clone() * rhs.value.clone());
+        Dual::new(self.value / rhs.value, deriv)
+    }
+}
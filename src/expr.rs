@@ -0,0 +1,907 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+
+use crate::codec::{self, DecodeError, Reader};
+#[cfg(feature = "units")]
+use crate::units::{Quantity, Unit, UnitError};
+use crate::{MathError, Number, NumberContext, RoundingMode};
+
+// 二进制形式的版本号，写在字节串开头
+const FORMAT_VERSION: u8 = 1;
+// 一个区域最多展开的单元格数，与电子表格一列的行数相同
+const MAX_RANGE_CELLS: u64 = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    // 比较运算的结果为 1 或 0
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl BinaryOp {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Rem => "%",
+            BinaryOp::Pow => "^",
+            BinaryOp::Eq => "=",
+            BinaryOp::Ne => "<>",
+            BinaryOp::Lt => "<",
+            BinaryOp::Le => "<=",
+            BinaryOp::Gt => ">",
+            BinaryOp::Ge => ">=",
+        }
+    }
+    fn is_comparison(self) -> bool {
+        self.precedence() == 0
+    }
+    fn precedence(self) -> u8 {
+        match self {
+            BinaryOp::Eq
+            | BinaryOp::Ne
+            | BinaryOp::Lt
+            | BinaryOp::Le
+            | BinaryOp::Gt
+            | BinaryOp::Ge => 0,
+            BinaryOp::Add | BinaryOp::Sub => 1,
+            BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => 2,
+            BinaryOp::Pow => 4,
+        }
+    }
+    pub fn apply(self, lhs: Number, rhs: Number) -> Number {
+        match self {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div => lhs / rhs,
+            BinaryOp::Rem => {
+                let mut result = lhs;
+                result %= rhs;
+                result
+            }
+            BinaryOp::Pow => lhs.pow(rhs),
+            BinaryOp::Eq => Number::from((lhs == rhs) as i32),
+            BinaryOp::Ne => Number::from((lhs != rhs) as i32),
+            BinaryOp::Lt => Number::from((lhs < rhs) as i32),
+            BinaryOp::Le => Number::from((lhs <= rhs) as i32),
+            BinaryOp::Gt => Number::from((lhs > rhs) as i32),
+            BinaryOp::Ge => Number::from((lhs >= rhs) as i32),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expr {
+    Number(Number),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+    // 单元格区域，如 A1:B3，只能作为函数参数，展开为区域内各单元格变量
+    Range(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    UnknownVariable(String),
+    UnknownFunction(String),
+    Arity {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
+    InvalidRange(String),
+    RangeTooLarge(String),
+    UnexpectedRange(String),
+    Math(MathError),
+    #[cfg(feature = "units")]
+    Unit(UnitError),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UnknownVariable(name) => write!(f, "unknown variable '{}'", name),
+            EvalError::UnknownFunction(name) => write!(f, "unknown function '{}'", name),
+            EvalError::Arity {
+                function,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}() takes {} argument(s), {} given",
+                function, expected, found
+            ),
+            EvalError::InvalidRange(range) => write!(f, "'{}' is not a valid cell range", range),
+            EvalError::RangeTooLarge(range) => write!(
+                f,
+                "range '{}' has more than {} cells",
+                range, MAX_RANGE_CELLS
+            ),
+            EvalError::UnexpectedRange(range) => {
+                write!(
+                    f,
+                    "range '{}' can only be used as a function argument",
+                    range
+                )
+            }
+            EvalError::Math(err) => write!(f, "{}", err),
+            #[cfg(feature = "units")]
+            EvalError::Unit(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+impl From<MathError> for EvalError {
+    fn from(err: MathError) -> Self {
+        EvalError::Math(err)
+    }
+}
+
+#[cfg(feature = "units")]
+impl From<UnitError> for EvalError {
+    fn from(err: UnitError) -> Self {
+        EvalError::Unit(err)
+    }
+}
+
+impl Expr {
+    // 接受电子表格公式开头的 '='，"=SUM(A1:A3)" 与 "SUM(A1:A3)" 相同
+    pub fn parse(s: &str) -> Result<Expr, String> {
+        let formula = s.trim_start();
+        let body = match formula.strip_prefix('=') {
+            Some(rest) if !rest.starts_with('=') => rest,
+            _ => formula,
+        };
+        let tokens = tokenize(body)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.comparison()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some(token) => Err(format!("Unexpected '{}' in '{}'", token, s)),
+        }
+    }
+    pub fn eval(&self, vars: &HashMap<String, Number>) -> Result<Number, EvalError> {
+        match self {
+            Expr::Number(n) => Ok(n.clone()),
+            Expr::Variable(name) => vars
+                .get(name)
+                .cloned()
+                .ok_or_else(|| EvalError::UnknownVariable(name.clone())),
+            Expr::Neg(inner) => Ok(Number::from(-1) * inner.eval(vars)?),
+            Expr::Binary(op, lhs, rhs) => Ok(op.apply(lhs.eval(vars)?, rhs.eval(vars)?)),
+            // IF 只对选中的分支求值，未选中分支里的错误不会报告
+            Expr::Call(name, args) if name.eq_ignore_ascii_case("if") && args.len() == 3 => {
+                if truthy(&args[0].eval(vars)?) {
+                    args[1].eval(vars)
+                } else {
+                    args[2].eval(vars)
+                }
+            }
+            Expr::Call(name, args) => {
+                let mut values = Vec::new();
+                for arg in args {
+                    match arg {
+                        Expr::Range(start, end) => {
+                            for cell in expand_range(start, end)? {
+                                let value = vars
+                                    .get(&cell)
+                                    .cloned()
+                                    .ok_or(EvalError::UnknownVariable(cell))?;
+                                values.push(value);
+                            }
+                        }
+                        _ => values.push(arg.eval(vars)?),
+                    }
+                }
+                call(name, &values)
+            }
+            Expr::Range(start, end) => {
+                Err(EvalError::UnexpectedRange(format!("{}:{}", start, end)))
+            }
+        }
+    }
+    #[cfg(feature = "units")]
+    // 带单位求值：未定义的变量名按单位符号解释，"5 km / 2 h" 得到 2.5 km/h；
+    // 加减要求量纲一致，函数参数和非整数指数要求无量纲
+    pub fn eval_quantity(&self, vars: &HashMap<String, Quantity>) -> Result<Quantity, EvalError> {
+        match self {
+            Expr::Number(n) => Ok(Quantity::dimensionless(n.clone())),
+            Expr::Variable(name) => match vars.get(name) {
+                Some(q) => Ok(q.clone()),
+                None => match Unit::parse(name) {
+                    Ok(unit) => Ok(Quantity::new(Number::from(1), unit)),
+                    Err(_) => Err(EvalError::UnknownVariable(name.clone())),
+                },
+            },
+            Expr::Neg(inner) => {
+                let q = inner.eval_quantity(vars)?;
+                Ok(Quantity::new(Number::from(-1) * q.value, q.unit))
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.eval_quantity(vars)?, rhs.eval_quantity(vars)?);
+                if op.is_comparison() {
+                    let rhs = rhs.convert_to(&lhs.unit)?.value;
+                    return Ok(Quantity::dimensionless(op.apply(lhs.value, rhs)));
+                }
+                Ok(match op {
+                    BinaryOp::Add => lhs.add(&rhs)?,
+                    BinaryOp::Sub => lhs.sub(&rhs)?,
+                    BinaryOp::Rem => lhs.rem(&rhs)?,
+                    BinaryOp::Mul => lhs.mul(&rhs)?,
+                    BinaryOp::Div => lhs.div(&rhs)?,
+                    BinaryOp::Pow => {
+                        let exponent = dimensionless(rhs)?;
+                        if lhs.unit.is_dimensionless() {
+                            Quantity::dimensionless(lhs.value.pow(exponent))
+                        } else {
+                            let n = exponent
+                                .as_i64()
+                                .and_then(|n| i32::try_from(n).ok())
+                                .ok_or(UnitError::NonIntegerPower)?;
+                            let unit = lhs.unit.powi(n)?;
+                            Quantity::new(lhs.value.pow(exponent), unit)
+                        }
+                    }
+                    _ => unreachable!("comparisons are handled above"),
+                })
+            }
+            Expr::Call(name, args) => {
+                let mut values = Vec::new();
+                for arg in args {
+                    match arg {
+                        Expr::Range(start, end) => {
+                            for cell in expand_range(start, end)? {
+                                let q = vars
+                                    .get(&cell)
+                                    .cloned()
+                                    .ok_or(EvalError::UnknownVariable(cell))?;
+                                values.push(dimensionless(q)?);
+                            }
+                        }
+                        _ => values.push(dimensionless(arg.eval_quantity(vars)?)?),
+                    }
+                }
+                Ok(Quantity::dimensionless(call(name, &values)?))
+            }
+            Expr::Range(start, end) => {
+                Err(EvalError::UnexpectedRange(format!("{}:{}", start, end)))
+            }
+        }
+    }
+    // 表达式引用的变量名，区域展开为各单元格，按首次出现的顺序去重
+    pub fn variables(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.collect_variables(&mut names, &mut HashSet::new());
+        names
+    }
+    fn collect_variables(&self, names: &mut Vec<String>, seen: &mut HashSet<String>) {
+        let mut push = |name: String| {
+            if seen.insert(name.clone()) {
+                names.push(name);
+            }
+        };
+        match self {
+            Expr::Number(_) => {}
+            Expr::Variable(name) => push(name.clone()),
+            Expr::Range(start, end) => {
+                for cell in expand_range(start, end).unwrap_or_default() {
+                    push(cell);
+                }
+            }
+            Expr::Neg(inner) => inner.collect_variables(names, seen),
+            Expr::Binary(_, lhs, rhs) => {
+                lhs.collect_variables(names, seen);
+                rhs.collect_variables(names, seen);
+            }
+            Expr::Call(_, args) => {
+                for arg in args {
+                    arg.collect_variables(names, seen);
+                }
+            }
+        }
+    }
+    // 常量折叠并消去恒等运算（x+0、x-0、x*1、x/1、x^1、--x），返回化简后的新树
+    pub fn simplify(&self) -> Expr {
+        match self {
+            Expr::Number(_) | Expr::Variable(_) | Expr::Range(..) => self.clone(),
+            Expr::Neg(inner) => match inner.simplify() {
+                Expr::Number(n) => Expr::Number(Number::from(-1) * n),
+                Expr::Neg(inner) => *inner,
+                inner => Expr::Neg(Box::new(inner)),
+            },
+            Expr::Binary(op, lhs, rhs) => simplify_binary(*op, lhs.simplify(), rhs.simplify()),
+            Expr::Call(name, args) => {
+                let args: Vec<Expr> = args.iter().map(Expr::simplify).collect();
+                let constants: Option<Vec<Number>> = args
+                    .iter()
+                    .map(|arg| match arg {
+                        Expr::Number(n) => Some(n.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                // 参数全是常量且求值成功时折叠；出错的调用原样保留，留到求值时报告
+                if let Some(values) = constants
+                    && let Ok(result) = call(name, &values)
+                {
+                    return Expr::Number(result);
+                }
+                Expr::Call(name.clone(), args)
+            }
+        }
+    }
+    // 紧凑二进制形式：版本字节后按前序写出节点，数值使用 codec 的编码，
+    // 字符串为 u32 长度 + UTF-8 字节，可以不经文本解析直接还原
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = vec![FORMAT_VERSION];
+        self.write_node(&mut out);
+        out
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Result<Expr, DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let version = reader.byte()?;
+        if version != FORMAT_VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        let expr = read_node(&mut reader)?;
+        reader.finish()?;
+        Ok(expr)
+    }
+    fn write_node(&self, out: &mut Vec<u8>) {
+        match self {
+            Expr::Number(n) => {
+                out.push(0);
+                codec::write_number(out, n);
+            }
+            Expr::Variable(name) => {
+                out.push(1);
+                write_str(out, name);
+            }
+            Expr::Neg(inner) => {
+                out.push(2);
+                inner.write_node(out);
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                out.push(3);
+                out.push(*op as u8);
+                lhs.write_node(out);
+                rhs.write_node(out);
+            }
+            Expr::Call(name, args) => {
+                out.push(4);
+                write_str(out, name);
+                out.extend_from_slice(&(args.len() as u32).to_le_bytes());
+                for arg in args {
+                    arg.write_node(out);
+                }
+            }
+            Expr::Range(start, end) => {
+                out.push(5);
+                write_str(out, start);
+                write_str(out, end);
+            }
+        }
+    }
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Binary(op, ..) => op.precedence(),
+            Expr::Neg(_) => 3,
+            Expr::Number(n) if n.is_sign_negative() => 3,
+            _ => 5,
+        }
+    }
+    fn fmt_child(&self, f: &mut fmt::Formatter<'_>, min: u8) -> fmt::Result {
+        if self.precedence() < min {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+// 换算为无量纲数值（例如 km/m 的比值），有量纲时报错
+#[cfg(feature = "units")]
+fn dimensionless(q: Quantity) -> Result<Number, EvalError> {
+    Ok(q.convert_to(&Unit::dimensionless())?.value)
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn read_str(reader: &mut Reader<'_>) -> Result<String, DecodeError> {
+    let len = reader.u32()? as usize;
+    String::from_utf8(reader.take(len)?.to_vec()).map_err(|_| DecodeError::InvalidUtf8)
+}
+
+fn read_node(reader: &mut Reader<'_>) -> Result<Expr, DecodeError> {
+    let tag = reader.byte()?;
+    Ok(match tag {
+        0 => Expr::Number(reader.number()?),
+        1 => Expr::Variable(read_str(reader)?),
+        2 => Expr::Neg(Box::new(read_node(reader)?)),
+        3 => {
+            let op = match reader.byte()? {
+                0 => BinaryOp::Add,
+                1 => BinaryOp::Sub,
+                2 => BinaryOp::Mul,
+                3 => BinaryOp::Div,
+                4 => BinaryOp::Rem,
+                5 => BinaryOp::Pow,
+                6 => BinaryOp::Eq,
+                7 => BinaryOp::Ne,
+                8 => BinaryOp::Lt,
+                9 => BinaryOp::Le,
+                10 => BinaryOp::Gt,
+                11 => BinaryOp::Ge,
+                other => return Err(DecodeError::UnknownTag(other)),
+            };
+            let lhs = read_node(reader)?;
+            let rhs = read_node(reader)?;
+            Expr::Binary(op, Box::new(lhs), Box::new(rhs))
+        }
+        4 => {
+            let name = read_str(reader)?;
+            let count = reader.u32()?;
+            let args = (0..count)
+                .map(|_| read_node(reader))
+                .collect::<Result<Vec<_>, _>>()?;
+            Expr::Call(name, args)
+        }
+        5 => Expr::Range(read_str(reader)?, read_str(reader)?),
+        _ => return Err(DecodeError::UnknownTag(tag)),
+    })
+}
+
+fn simplify_binary(op: BinaryOp, lhs: Expr, rhs: Expr) -> Expr {
+    let is = |expr: &Expr, value: i32| matches!(expr, Expr::Number(n) if *n == Number::from(value));
+    match (op, &lhs, &rhs) {
+        (_, Expr::Number(a), Expr::Number(b)) => Expr::Number(op.apply(a.clone(), b.clone())),
+        (BinaryOp::Add, _, _) if is(&lhs, 0) => rhs,
+        (BinaryOp::Add | BinaryOp::Sub, _, _) if is(&rhs, 0) => lhs,
+        (BinaryOp::Sub, _, _) if is(&lhs, 0) => Expr::Neg(Box::new(rhs)),
+        (BinaryOp::Mul, _, _) if is(&lhs, 1) => rhs,
+        (BinaryOp::Mul | BinaryOp::Div | BinaryOp::Pow, _, _) if is(&rhs, 1) => lhs,
+        _ => Expr::Binary(op, Box::new(lhs), Box::new(rhs)),
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{}", n),
+            Expr::Variable(name) => write!(f, "{}", name),
+            Expr::Neg(inner) => {
+                write!(f, "-")?;
+                inner.fmt_child(f, 3)
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                let p = op.precedence();
+                // 左结合运算的右操作数、右结合乘方的左操作数需要更高优先级，
+                // 比较不能连写，两侧都要更高优先级
+                let (left_min, right_min) = match op {
+                    BinaryOp::Pow => (p + 1, 3),
+                    _ if op.is_comparison() => (p + 1, p + 1),
+                    _ => (p, p + 1),
+                };
+                lhs.fmt_child(f, left_min)?;
+                write!(f, " {} ", op.symbol())?;
+                rhs.fmt_child(f, right_min)
+            }
+            Expr::Call(name, args) => {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            }
+            Expr::Range(start, end) => write!(f, "{}:{}", start, end),
+        }
+    }
+}
+
+// 函数名不区分大小写，SUM 与 sum 相同；电子表格函数中区域已展开为多个参数
+fn call(name: &str, args: &[Number]) -> Result<Number, EvalError> {
+    let arity = |expected: usize| EvalError::Arity {
+        function: name.to_string(),
+        expected,
+        found: args.len(),
+    };
+    let unary = |f: fn(&Number) -> Result<Number, MathError>| match args {
+        [x] => Ok(f(x)?),
+        _ => Err(arity(1)),
+    };
+    match name.to_ascii_lowercase().as_str() {
+        "sqrt" => unary(Number::try_sqrt),
+        "ln" => unary(Number::try_ln),
+        "log2" => unary(Number::try_log2),
+        "log10" => unary(Number::try_log10),
+        "exp" => unary(Number::try_exp),
+        "asin" => unary(Number::try_asin),
+        "acos" => unary(Number::try_acos),
+        "sin" => unary(|x| x.apply_checked(f64::sin)),
+        "cos" => unary(|x| x.apply_checked(f64::cos)),
+        "tan" => unary(|x| x.apply_checked(f64::tan)),
+        "atan" => unary(|x| x.apply_checked(f64::atan)),
+        "abs" => unary(|x| Ok(x.abs())),
+        "sum" => Ok(args.iter().fold(Number::from(0), |acc, x| acc + x.clone())),
+        "average" => match args.len() {
+            0 => Err(arity(1)),
+            n => Ok(call("sum", args)? / Number::from_wide_int(n as i128)),
+        },
+        "min" => Ok(extreme(args, |x, best| x < best)),
+        "max" => Ok(extreme(args, |x, best| x > best)),
+        "mod" => match args {
+            [a, b] => spreadsheet_mod(a, b),
+            _ => Err(arity(2)),
+        },
+        "round" => match args {
+            [x] => Ok(round_half_up(x, 0)?),
+            [x, digits] => {
+                let digits = digits
+                    .as_i64()
+                    .and_then(|d| i32::try_from(d).ok())
+                    .ok_or(MathError::DomainError)?;
+                Ok(round_half_up(x, digits)?)
+            }
+            _ => Err(arity(2)),
+        },
+        "if" => match args {
+            [cond, then] => Ok(if truthy(cond) {
+                then.clone()
+            } else {
+                Number::from(0)
+            }),
+            [cond, then, otherwise] => Ok(if truthy(cond) {
+                then.clone()
+            } else {
+                otherwise.clone()
+            }),
+            _ => Err(arity(3)),
+        },
+        _ => Err(EvalError::UnknownFunction(name.to_string())),
+    }
+}
+
+// 非零且不是 NaN 即为真
+fn truthy(n: &Number) -> bool {
+    !n.is_nan() && *n != Number::from(0)
+}
+
+// MIN/MAX 跳过 NaN，没有参数时与电子表格一样得到 0
+fn extreme(args: &[Number], better: fn(&Number, &Number) -> bool) -> Number {
+    args.iter()
+        .filter(|x| !x.is_nan())
+        .fold(None, |best: Option<&Number>, x| match best {
+            Some(b) if !better(x, b) => Some(b),
+            _ => Some(x),
+        })
+        .cloned()
+        .unwrap_or(Number::from(0))
+}
+
+// 电子表格的 MOD：结果与除数同号，MOD(-7, 3) = 2
+fn spreadsheet_mod(a: &Number, b: &Number) -> Result<Number, EvalError> {
+    if *b == Number::from(0) {
+        return Err(MathError::PoleError.into());
+    }
+    let mut r = a.clone();
+    r %= b.clone();
+    if r != Number::from(0) && r.is_sign_negative() != b.is_sign_negative() {
+        r += b.clone();
+    }
+    Ok(r)
+}
+
+// 电子表格的 ROUND：半数远离零舍入，digits 为负时舍入到十位、百位等
+fn round_half_up(x: &Number, digits: i32) -> Result<Number, MathError> {
+    if x.is_nan() {
+        return Err(MathError::DomainError);
+    }
+    if !x.is_finite() {
+        return Ok(x.clone());
+    }
+    // 浮点数按最短十进制表示舍入，ROUND(2.675, 2) 得到 2.68 而不是 2.67
+    let x = if x.is_float_variant() {
+        Number::parse_decimal(&x.to_f64().to_string()).unwrap_or(x.clone())
+    } else {
+        x.clone()
+    };
+    let context = |scale| NumberContext {
+        scale: Some(scale),
+        rounding: RoundingMode::HalfUp,
+        ..NumberContext::default()
+    };
+    if digits >= 0 {
+        return context(digits as u32)
+            .round(&x)
+            .map_err(|_| MathError::LossOfPrecision);
+    }
+    // digits 可能是 i32::MIN，不能直接取反
+    let step = Number::from(10).pow(digits.unsigned_abs());
+    let rounded = context(0)
+        .round(&(x / step.clone()))
+        .map_err(|_| MathError::LossOfPrecision)?;
+    // step 超出 f64 范围时商为 0，再乘回 step 会得到 0 × ∞ = NaN
+    if rounded == Number::from(0) {
+        return Ok(Number::from(0));
+    }
+    Ok(rounded * step)
+}
+
+// 把 A1:B3 这样的区域展开为按行排列的单元格名，两端可以任意顺序书写
+fn expand_range(start: &str, end: &str) -> Result<Vec<String>, EvalError> {
+    let invalid = || EvalError::InvalidRange(format!("{}:{}", start, end));
+    let (c1, r1) = parse_cell(start).ok_or_else(invalid)?;
+    let (c2, r2) = parse_cell(end).ok_or_else(invalid)?;
+    let (columns, rows) = (c1.min(c2)..=c1.max(c2), r1.min(r2)..=r1.max(r2));
+    let count = (c1.abs_diff(c2) as u64 + 1) * (r1.abs_diff(r2) as u64 + 1);
+    if count > MAX_RANGE_CELLS {
+        return Err(EvalError::RangeTooLarge(format!("{}:{}", start, end)));
+    }
+    let mut cells = Vec::with_capacity(count as usize);
+    for row in rows {
+        for column in columns.clone() {
+            cells.push(format!("{}{}", column_name(column), row));
+        }
+    }
+    Ok(cells)
+}
+
+// 列字母按 A..Z、AA..ZZ 的方式编号，从 1 开始
+fn parse_cell(cell: &str) -> Option<(u32, u32)> {
+    let split = cell.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = cell.split_at(split);
+    if letters.is_empty() || letters.len() > 3 || !letters.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    let column = letters
+        .bytes()
+        .fold(0, |acc, b| acc * 26 + u32::from(b - b'A' + 1));
+    let row = digits.parse::<u32>().ok().filter(|&r| r > 0)?;
+    Some((column, row))
+}
+
+fn column_name(mut column: u32) -> String {
+    let mut letters = Vec::new();
+    while column > 0 {
+        column -= 1;
+        letters.push(char::from(b'A' + (column % 26) as u8));
+        column /= 26;
+    }
+    letters.iter().rev().collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(Number),
+    Ident(String),
+    Op(char),
+    Compare(BinaryOp),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Ident(name) => write!(f, "{}", name),
+            Token::Op(c) => write!(f, "{}", c),
+            Token::Compare(op) => write!(f, "{}", op.symbol()),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() {
+                let d = chars[i];
+                // 指数记号后的符号属于数字本身
+                let exponent_sign =
+                    i > start && (d == '+' || d == '-') && matches!(chars[i - 1], 'e' | 'E');
+                if d.is_ascii_digit() || d == '.' || d == 'e' || d == 'E' || exponent_sign {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            let text: String = chars[start..i].iter().collect();
+            let number = Number::parse(&text)
+                .map_err(|err| format!("invalid number '{}': {}", text, err))?;
+            tokens.push(Token::Number(number));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if "+-*/%^(),:".contains(c) {
+            tokens.push(Token::Op(c));
+            i += 1;
+        } else if "=<>!".contains(c) {
+            // 同时接受电子表格写法 = <> 和程序写法 == !=
+            let next = chars.get(i + 1).copied();
+            let (op, len) = match (c, next) {
+                ('=', Some('=')) => (BinaryOp::Eq, 2),
+                ('=', _) => (BinaryOp::Eq, 1),
+                ('!', Some('=')) | ('<', Some('>')) => (BinaryOp::Ne, 2),
+                ('<', Some('=')) => (BinaryOp::Le, 2),
+                ('<', _) => (BinaryOp::Lt, 1),
+                ('>', Some('=')) => (BinaryOp::Ge, 2),
+                ('>', _) => (BinaryOp::Gt, 1),
+                _ => return Err(format!("Unexpected character '{}' in '{}'", c, s)),
+            };
+            tokens.push(Token::Compare(op));
+            i += len;
+        } else {
+            return Err(format!("Unexpected character '{}' in '{}'", c, s));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek_op(&self) -> Option<char> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(c)) => Some(*c),
+            _ => None,
+        }
+    }
+    fn expect(&mut self, op: char) -> Result<(), String> {
+        if self.peek_op() == Some(op) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!("Expected '{}'", op))
+        }
+    }
+    // 比较的优先级最低且不能连写：1 < 2 < 3 是语法错误
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let lhs = self.expression()?;
+        if let Some(Token::Compare(op)) = self.tokens.get(self.pos) {
+            let op = *op;
+            self.pos += 1;
+            let rhs = self.expression()?;
+            return Ok(Expr::Binary(op, Box::new(lhs), Box::new(rhs)));
+        }
+        Ok(lhs)
+    }
+    fn expression(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.term()?;
+        while let Some(op @ ('+' | '-')) = self.peek_op() {
+            self.pos += 1;
+            let rhs = self.term()?;
+            let op = if op == '+' {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.unary()?;
+        while let Some(op @ ('*' | '/' | '%')) = self.peek_op() {
+            self.pos += 1;
+            let rhs = self.unary()?;
+            let op = match op {
+                '*' => BinaryOp::Mul,
+                '/' => BinaryOp::Div,
+                _ => BinaryOp::Rem,
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+    // 一元负号的优先级低于乘方：-2^2 = -(2^2)
+    fn unary(&mut self) -> Result<Expr, String> {
+        match self.peek_op() {
+            Some('-') => {
+                self.pos += 1;
+                Ok(Expr::Neg(Box::new(self.unary()?)))
+            }
+            Some('+') => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+    fn power(&mut self) -> Result<Expr, String> {
+        let base = self.atom()?;
+        // 数字后直接跟标识符视为隐式乘法，绑定得比 * / 更紧："5 km / 2 h"、"3 x^2"
+        if let Expr::Number(_) = base
+            && let Some(Token::Ident(_)) = self.tokens.get(self.pos)
+        {
+            let rhs = self.power()?;
+            return Ok(Expr::Binary(BinaryOp::Mul, Box::new(base), Box::new(rhs)));
+        }
+        if self.peek_op() == Some('^') {
+            self.pos += 1;
+            let exponent = self.unary()?;
+            return Ok(Expr::Binary(
+                BinaryOp::Pow,
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+    fn atom(&mut self) -> Result<Expr, String> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| "Unexpected end of expression".to_string())?;
+        self.pos += 1;
+        match token {
+            Token::Number(n) => Ok(Expr::Number(n)),
+            Token::Ident(name) if self.peek_op() == Some('(') => {
+                self.pos += 1;
+                let mut args = Vec::new();
+                if self.peek_op() != Some(')') {
+                    args.push(self.comparison()?);
+                    while self.peek_op() == Some(',') {
+                        self.pos += 1;
+                        args.push(self.comparison()?);
+                    }
+                }
+                self.expect(')')?;
+                Ok(Expr::Call(name, args))
+            }
+            Token::Ident(start) if self.peek_op() == Some(':') => {
+                self.pos += 1;
+                match self.tokens.get(self.pos).cloned() {
+                    Some(Token::Ident(end)) => {
+                        self.pos += 1;
+                        Ok(Expr::Range(start, end))
+                    }
+                    _ => Err(format!("Expected a cell after '{}:'", start)),
+                }
+            }
+            Token::Ident(name) => Ok(Expr::Variable(name)),
+            Token::Op('(') => {
+                let inner = self.comparison()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Token::Op(c) => Err(format!("Unexpected '{}'", c)),
+            Token::Compare(op) => Err(format!("Unexpected '{}'", op.symbol())),
+        }
+    }
+}
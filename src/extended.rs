@@ -0,0 +1,64 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{BinOp, Number};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+// 下游数值后端（GMP、decimal128 等）的接入点。
+// binary_op 返回 None 时回退到 f64 运算；reversed 表示扩展值位于右操作数
+pub trait ExtendedNumber: fmt::Debug + fmt::Display + Send + Sync {
+    fn type_name(&self) -> &'static str;
+    fn to_f64(&self) -> f64;
+    fn binary_op(&self, op: Operation, other: &Number, reversed: bool) -> Option<Number> {
+        let _ = (op, other, reversed);
+        None
+    }
+    fn compare(&self, other: &Number) -> Option<Ordering> {
+        let _ = other;
+        None
+    }
+}
+
+impl Number {
+    pub fn extended(value: impl ExtendedNumber + 'static) -> Number {
+        Number::Extended(Arc::new(value))
+    }
+    pub(crate) fn extended_op(&self, rhs: &Self, op: BinOp) -> Option<Number> {
+        let op = match op {
+            BinOp::Add => Operation::Add,
+            BinOp::Sub => Operation::Sub,
+            BinOp::Mul => Operation::Mul,
+            BinOp::Div => Operation::Div,
+            BinOp::Rem => Operation::Rem,
+        };
+        if let Number::Extended(value) = self
+            && let Some(result) = value.binary_op(op, rhs, false)
+        {
+            return Some(result);
+        }
+        match rhs {
+            Number::Extended(value) => value.binary_op(op, self, true),
+            _ => None,
+        }
+    }
+    pub(crate) fn extended_cmp(&self, other: &Self) -> Option<Ordering> {
+        if let Number::Extended(value) = self
+            && let Some(ordering) = value.compare(other)
+        {
+            return Some(ordering);
+        }
+        match other {
+            Number::Extended(value) => value.compare(self).map(Ordering::reverse),
+            _ => None,
+        }
+    }
+}
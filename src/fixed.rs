@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::parse::ParseErrorKind;
+use crate::{BinOp, Number, ParseNumberError};
+
+pub(crate) const MAX_FRAC_BITS: u8 = 63;
+
+impl Number {
+    pub fn fixed(raw: i64, frac_bits: u8) -> Number {
+        if frac_bits > MAX_FRAC_BITS {
+            return Number::from_float(raw as f64 / 2f64.powi(frac_bits as i32));
+        }
+        Number::Fixed64 { raw, frac_bits }
+    }
+    // 解析 "3.25@Q16.16"：Qm.n 中 m 含符号位，n 为小数位数，m + n 不超过 64
+    pub fn parse_fixed(s: &str) -> Result<Self, ParseNumberError> {
+        let text = s.trim();
+        let leading = s.len() - s.trim_start().len();
+        let Some((value, format)) = text.split_once('@') else {
+            return Err(ParseNumberError::new(
+                ParseErrorKind::InvalidDigit,
+                leading + text.len(),
+            ));
+        };
+        // 格式部分写错时指向 '@' 之后
+        let format_error =
+            ParseNumberError::new(ParseErrorKind::InvalidDigit, leading + value.len() + 1);
+        let format = format.strip_prefix(['Q', 'q']).ok_or(format_error)?;
+        let (int_bits, frac_bits) = format.split_once('.').ok_or(format_error)?;
+        let int_bits = int_bits.parse::<u32>().map_err(|_| format_error)?;
+        let frac_bits = frac_bits.parse::<u8>().map_err(|_| format_error)?;
+        let width = int_bits + frac_bits as u32;
+        if int_bits == 0 || width > 64 {
+            return Err(format_error);
+        }
+        let value = Number::parse_decimal(value).map_err(|err| err.offset(leading))?;
+        match value.to_fixed(frac_bits) {
+            Some(Number::Fixed64 { raw, frac_bits }) if fits_in_bits(raw, width) => {
+                Ok(Number::Fixed64 { raw, frac_bits })
+            }
+            _ => Err(ParseNumberError::new(ParseErrorKind::Overflow, leading)),
+        }
+    }
+    // 转换为 frac_bits 位小数的定点数（就近舍入，平局取偶），超出 i64 范围时返回 None
+    pub fn to_fixed(&self, frac_bits: u8) -> Option<Number> {
+        if frac_bits > MAX_FRAC_BITS {
+            return None;
+        }
+        let target = frac_bits as u32;
+        let raw = match self {
+            Number::Fixed64 { raw, frac_bits } => {
+                let current = *frac_bits as u32;
+                if current > target {
+                    shift_round(*raw as i128, current - target)
+                } else {
+                    (*raw as i128).checked_mul(1i128 << (target - current))?
+                }
+            }
+            Number::Decimal(m, scale) => match m.checked_mul(1i128 << target) {
+                Some(scaled) => div_round(scaled, 10i128.checked_pow(*scale)?)?,
+                None => float_raw(self.to_f64(), target)?,
+            },
+            Number::Rational(n, d) => div_round((*n as i128) << target, *d as i128)?,
+            _ if self.is_float_variant() => float_raw(self.to_f64(), target)?,
+            _ => self.int_value()?.checked_mul(1i128 << target)?,
+        };
+        Some(Number::Fixed64 {
+            raw: i64::try_from(raw).ok()?,
+            frac_bits,
+        })
+    }
+    pub(crate) fn fixed_op(&self, rhs: &Self, op: BinOp) -> Option<Number> {
+        if !matches!(self, Number::Fixed64 { .. }) && !matches!(rhs, Number::Fixed64 { .. }) {
+            return None;
+        }
+        let (a, a_bits) = self.fixed_parts()?;
+        let (b, b_bits) = rhs.fixed_parts()?;
+        let frac_bits = a_bits.max(b_bits);
+        let raw = match op {
+            BinOp::Add => {
+                let (x, y) = align(a, a_bits, b, b_bits);
+                x.checked_add(y)?
+            }
+            BinOp::Sub => {
+                let (x, y) = align(a, a_bits, b, b_bits);
+                x.checked_sub(y)?
+            }
+            BinOp::Mul => shift_round(a * b, a_bits + b_bits - frac_bits),
+            BinOp::Div => {
+                if b == 0 {
+                    return None;
+                }
+                let shift = frac_bits + b_bits - a_bits;
+                div_round(a.checked_mul(1i128.checked_shl(shift)?)?, b)?
+            }
+            BinOp::Rem => {
+                let (x, y) = align(a, a_bits, b, b_bits);
+                x.checked_rem(y)?
+            }
+        };
+        Some(Number::Fixed64 {
+            raw: i64::try_from(raw).ok()?,
+            frac_bits: frac_bits as u8,
+        })
+    }
+    pub(crate) fn fixed_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !matches!(self, Number::Fixed64 { .. }) && !matches!(other, Number::Fixed64 { .. }) {
+            return None;
+        }
+        let (a, a_bits) = self.fixed_parts()?;
+        let (b, b_bits) = other.fixed_parts()?;
+        let (x, y) = align(a, a_bits, b, b_bits);
+        Some(x.cmp(&y))
+    }
+    fn fixed_parts(&self) -> Option<(i128, u32)> {
+        match self {
+            Number::Fixed64 { raw, frac_bits } => Some((*raw as i128, *frac_bits as u32)),
+            _ => self
+                .int_value()
+                .filter(|v| *v >= i64::MIN as i128 && *v <= i64::MAX as i128)
+                .map(|v| (v, 0)),
+        }
+    }
+}
+
+// raw / 2^frac_bits 总是有限小数，放得下时按十进制精确输出
+pub(crate) fn fmt_fixed(f: &mut fmt::Formatter<'_>, raw: i64, frac_bits: u8) -> fmt::Result {
+    let exact = 5i128
+        .checked_pow(frac_bits as u32)
+        .and_then(|factor| factor.checked_mul(raw as i128));
+    let Some(mut mantissa) = exact else {
+        return write!(f, "{}", raw as f64 / 2f64.powi(frac_bits as i32));
+    };
+    let mut scale = frac_bits as u32;
+    while scale > 0 && mantissa % 10 == 0 {
+        mantissa /= 10;
+        scale -= 1;
+    }
+    crate::decimal::fmt_decimal(f, mantissa, scale)
+}
+
+// 两边的 i64 原始值左移至多 63 位，在 i128 中不会溢出
+fn align(a: i128, a_bits: u32, b: i128, b_bits: u32) -> (i128, i128) {
+    match a_bits.cmp(&b_bits) {
+        Ordering::Equal => (a, b),
+        Ordering::Less => (a << (b_bits - a_bits), b),
+        Ordering::Greater => (a, b << (a_bits - b_bits)),
+    }
+}
+
+fn shift_round(value: i128, shift: u32) -> i128 {
+    if shift == 0 {
+        return value;
+    }
+    let quotient = value >> shift;
+    let remainder = value - (quotient << shift);
+    let half = 1i128 << (shift - 1);
+    if remainder > half || (remainder == half && quotient & 1 == 1) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+fn div_round(numerator: i128, denominator: i128) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator % denominator;
+    let twice = remainder.unsigned_abs() * 2;
+    let magnitude = denominator.unsigned_abs();
+    let negative = (numerator < 0) != (denominator < 0);
+    let round_away = twice > magnitude || (twice == magnitude && quotient & 1 == 1);
+    Some(match (round_away, negative) {
+        (false, _) => quotient,
+        (true, false) => quotient + 1,
+        (true, true) => quotient - 1,
+    })
+}
+
+// f64 乘以 2 的幂是精确的，只在取整时舍入一次
+fn float_raw(value: f64, frac_bits: u32) -> Option<i128> {
+    let scaled = (value * 2f64.powi(frac_bits as i32)).round_ties_even();
+    (scaled.is_finite() && scaled.abs() < 2f64.powi(63)).then_some(scaled as i128)
+}
+
+fn fits_in_bits(raw: i64, bits: u32) -> bool {
+    bits >= 64 || (raw >= -(1i64 << (bits - 1)) && raw < (1i64 << (bits - 1)))
+}
@@ -0,0 +1,59 @@
+use half::{bf16, f16};
+
+use crate::Number;
+
+impl Number {
+    // 能被半精度精确表示的值优先选用 Float16，其次 BFloat16
+    pub(crate) fn from_half_float(value: f64) -> Option<Number> {
+        if !value.is_finite() {
+            return None;
+        }
+        let as_f16 = f16::from_f64(value);
+        if as_f16.to_f64() == value {
+            return Some(Number::Float16(as_f16));
+        }
+        let as_bf16 = bf16::from_f64(value);
+        if as_bf16.to_f64() == value {
+            return Some(Number::BFloat16(as_bf16));
+        }
+        None
+    }
+    // 支持 "1.5f16" 和 "1.5bf16" 这样的显式宽度后缀
+    pub(crate) fn parse_half_suffix(s: &str) -> Option<Number> {
+        let s = s.trim();
+        if let Some(body) = s.strip_suffix("bf16") {
+            return body
+                .parse::<f64>()
+                .ok()
+                .map(|v| Number::from(bf16::from_f64(v)));
+        }
+        let body = s.strip_suffix("f16")?;
+        body.parse::<f64>()
+            .ok()
+            .map(|v| Number::from(f16::from_f64(v)))
+    }
+}
+
+impl From<f16> for Number {
+    fn from(value: f16) -> Self {
+        if value.is_nan() {
+            Number::NaN
+        } else if value.is_infinite() {
+            Number::from_f64(value.to_f64())
+        } else {
+            Number::Float16(value)
+        }
+    }
+}
+
+impl From<bf16> for Number {
+    fn from(value: bf16) -> Self {
+        if value.is_nan() {
+            Number::NaN
+        } else if value.is_infinite() {
+            Number::from_f64(value.to_f64())
+        } else {
+            Number::BFloat16(value)
+        }
+    }
+}
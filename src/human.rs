@@ -0,0 +1,70 @@
+use crate::Number;
+use crate::display::fixed_digits;
+
+// 数量级后缀，每一级是上一级的 1000 倍
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HumanScale {
+    // 短级差：K、M、B、T
+    #[default]
+    Short,
+    // 国际单位制词头：k、M、G、T、P、E、Z、Y
+    Si,
+}
+
+impl HumanScale {
+    fn suffixes(self) -> &'static [&'static str] {
+        match self {
+            HumanScale::Short => &["", "K", "M", "B", "T"],
+            HumanScale::Si => &["", "k", "M", "G", "T", "P", "E", "Z", "Y"],
+        }
+    }
+}
+
+impl Number {
+    // 缩写成 1.2K、3.4M 这样的短文本，precision 为小数位数
+    pub fn to_human_string(&self, precision: usize) -> String {
+        self.to_human_string_with(precision, HumanScale::Short)
+    }
+    // 按精确值舍入（逢中间值取偶）；舍入后达到 1000 时进到下一级，如 999_950 为 1.0M 而不是 1000.0K。
+    // 小于 1000 的整数原样输出，超过最大后缀的值停在最大后缀上
+    pub fn to_human_string_with(&self, precision: usize, scale: HumanScale) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+        let suffixes = scale.suffixes();
+        let magnitude = self.to_f64().abs();
+        let mut tier = if magnitude < 1000.0 {
+            0
+        } else {
+            ((magnitude.log10() / 3.0).floor() as usize).min(suffixes.len() - 1)
+        };
+        loop {
+            let text = self.scaled_text(tier, precision);
+            let carried = text
+                .trim_start_matches('-')
+                .parse::<f64>()
+                .is_ok_and(|v| v >= 1000.0);
+            if carried && tier + 1 < suffixes.len() {
+                tier += 1;
+                continue;
+            }
+            return text + suffixes[tier];
+        }
+    }
+    // 除以 1000^tier 后保留 precision 位小数
+    fn scaled_text(&self, tier: usize, precision: usize) -> String {
+        if tier == 0 && self.is_integer_variant() {
+            return self.to_string();
+        }
+        let divisor = 1000i128.pow(tier as u32);
+        self.exact_ratio()
+            .and_then(|(n, d)| fixed_digits(n, d.checked_mul(divisor)?, precision))
+            .unwrap_or_else(|| {
+                format!(
+                    "{:.*}",
+                    precision,
+                    self.to_f64() / 1000f64.powi(tier as i32)
+                )
+            })
+    }
+}
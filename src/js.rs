@@ -0,0 +1,84 @@
+use crate::Number;
+
+const TWO_POW_32: f64 = 4294967296.0;
+
+impl Number {
+    pub const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+    pub const MIN_SAFE_INTEGER: i64 = -((1 << 53) - 1);
+
+    // 与 ECMAScript Number.isSafeInteger 一致：整数值且绝对值不超过 2^53 - 1
+    pub fn is_safe_integer(&self) -> bool {
+        let x = self.to_f64();
+        x.is_finite()
+            && x == x.trunc()
+            && x.abs() <= Number::MAX_SAFE_INTEGER as f64
+            && !self.loses_precision_as_f64()
+    }
+    // ECMAScript ToNumber：一律按 f64 处理，安全整数保留为整数变体
+    pub fn to_js_number(&self) -> Number {
+        js_result(self.to_f64())
+    }
+    // ECMAScript ToInt32：截断后对 2^32 取模再解释为有符号数
+    pub fn to_int32(&self) -> i32 {
+        self.to_uint32() as i32
+    }
+    pub fn to_uint32(&self) -> u32 {
+        let x = self.to_f64();
+        if !x.is_finite() {
+            return 0;
+        }
+        x.trunc().rem_euclid(TWO_POW_32) as u32
+    }
+}
+
+// 按 JavaScript 语义运算：所有值视为 f64，超过 2^53 的整数会悄悄丢失精度
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsContext;
+
+impl JsContext {
+    pub fn add(&self, a: &Number, b: &Number) -> Number {
+        js_result(a.to_f64() + b.to_f64())
+    }
+    pub fn sub(&self, a: &Number, b: &Number) -> Number {
+        js_result(a.to_f64() - b.to_f64())
+    }
+    pub fn mul(&self, a: &Number, b: &Number) -> Number {
+        js_result(a.to_f64() * b.to_f64())
+    }
+    pub fn div(&self, a: &Number, b: &Number) -> Number {
+        js_result(a.to_f64() / b.to_f64())
+    }
+    pub fn rem(&self, a: &Number, b: &Number) -> Number {
+        js_result(a.to_f64() % b.to_f64())
+    }
+    pub fn bit_and(&self, a: &Number, b: &Number) -> Number {
+        Number::from(a.to_int32() & b.to_int32())
+    }
+    pub fn bit_or(&self, a: &Number, b: &Number) -> Number {
+        Number::from(a.to_int32() | b.to_int32())
+    }
+    pub fn bit_xor(&self, a: &Number, b: &Number) -> Number {
+        Number::from(a.to_int32() ^ b.to_int32())
+    }
+    pub fn shl(&self, a: &Number, b: &Number) -> Number {
+        Number::from(a.to_int32().wrapping_shl(b.to_uint32() & 31))
+    }
+    pub fn shr(&self, a: &Number, b: &Number) -> Number {
+        Number::from(a.to_int32() >> (b.to_uint32() & 31))
+    }
+    pub fn ushr(&self, a: &Number, b: &Number) -> Number {
+        Number::from(a.to_uint32() >> (b.to_uint32() & 31))
+    }
+}
+
+fn js_result(x: f64) -> Number {
+    // -0 在 JavaScript 中可观察，保留为浮点数
+    if x == x.trunc()
+        && x.abs() <= Number::MAX_SAFE_INTEGER as f64
+        && !(x == 0.0 && x.is_sign_negative())
+    {
+        Number::from_i64(x as i64)
+    } else {
+        Number::from_f64(x)
+    }
+}
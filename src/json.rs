@@ -0,0 +1,85 @@
+// 与 serde_json::Number 互转。启用 arbitrary-precision 时 serde_json 保留原始数字串，
+// 超出 64 位的整数和十进制小数按原文精确转换，不会经过 f64
+
+#[cfg(feature = "arbitrary-precision")]
+use crate::ParseNumberError;
+use crate::{CoerceError, Number};
+
+impl From<serde_json::Number> for Number {
+    fn from(n: serde_json::Number) -> Self {
+        #[cfg(feature = "arbitrary-precision")]
+        if let Ok(number) = from_json_digits(n.as_str()) {
+            return number;
+        }
+        if let Some(v) = n.as_i64() {
+            return Number::from_i64(v);
+        }
+        if let Some(v) = n.as_u64() {
+            return Number::from_wide_int(v as i128);
+        }
+        n.as_f64().map_or(Number::NaN, Number::from_float)
+    }
+}
+
+// 整数超出 i64/u64 且没有 arbitrary-precision 时报 OutOfRange，不静默变成浮点数；
+// 精确分数能写成有限小数时按原样写出（需要 arbitrary-precision），否则取 f64
+impl TryFrom<Number> for serde_json::Number {
+    type Error = CoerceError;
+
+    fn try_from(n: Number) -> Result<Self, CoerceError> {
+        if !n.is_finite() {
+            return Err(CoerceError::NonFinite);
+        }
+        if n.is_integer_variant() {
+            if let Some(v) = n.int_value() {
+                if let Ok(v) = i64::try_from(v) {
+                    return Ok(v.into());
+                }
+                if let Ok(v) = u64::try_from(v) {
+                    return Ok(v.into());
+                }
+            }
+            return exact_digits(&n.plain_string()).ok_or(CoerceError::OutOfRange);
+        }
+        if !n.is_float_variant()
+            && let Some((numerator, denominator)) = n.exact_ratio()
+            && let Some(decimal) = terminating_decimal(numerator, denominator)
+            && let Some(number) = exact_digits(&decimal.to_string())
+        {
+            return Ok(number);
+        }
+        serde_json::Number::from_f64(n.to_f64()).ok_or(CoerceError::NonFinite)
+    }
+}
+
+// JSON 数字原文：整数走 parse（按大小选择整数变体），带小数点或指数的按十进制精确解析
+#[cfg(feature = "arbitrary-precision")]
+pub(crate) fn from_json_digits(s: &str) -> Result<Number, ParseNumberError> {
+    if s.contains(['.', 'e', 'E']) {
+        Number::parse_decimal(s).or_else(|_| Number::parse(s))
+    } else {
+        Number::parse(s)
+    }
+}
+
+#[cfg(feature = "arbitrary-precision")]
+fn exact_digits(s: &str) -> Option<serde_json::Number> {
+    s.parse().ok()
+}
+
+#[cfg(not(feature = "arbitrary-precision"))]
+fn exact_digits(_: &str) -> Option<serde_json::Number> {
+    None
+}
+
+// 分母只含因子 2 和 5 时 n/d 是有限小数
+fn terminating_decimal(numerator: i128, denominator: i128) -> Option<Number> {
+    for scale in 0..=38 {
+        let power = 10i128.checked_pow(scale)?;
+        if power % denominator == 0 {
+            let mantissa = numerator.checked_mul(power / denominator)?;
+            return Some(Number::Decimal(mantissa, scale));
+        }
+    }
+    None
+}
@@ -1,23 +1,183 @@
+// Number 只有在未启用 bigint 和 extended 时才是 Copy，内部代码统一使用 clone
+#![cfg_attr(
+    not(any(feature = "bigint", feature = "extended")),
+    allow(clippy::clone_on_copy)
+)]
+
+#[cfg(feature = "repr-c")]
+pub mod abi;
+mod array;
+pub mod backoff;
+#[cfg(feature = "bigint")]
+mod bigint;
+#[cfg(feature = "format")]
+mod buffer;
+#[cfg(feature = "format")]
+mod byte_size;
+#[cfg(feature = "cbor")]
+mod cbor;
+#[cfg(feature = "expr")]
+pub mod cells;
+mod chain;
+#[cfg(feature = "format")]
+mod chinese;
+mod codec;
+pub mod compat;
+mod complex;
+mod config;
+mod context;
+#[cfg(feature = "parse")]
+mod currency;
+mod debug;
+mod decimal;
+mod display;
+#[cfg(feature = "stats")]
+pub mod distributions;
+mod dual;
+#[cfg(feature = "expr")]
+pub mod expr;
+#[cfg(feature = "extended")]
+mod extended;
+mod fixed;
+#[cfg(feature = "half")]
+mod half_float;
+#[cfg(feature = "format")]
+mod human;
+mod js;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "format")]
+mod localized;
+mod macros;
+mod math;
+mod migrate;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod nan;
+pub mod optimize;
+#[cfg(feature = "format")]
+mod ordinal;
+mod packed;
+mod parse;
+mod power;
+pub mod prelude;
+mod quaternion;
+pub mod rates;
+mod rational;
+#[cfg(feature = "stats")]
+pub mod sampling;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod sig_figs;
+mod sign;
+mod small_number;
+#[cfg(feature = "parse")]
+mod sniff;
+#[cfg(feature = "stats")]
+pub mod stats;
+#[cfg(feature = "parse")]
+mod stream;
+mod strict;
+pub mod trace;
+mod tracked;
+#[cfg(feature = "units")]
+pub mod units;
+mod width;
+#[cfg(feature = "format")]
+pub mod words;
+
+pub use array::NumberArray;
+#[cfg(feature = "format")]
+pub use buffer::BufferTooSmall;
+#[cfg(feature = "format")]
+pub use byte_size::ByteUnits;
+pub use chain::MathChain;
+#[cfg(feature = "format")]
+pub use chinese::ChineseStyle;
+pub use codec::DecodeError;
+pub use complex::ComplexNumber;
+#[cfg(feature = "format")]
+pub use config::DefaultFormatGuard;
+pub use config::{
+    FormatConfig, FormatOptions, NonFiniteRepr, NonFiniteTokens, NumberConfig, OverflowPolicy,
+    ParseConfig, ScientificThreshold,
+};
+pub use context::{IntegerDivision, NumberContext, RoundingMode};
+#[cfg(feature = "parse")]
+pub use currency::CurrencyAmount;
+pub use dual::Dual;
+#[cfg(feature = "extended")]
+pub use extended::{ExtendedNumber, Operation};
+#[cfg(feature = "format")]
+pub use human::HumanScale;
+pub use js::JsContext;
+pub use math::MathError;
+pub use nan::NanOrigin;
+pub use packed::PackedNumber;
+pub use parse::{ParseErrorKind, ParseNumberError};
+pub use quaternion::Quaternion;
+pub use small_number::SmallNumber;
+#[cfg(feature = "parse")]
+pub use sniff::{SniffCandidate, SniffHints};
+#[cfg(feature = "parse")]
+pub use stream::{NumberStream, StreamError};
+pub use strict::ArithmeticError;
+pub use tracked::OpResult;
+pub use width::{CoerceError, ExactWidth, NumberKind, PrecisionLoss};
 
 use std::cmp::Ordering;
 use std::fmt;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, RemAssign, Sub, SubAssign};
 
-#[derive(Debug, Clone, Copy)]
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+#[cfg(feature = "bigint")]
+use num_traits::ToPrimitive;
+
+// 启用 repr-c 时布局为 #[repr(C, u8)]，标签值按声明顺序分配，见 abi 模块
+#[derive(Clone)]
+#[cfg_attr(not(any(feature = "bigint", feature = "extended")), derive(Copy))]
+#[cfg_attr(feature = "repr-c", repr(C, u8))]
 pub enum Number {
     PositiveInfinity,
     NegativeInfinity,
     NaN,
+    Rational(i64, i64),
+    Decimal(i128, u32),
+    Fixed64 {
+        raw: i64,
+        frac_bits: u8,
+    },
+    Integer128(i128),
+    UnsignedInteger64(u64),
+    #[cfg(not(feature = "small"))]
     Integer64(i64),
     Integer32(i32),
     Integer16(i16),
     Integer8(i8),
     Float64(f64),
     Float32(f32),
+    #[cfg(feature = "half")]
+    Float16(f16),
+    #[cfg(feature = "half")]
+    BFloat16(bf16),
+    #[cfg(feature = "bigint")]
+    BigInteger(BigInt),
+    #[cfg(feature = "extended")]
+    Extended(std::sync::Arc<dyn ExtendedNumber>),
 }
 
 impl Number {
-    pub fn from_int(value: i64) -> Self {
+    // small 构建没有 64 位整数，from_int 不可用，i64 参数在编译期报错
+    #[cfg(not(feature = "small"))]
+    pub const fn from_int(value: i64) -> Self {
+        Number::from_i64(value)
+    }
+    pub(crate) const fn from_i64(value: i64) -> Self {
         if value >= i8::MIN as i64 && value <= i8::MAX as i64 {
             Number::Integer8(value as i8)
         } else if value >= i16::MIN as i64 && value <= i16::MAX as i64 {
@@ -25,43 +185,115 @@ impl Number {
         } else if value >= i32::MIN as i64 && value <= i32::MAX as i64 {
             Number::Integer32(value as i32)
         } else {
-            Number::Integer64(value)
+            #[cfg(feature = "small")]
+            {
+                // small 构建没有更宽的整数，超出 i32 的值降为 Float32
+                Number::Float32(value as f32)
+            }
+            #[cfg(not(feature = "small"))]
+            {
+                Number::Integer64(value)
+            }
         }
     }
     pub fn from_float(value: f64) -> Self {
+        #[cfg(feature = "half")]
+        if let Some(number) = Number::from_half_float(value) {
+            return number;
+        }
         let as_f32 = value as f32;
-        if (as_f32 as f64 - value).abs() < f64::EPSILON && value.is_finite() {
+        if cfg!(feature = "small") {
+            return Number::small_float(value, as_f32);
+        }
+        // 只有完全相等才收窄，否则 Display 的最短表示无法读回同一个值
+        if as_f32 as f64 == value && value.is_finite() {
             Number::Float32(as_f32)
         } else {
             Number::Float64(value)
         }
     }
-    pub fn parse(s: &str) -> Result<Self, String> {
-        match s.trim().to_lowercase().as_str() {
-            "inf" | "infinity" | "+inf" | "+infinity" => return Ok(Number::PositiveInfinity),
-            "-inf" | "-infinity" => return Ok(Number::NegativeInfinity),
-            "nan" => return Ok(Number::NaN),
-            _ => {}
-        }
-        if let Ok(value) = s.parse::<i64>() {
-            return Ok(Self::from_int(value));
+    // small 构建没有 Float64：±∞ 为无穷变体，NaN 保存为 Float32，
+    // 超出 f32 范围的有限值按当前的溢出策略处理，Saturate 取 ±f32::MAX，Error 为 NaN，其余为 ±∞
+    fn small_float(value: f64, as_f32: f32) -> Self {
+        if value.is_nan() || as_f32.is_finite() {
+            return Number::Float32(as_f32);
         }
-        if let Ok(value) = s.parse::<f64>() {
-            return Ok(Self::from_float(value));
+        let policy = if value.is_finite() {
+            config::with_current(|config| config.overflow)
+        } else {
+            OverflowPolicy::PromoteFloat
+        };
+        match (policy, value > 0.0) {
+            (OverflowPolicy::Saturate, positive) => {
+                Number::Float32(if positive { f32::MAX } else { f32::MIN })
+            }
+            (OverflowPolicy::Error, _) => Number::NaN,
+            (_, true) => Number::PositiveInfinity,
+            (_, false) => Number::NegativeInfinity,
         }
-        Err(format!("Cannot parse '{}' as a number", s))
+    }
+    // 原样保存为 Float32，不经过 f64 和 from_float 的收窄判断，NaN 与无穷的位模式保持不变
+    pub const fn from_f32(value: f32) -> Self {
+        Number::Float32(value)
     }
     pub fn type_name(&self) -> &'static str {
         match self {
             Number::PositiveInfinity => "PositiveInfinity",
             Number::NegativeInfinity => "NegativeInfinity",
             Number::NaN => "NaN",
+            Number::Rational(..) => "Rational",
+            Number::Decimal(..) => "Decimal",
+            Number::Fixed64 { .. } => "Fixed64",
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(_) => "BigInteger",
+            Number::Integer128(_) => "Integer128",
+            Number::UnsignedInteger64(_) => "UnsignedInteger64",
             Number::Integer8(_) => "Integer8",
             Number::Integer16(_) => "Integer16",
             Number::Integer32(_) => "Integer32",
+            #[cfg(not(feature = "small"))]
             Number::Integer64(_) => "Integer64",
             Number::Float32(_) => "Float32",
             Number::Float64(_) => "Float64",
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => v.type_name(),
+            #[cfg(feature = "half")]
+            Number::Float16(_) => "Float16",
+            #[cfg(feature = "half")]
+            Number::BFloat16(_) => "BFloat16",
+        }
+    }
+    pub fn same_kind(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+    // 结构相等：变体必须相同，浮点按位比较（NaN 等于自身，+0.0 不等于 -0.0）
+    pub fn eq_exact(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Number::Float64(a), Number::Float64(b)) => a.to_bits() == b.to_bits(),
+            (Number::Float32(a), Number::Float32(b)) => a.to_bits() == b.to_bits(),
+            #[cfg(feature = "half")]
+            (Number::Float16(a), Number::Float16(b)) => a.to_bits() == b.to_bits(),
+            #[cfg(feature = "half")]
+            (Number::BFloat16(a), Number::BFloat16(b)) => a.to_bits() == b.to_bits(),
+            (Number::Rational(a, b), Number::Rational(c, d)) => a == c && b == d,
+            (Number::Decimal(a, a_scale), Number::Decimal(b, b_scale)) => {
+                a == b && a_scale == b_scale
+            }
+            (
+                Number::Fixed64 {
+                    raw: a,
+                    frac_bits: a_bits,
+                },
+                Number::Fixed64 {
+                    raw: b,
+                    frac_bits: b_bits,
+                },
+            ) => a == b && a_bits == b_bits,
+            #[cfg(feature = "bigint")]
+            (Number::BigInteger(a), Number::BigInteger(b)) => a == b,
+            #[cfg(feature = "extended")]
+            (Number::Extended(a), Number::Extended(b)) => std::sync::Arc::ptr_eq(a, b),
+            _ => self.same_kind(other) && self.int_value() == other.int_value(),
         }
     }
     pub fn to_f64(&self) -> f64 {
@@ -69,12 +301,26 @@ impl Number {
             Number::PositiveInfinity => f64::INFINITY,
             Number::NegativeInfinity => f64::NEG_INFINITY,
             Number::NaN => f64::NAN,
+            Number::Rational(n, d) => *n as f64 / *d as f64,
+            Number::Decimal(m, scale) => *m as f64 / 10f64.powi(*scale as i32),
+            Number::Fixed64 { raw, frac_bits } => *raw as f64 / 2f64.powi(*frac_bits as i32),
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => v.to_f64().unwrap_or(f64::NAN),
+            Number::Integer128(v) => *v as f64,
+            Number::UnsignedInteger64(v) => *v as f64,
+            #[cfg(not(feature = "small"))]
             Number::Integer64(v) => *v as f64,
             Number::Integer32(v) => *v as f64,
             Number::Integer16(v) => *v as f64,
             Number::Integer8(v) => *v as f64,
             Number::Float64(v) => *v,
             Number::Float32(v) => *v as f64,
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => v.to_f64(),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => v.to_f64(),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => v.to_f64(),
         }
     }
     pub fn is_nan(&self) -> bool {
@@ -82,6 +328,12 @@ impl Number {
             Number::NaN => true,
             Number::Float64(v) => v.is_nan(),
             Number::Float32(v) => v.is_nan(),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => v.to_f64().is_nan(),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => v.is_nan(),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => v.is_nan(),
             _ => false,
         }
     }
@@ -90,14 +342,69 @@ impl Number {
             Number::PositiveInfinity | Number::NegativeInfinity => true,
             Number::Float64(v) => v.is_infinite(),
             Number::Float32(v) => v.is_infinite(),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => v.to_f64().is_infinite(),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => v.is_infinite(),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => v.is_infinite(),
             _ => false,
         }
     }
     pub fn is_finite(&self) -> bool {
         !self.is_nan() && !self.is_infinite()
     }
-    pub fn from_f64(value: f64) -> Self {
-        if value.is_nan() {
+    // 与 f64 一致：浮点看符号位（-0.0 为负），精确类型的零没有符号
+    pub fn is_sign_negative(&self) -> bool {
+        match self {
+            Number::PositiveInfinity | Number::NaN => false,
+            Number::NegativeInfinity => true,
+            Number::Rational(n, _) => *n < 0,
+            Number::Decimal(m, _) => *m < 0,
+            Number::Fixed64 { raw, .. } => *raw < 0,
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => v.sign() == num_bigint::Sign::Minus,
+            Number::Integer128(v) => *v < 0,
+            Number::UnsignedInteger64(_) => false,
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => *v < 0,
+            Number::Integer32(v) => *v < 0,
+            Number::Integer16(v) => *v < 0,
+            Number::Integer8(v) => *v < 0,
+            Number::Float64(v) => v.is_sign_negative(),
+            Number::Float32(v) => v.is_sign_negative(),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => v.to_f64().is_sign_negative(),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => v.is_sign_negative(),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => v.is_sign_negative(),
+        }
+    }
+    // 非零且不是 NaN 时返回自身，对应 NonZero 类型的校验
+    pub fn non_zero(&self) -> Option<Number> {
+        (!self.is_nan() && *self != Number::from(0)).then(|| self.clone())
+    }
+    pub fn is_sign_positive(&self) -> bool {
+        !self.is_sign_negative()
+    }
+    pub const fn from_f64(value: f64) -> Self {
+        // small 构建没有 Float64，有限值和带负载的 NaN 存为 Float32。
+        // const 中读不到溢出策略，超出 f32 范围的有限值按默认策略为 ±∞
+        #[cfg(feature = "small")]
+        if value.is_finite() || value.is_nan() && nan::has_payload(value) {
+            let as_f32 = value as f32;
+            return if !as_f32.is_infinite() {
+                Number::Float32(as_f32)
+            } else if value > 0.0 {
+                Number::PositiveInfinity
+            } else {
+                Number::NegativeInfinity
+            };
+        }
+        if value.is_nan() && nan::has_payload(value) {
+            Number::Float64(value)
+        } else if value.is_nan() {
             Number::NaN
         } else if value == f64::INFINITY {
             Number::PositiveInfinity
@@ -107,19 +414,407 @@ impl Number {
             Number::Float64(value)
         }
     }
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.wrapping_op(rhs, i128::wrapping_add, |a, b| a + b)
+    }
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.wrapping_op(rhs, i128::wrapping_sub, |a, b| a - b)
+    }
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.wrapping_op(rhs, i128::wrapping_mul, |a, b| a * b)
+    }
+    pub fn wrapping_shl(self, rhs: u32) -> Self {
+        match self {
+            Number::Integer8(v) => Number::Integer8(v.wrapping_shl(rhs)),
+            Number::Integer16(v) => Number::Integer16(v.wrapping_shl(rhs)),
+            Number::Integer32(v) => Number::Integer32(v.wrapping_shl(rhs)),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => Number::Integer64(v.wrapping_shl(rhs)),
+            Number::UnsignedInteger64(v) => Number::UnsignedInteger64(v.wrapping_shl(rhs)),
+            Number::Integer128(v) => Number::Integer128(v.wrapping_shl(rhs)),
+            _ => Number::NaN,
+        }
+    }
+    // 整数按操作数的宽度判断：结果超出 64 位（Integer128 参与时为 128 位，small 构建为 32 位）
+    // 即为溢出，也就是 OverflowPolicy 生效的情形；浮点数判断结果是否为无穷
+    pub fn will_overflow_add(&self, other: &Self) -> bool {
+        self.int_overflows(other, BinOp::Add)
+            .unwrap_or_else(|| Number::float_overflows(self, other, |a, b| a + b))
+    }
+    pub fn will_overflow_sub(&self, other: &Self) -> bool {
+        self.int_overflows(other, BinOp::Sub)
+            .unwrap_or_else(|| Number::float_overflows(self, other, |a, b| a - b))
+    }
+    pub fn will_overflow_mul(&self, other: &Self) -> bool {
+        match self.int_overflows(other, BinOp::Mul) {
+            Some(overflows) => overflows,
+            None => {
+                if !self.is_finite() || !other.is_finite() {
+                    return false;
+                }
+                let (a, b) = (self.to_f64(), other.to_f64());
+                if a == 0.0 || b == 0.0 {
+                    return false;
+                }
+                // 先用指数粗略判断，只有临界情况才真正做乘法
+                let exp_sum = float_exponent(a) + float_exponent(b);
+                if exp_sum < f64::MAX_EXP {
+                    false
+                } else if exp_sum > f64::MAX_EXP + 1 {
+                    true
+                } else {
+                    (a * b).is_infinite()
+                }
+            }
+        }
+    }
+    fn float_overflows(a: &Number, b: &Number, op: fn(f64, f64) -> f64) -> bool {
+        a.is_finite() && b.is_finite() && op(a.to_f64(), b.to_f64()).is_infinite()
+    }
+    fn wrapping_op(
+        self,
+        rhs: Self,
+        op: fn(i128, i128) -> i128,
+        fallback: fn(Number, Number) -> Number,
+    ) -> Self {
+        match (self.int_bits(), rhs.int_bits()) {
+            (Some(a_bits), Some(b_bits)) => {
+                let result = op(self.int_value().unwrap(), rhs.int_value().unwrap());
+                let bits = a_bits.max(b_bits);
+                let unsigned = matches!(self, Number::UnsignedInteger64(_))
+                    || matches!(rhs, Number::UnsignedInteger64(_));
+                if unsigned && bits == 64 {
+                    Number::UnsignedInteger64(result as u64)
+                } else {
+                    Number::truncate_to_bits(result, bits)
+                }
+            }
+            _ => fallback(self, rhs),
+        }
+    }
+    fn int_bits(&self) -> Option<u32> {
+        match self {
+            Number::Integer8(_) => Some(8),
+            Number::Integer16(_) => Some(16),
+            Number::Integer32(_) => Some(32),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(_) | Number::UnsignedInteger64(_) => Some(64),
+            Number::Integer128(_) => Some(128),
+            _ => None,
+        }
+    }
+    fn is_float_variant(&self) -> bool {
+        #[cfg(feature = "half")]
+        if let Number::Float16(_) | Number::BFloat16(_) = self {
+            return true;
+        }
+        matches!(self, Number::Float32(_) | Number::Float64(_))
+    }
+    fn is_integer_variant(&self) -> bool {
+        #[cfg(feature = "bigint")]
+        if let Number::BigInteger(_) = self {
+            return true;
+        }
+        self.int_value().is_some()
+    }
+    // 两个操作数都是整数时返回精确结果；溢出 i128 且未启用 bigint 时返回 None
+    fn integer_op(&self, rhs: &Self, op: BinOp) -> Option<Number> {
+        if let (Some(a), Some(b)) = (self.int_value(), rhs.int_value())
+            && let Some(result) = op.checked(a, b)
+        {
+            return Some(self.int_result(rhs, op, result));
+        }
+        #[cfg(feature = "bigint")]
+        {
+            self.big_integer_op(rhs, op)
+        }
+        #[cfg(not(feature = "bigint"))]
+        {
+            None
+        }
+    }
+    // 与早期版本一致，Integer64 + Integer64 在范围内时仍为 Integer64；其余结果按值收窄
+    fn int_result(&self, rhs: &Self, op: BinOp, value: i128) -> Number {
+        match (op, self, rhs) {
+            #[cfg(not(feature = "small"))]
+            (BinOp::Add, Number::Integer64(_), Number::Integer64(_))
+                if value >= i64::MIN as i128 && value <= i64::MAX as i128 =>
+            {
+                Number::Integer64(value as i64)
+            }
+            _ => Number::from_wide_int(value),
+        }
+    }
+    pub fn add_with_policy(&self, rhs: &Self, policy: OverflowPolicy) -> Number {
+        self.op_with_policy(rhs, BinOp::Add, policy)
+            .unwrap_or_else(|| self.clone() + rhs.clone())
+    }
+    pub fn sub_with_policy(&self, rhs: &Self, policy: OverflowPolicy) -> Number {
+        self.op_with_policy(rhs, BinOp::Sub, policy)
+            .unwrap_or_else(|| self.clone() - rhs.clone())
+    }
+    pub fn mul_with_policy(&self, rhs: &Self, policy: OverflowPolicy) -> Number {
+        self.op_with_policy(rhs, BinOp::Mul, policy)
+            .unwrap_or_else(|| self.clone() * rhs.clone())
+    }
+    // 按溢出策略处理两个定宽整数的加减乘；不适用时返回 None 交给普通路径。
+    // 范围由操作数决定：Integer128 参与时为 i128，UnsignedInteger64 参与时上界为 u64::MAX，否则为 i64
+    fn op_with_policy(&self, rhs: &Self, op: BinOp, policy: OverflowPolicy) -> Option<Number> {
+        let (a, b) = (self.int_value()?, rhs.int_value()?);
+        // small 构建的最宽整数是 Integer32，PromoteWide 无处可升，按 PromoteFloat 处理
+        let policy = match policy {
+            OverflowPolicy::PromoteWide if cfg!(feature = "small") => OverflowPolicy::PromoteFloat,
+            policy => policy,
+        };
+        if policy == OverflowPolicy::PromoteWide {
+            return self.integer_op(rhs, op);
+        }
+        let (min, max) = self.overflow_range(rhs);
+        let result = op.checked(a, b);
+        if let Some(value) = result
+            && (min..=max).contains(&value)
+        {
+            return Some(self.int_result(rhs, op, value));
+        }
+        match policy {
+            OverflowPolicy::PromoteFloat => {
+                let (x, y) = (a as f64, b as f64);
+                Some(Number::from_float(match op {
+                    BinOp::Add => x + y,
+                    BinOp::Sub => x - y,
+                    _ => x * y,
+                }))
+            }
+            OverflowPolicy::Saturate => {
+                let negative = match result {
+                    Some(value) => value < 0,
+                    None => match op {
+                        BinOp::Add => a < 0,
+                        BinOp::Sub => a < b,
+                        _ => (a < 0) != (b < 0),
+                    },
+                };
+                Some(Number::from_wide_int(if negative { min } else { max }))
+            }
+            _ => Some(Number::NaN),
+        }
+    }
+    fn overflow_range(&self, rhs: &Self) -> (i128, i128) {
+        let is = |kind: fn(&Number) -> bool| kind(self) || kind(rhs);
+        if cfg!(feature = "small") {
+            (i32::MIN as i128, i32::MAX as i128)
+        } else if is(|n| matches!(n, Number::Integer128(_))) {
+            (i128::MIN, i128::MAX)
+        } else if is(|n| matches!(n, Number::UnsignedInteger64(_))) {
+            (i64::MIN as i128, u64::MAX as i128)
+        } else {
+            (i64::MIN as i128, i64::MAX as i128)
+        }
+    }
+    // 两个定宽整数的运算结果是否超出 overflow_range，即是否会触发溢出策略；不是整数时返回 None
+    fn int_overflows(&self, rhs: &Self, op: BinOp) -> Option<bool> {
+        let (a, b) = (self.int_value()?, rhs.int_value()?);
+        let (min, max) = self.overflow_range(rhs);
+        Some(
+            op.checked(a, b)
+                .is_none_or(|value| !(min..=max).contains(&value)),
+        )
+    }
+    // 整数、有理数、十进制小数、定点数之间的精确运算；无法精确完成时返回 None
+    fn exact_op(&self, rhs: &Self, op: BinOp) -> Option<Number> {
+        // f64-only 构建把所有运算降为 f64，精确路径在编译期被裁掉
+        if cfg!(feature = "f64-only") {
+            return None;
+        }
+        #[cfg(feature = "extended")]
+        if let Some(result) = self.extended_op(rhs, op) {
+            return Some(result);
+        }
+        if matches!(op, BinOp::Add | BinOp::Sub | BinOp::Mul)
+            && let Some(result) =
+                self.op_with_policy(rhs, op, config::with_current(|config| config.overflow))
+        {
+            return Some(result);
+        }
+        self.integer_op(rhs, op)
+            .or_else(|| self.rational_op(rhs, op))
+            .or_else(|| self.decimal_op(rhs, op))
+            .or_else(|| self.fixed_op(rhs, op))
+    }
+    fn exact_cmp(&self, other: &Self) -> Option<Ordering> {
+        if cfg!(feature = "f64-only") {
+            return None;
+        }
+        #[cfg(feature = "extended")]
+        if let Some(ordering) = self.extended_cmp(other) {
+            return Some(ordering);
+        }
+        #[cfg(feature = "bigint")]
+        if let Some(ordering) = self.big_integer_cmp(other) {
+            return Some(ordering);
+        }
+        self.integer_cmp(other)
+            .or_else(|| self.rational_cmp(other))
+            .or_else(|| self.decimal_cmp(other))
+            .or_else(|| self.fixed_cmp(other))
+    }
+    // 整数之间、整数与浮点数之间的精确比较，不经过 f64 舍入
+    fn integer_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.int_value(), other.int_value()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            (Some(a), None) if other.is_float_variant() => int_float_cmp(a, other.to_f64()),
+            (None, Some(b)) if self.is_float_variant() => {
+                int_float_cmp(b, self.to_f64()).map(Ordering::reverse)
+            }
+            _ => None,
+        }
+    }
+    fn loses_precision_as_f64(&self) -> bool {
+        self.is_integer_variant()
+            && self
+                .int_value()
+                .is_none_or(|v| v.unsigned_abs() > (1u128 << f64::MANTISSA_DIGITS))
+    }
+    fn int_value(&self) -> Option<i128> {
+        match self {
+            Number::Integer8(v) => Some(*v as i128),
+            Number::Integer16(v) => Some(*v as i128),
+            Number::Integer32(v) => Some(*v as i128),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => Some(*v as i128),
+            Number::UnsignedInteger64(v) => Some(*v as i128),
+            Number::Integer128(v) => Some(*v),
+            _ => None,
+        }
+    }
+    fn truncate_to_bits(value: i128, bits: u32) -> Self {
+        match bits {
+            8 => Number::Integer8(value as i8),
+            16 => Number::Integer16(value as i16),
+            32 => Number::Integer32(value as i32),
+            #[cfg(not(feature = "small"))]
+            64 => Number::Integer64(value as i64),
+            _ => Number::Integer128(value),
+        }
+    }
+    const fn from_wide_int(value: i128) -> Self {
+        if value >= i64::MIN as i128 && value <= i64::MAX as i128 {
+            Number::from_i64(value as i64)
+        } else if cfg!(feature = "small") {
+            Number::Float32(value as f32)
+        } else if value > 0 && value <= u64::MAX as i128 {
+            Number::UnsignedInteger64(value as u64)
+        } else {
+            Number::Integer128(value)
+        }
+    }
+}
+#[derive(Clone, Copy)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+impl BinOp {
+    fn checked(self, a: i128, b: i128) -> Option<i128> {
+        match self {
+            BinOp::Add => a.checked_add(b),
+            BinOp::Sub => a.checked_sub(b),
+            BinOp::Mul => a.checked_mul(b),
+            // 只有整除时才给出整数结果
+            BinOp::Div => match a.checked_rem(b) {
+                Some(0) => a.checked_div(b),
+                _ => None,
+            },
+            BinOp::Rem => {
+                if b == 0 {
+                    None
+                } else {
+                    Some(a.checked_rem(b).unwrap_or(0))
+                }
+            }
+        }
+    }
+}
+fn int_float_cmp(a: i128, b: f64) -> Option<Ordering> {
+    const LIMIT: f64 = 170141183460469231731687303715884105728.0; // 2^127
+    if b.is_nan() {
+        return None;
+    }
+    if b >= LIMIT {
+        return Some(Ordering::Less);
+    }
+    if b < -LIMIT {
+        return Some(Ordering::Greater);
+    }
+    // |b| < 2^127 时整数部分可以精确转为 i128，再用小数部分决定平局
+    let whole = b.trunc();
+    Some(a.cmp(&(whole as i128)).then(if b > whole {
+        Ordering::Less
+    } else if b < whole {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }))
+}
+fn float_exponent(value: f64) -> i32 {
+    let biased = ((value.to_bits() >> 52) & 0x7ff) as i32;
+    if biased == 0 {
+        // 非规格化数按最小指数处理
+        f64::MIN_EXP - 1
+    } else {
+        biased - 1022
+    }
 }
 impl fmt::Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_some() || f.precision().is_some() || f.sign_plus() {
+            return self.fmt_with_flags(f);
+        }
+        config::with_current(|config| self.fmt_with_config(f, config))
+    }
+}
+impl Number {
+    fn fmt_with_config(&self, f: &mut fmt::Formatter<'_>, config: &NumberConfig) -> fmt::Result {
+        #[cfg(feature = "format")]
+        if let Some(options) = &config.format.options {
+            return f.write_str(&self.format_with_config(options, config));
+        }
+        self.fmt_plain(f, config)
+    }
+    fn fmt_plain(&self, f: &mut fmt::Formatter<'_>, config: &NumberConfig) -> fmt::Result {
         match self {
-            Number::PositiveInfinity => write!(f, "∞"),
-            Number::NegativeInfinity => write!(f, "-∞"),
-            Number::NaN => write!(f, "NaN"),
+            Number::PositiveInfinity => f.write_str(config.format.non_finite_tokens.infinity()),
+            Number::NegativeInfinity => {
+                f.write_str(config.format.non_finite_tokens.negative_infinity())
+            }
+            Number::NaN => f.write_str(config.format.non_finite_tokens.nan()),
+            Number::Rational(n, d) => write!(f, "{}/{}", n, d),
+            Number::Decimal(m, scale) => decimal::fmt_decimal(f, *m, *scale),
+            Number::Fixed64 { raw, frac_bits } => fixed::fmt_fixed(f, *raw, *frac_bits),
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => write!(f, "{}", v),
+            Number::Integer128(v) => write!(f, "{}", v),
+            Number::UnsignedInteger64(v) => write!(f, "{}", v),
+            #[cfg(not(feature = "small"))]
             Number::Integer64(v) => write!(f, "{}", v),
             Number::Integer32(v) => write!(f, "{}", v),
             Number::Integer16(v) => write!(f, "{}", v),
             Number::Integer8(v) => write!(f, "{}", v),
-            Number::Float64(v) => write!(f, "{}", v),
-            Number::Float32(v) => write!(f, "{}", v),
+            Number::Float64(v) => config::fmt_float(f, display::RoundTrip::Double(*v), config),
+            Number::Float32(v) => config::fmt_float(f, display::RoundTrip::Single(*v), config),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => write!(f, "{}", v),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => {
+                config::fmt_float(f, display::RoundTrip::Single(v.to_f32()), config)
+            }
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => {
+                config::fmt_float(f, display::RoundTrip::Single(v.to_f32()), config)
+            }
         }
     }
 }
@@ -128,6 +823,9 @@ impl PartialOrd for Number {
         if self.is_nan() || other.is_nan() {
             return None;
         }
+        if let Some(ordering) = self.exact_cmp(other) {
+            return Some(ordering);
+        }
         let self_f64 = self.to_f64();
         let other_f64 = other.to_f64();
         self_f64.partial_cmp(&other_f64)
@@ -138,6 +836,9 @@ impl PartialEq for Number {
         if self.is_nan() || other.is_nan() {
             return false;
         }
+        if let Some(ordering) = self.exact_cmp(other) {
+            return ordering == Ordering::Equal;
+        }
         self.to_f64() == other.to_f64()
     }
 }
@@ -145,61 +846,23 @@ impl Add for Number {
     type Output = Number;
     fn add(self, rhs: Self) -> Self::Output {
         if self.is_nan() || rhs.is_nan() {
-            return Number::NaN;
+            return Number::propagate_nan(&self, &rhs);
         }
-        match (self, rhs) {
-            (Number::PositiveInfinity, Number::NegativeInfinity) => Number::NaN,
-            (Number::NegativeInfinity, Number::PositiveInfinity) => Number::NaN,
+        match (&self, &rhs) {
+            (Number::PositiveInfinity, Number::NegativeInfinity)
+            | (Number::NegativeInfinity, Number::PositiveInfinity) => {
+                Number::invalid_op(NanOrigin::InfMinusInf)
+            }
             (Number::PositiveInfinity, _) | (_, Number::PositiveInfinity) => {
                 Number::PositiveInfinity
             }
             (Number::NegativeInfinity, _) | (_, Number::NegativeInfinity) => {
                 Number::NegativeInfinity
             }
-            (Number::Integer8(a), Number::Integer8(b)) => Number::from_int(a as i64 + b as i64),
-            (Number::Integer16(a), Number::Integer16(b)) => Number::from_int(a as i64 + b as i64),
-            (Number::Integer32(a), Number::Integer32(b)) => Number::from_int(a as i64 + b as i64),
-            (Number::Integer64(a), Number::Integer64(b)) => {
-                if let Some(result) = a.checked_add(b) {
-                    Number::Integer64(result)
-                } else {
-                    Number::Float64(a as f64 + b as f64)
-                }
-            }
-            (
-                a @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-                b @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-            ) => {
-                let a_val = match a {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                let b_val = match b {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                if let Some(result) = a_val.checked_add(b_val) {
-                    Number::from_int(result)
-                } else {
-                    Number::Float64(a_val as f64 + b_val as f64)
-                }
-            }
-            _ => {
+            _ => self.exact_op(&rhs, BinOp::Add).unwrap_or_else(|| {
                 let result = self.to_f64() + rhs.to_f64();
                 Number::from_float(result)
-            }
+            }),
         }
     }
 }
@@ -207,49 +870,21 @@ impl Sub for Number {
     type Output = Number;
     fn sub(self, rhs: Self) -> Self::Output {
         if self.is_nan() || rhs.is_nan() {
-            return Number::NaN;
+            return Number::propagate_nan(&self, &rhs);
         }
-        match (self, rhs) {
-            (Number::PositiveInfinity, Number::PositiveInfinity) => Number::NaN,
-            (Number::NegativeInfinity, Number::NegativeInfinity) => Number::NaN,
+        match (&self, &rhs) {
+            (Number::PositiveInfinity, Number::PositiveInfinity)
+            | (Number::NegativeInfinity, Number::NegativeInfinity) => {
+                Number::invalid_op(NanOrigin::InfMinusInf)
+            }
             (Number::PositiveInfinity, _) => Number::PositiveInfinity,
             (Number::NegativeInfinity, _) => Number::NegativeInfinity,
             (_, Number::PositiveInfinity) => Number::NegativeInfinity,
             (_, Number::NegativeInfinity) => Number::PositiveInfinity,
-            (
-                a @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-                b @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-            ) => {
-                let a_val = match a {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                let b_val = match b {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                if let Some(result) = a_val.checked_sub(b_val) {
-                    Number::from_int(result)
-                } else {
-                    Number::Float64(a_val as f64 - b_val as f64)
-                }
-            }
-            _ => {
+            _ => self.exact_op(&rhs, BinOp::Sub).unwrap_or_else(|| {
                 let result = self.to_f64() - rhs.to_f64();
                 Number::from_float(result)
-            }
+            }),
         }
     }
 }
@@ -257,172 +892,96 @@ impl Mul for Number {
     type Output = Number;
     fn mul(self, rhs: Self) -> Self::Output {
         if self.is_nan() || rhs.is_nan() {
-            return Number::NaN;
+            return Number::propagate_nan(&self, &rhs);
         }
         let self_f64 = self.to_f64();
         let rhs_f64 = rhs.to_f64();
         if (self.is_infinite() && rhs_f64 == 0.0) || (rhs.is_infinite() && self_f64 == 0.0) {
-            return Number::NaN;
+            return Number::invalid_op(NanOrigin::ZeroTimesInf);
         }
         if self.is_infinite() || rhs.is_infinite() {
             let result = self_f64 * rhs_f64;
             return Number::from_f64(result);
         }
-        match (self, rhs) {
-            (
-                a @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-                b @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-            ) => {
-                let a_val = match a {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                let b_val = match b {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                if let Some(result) = a_val.checked_mul(b_val) {
-                    Number::from_int(result)
-                } else {
-                    Number::Float64(a_val as f64 * b_val as f64)
-                }
-            }
-            _ => {
-                let result = self_f64 * rhs_f64;
-                Number::from_float(result)
-            }
-        }
+        self.exact_op(&rhs, BinOp::Mul).unwrap_or_else(|| {
+            let result = self_f64 * rhs_f64;
+            Number::from_float(result)
+        })
     }
 }
 impl Div for Number {
     type Output = Number;
     fn div(self, rhs: Self) -> Self::Output {
         if self.is_nan() || rhs.is_nan() {
-            return Number::NaN;
+            return Number::propagate_nan(&self, &rhs);
         }
         let self_f64 = self.to_f64();
         let rhs_f64 = rhs.to_f64();
         if rhs_f64 == 0.0 && self_f64 == 0.0 {
-            return Number::NaN;
+            return Number::invalid_op(NanOrigin::DivZeroZero);
         }
         if self.is_infinite() && rhs.is_infinite() {
-            return Number::NaN;
+            return Number::invalid_op(NanOrigin::InfDivInf);
         }
-        match (self, rhs) {
-            (
-                a @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-                b @ (Number::Integer8(_)
-                | Number::Integer16(_)
-                | Number::Integer32(_)
-                | Number::Integer64(_)),
-            ) => {
-                let a_val = match a {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                let b_val = match b {
-                    Number::Integer8(v) => v as i64,
-                    Number::Integer16(v) => v as i64,
-                    Number::Integer32(v) => v as i64,
-                    Number::Integer64(v) => v,
-                    _ => unreachable!(),
-                };
-                if b_val != 0 && a_val % b_val == 0 {
-                    Number::from_int(a_val / b_val)
-                } else {
-                    let result = a_val as f64 / b_val as f64;
-                    Number::from_float(result)
-                }
-            }
-            _ => {
-                let result = self_f64 / rhs_f64;
-                Number::from_float(result)
-            }
+        // 除以零按 IEEE 754 取符号：1 / -0.0 = -∞，-1 / 0 = -∞
+        if rhs_f64 == 0.0 {
+            return Number::from_f64(self_f64 / rhs_f64);
         }
+        self.exact_op(&rhs, BinOp::Div).unwrap_or_else(|| {
+            let result = self_f64 / rhs_f64;
+            Number::from_float(result)
+        })
     }
 }
 impl AddAssign for Number {
     fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+        let lhs = std::mem::replace(self, Number::NaN);
+        *self = lhs + rhs;
     }
 }
 impl SubAssign for Number {
     fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+        let lhs = std::mem::replace(self, Number::NaN);
+        *self = lhs - rhs;
     }
 }
 impl MulAssign for Number {
     fn mul_assign(&mut self, rhs: Self) {
-        *self = *self * rhs;
+        let lhs = std::mem::replace(self, Number::NaN);
+        *self = lhs * rhs;
     }
 }
 impl DivAssign for Number {
     fn div_assign(&mut self, rhs: Self) {
-        *self = *self / rhs;
+        let lhs = std::mem::replace(self, Number::NaN);
+        *self = lhs / rhs;
     }
 }
 impl RemAssign for Number {
     fn rem_assign(&mut self, rhs: Self) {
         if self.is_nan() || rhs.is_nan() {
-            *self = Number::NaN;
+            *self = Number::propagate_nan(self, &rhs);
             return;
         }
         if self.is_infinite() || rhs.is_infinite() {
-            *self = Number::NaN;
+            *self = Number::invalid_op(NanOrigin::RemOfInfinity);
             return;
         }
-        match (&*self, &rhs) {
-            (Number::Integer8(a), Number::Integer8(b)) => {
-                if *b == 0 {
-                    *self = Number::NaN;
-                } else {
-                    *self = Number::from_int((*a as i64) % (*b as i64));
-                }
-            }
-            (Number::Integer16(a), Number::Integer16(b)) => {
-                if *b == 0 {
-                    *self = Number::NaN;
-                } else {
-                    *self = Number::from_int((*a as i64) % (*b as i64));
-                }
-            }
-            (Number::Integer32(a), Number::Integer32(b)) => {
-                if *b == 0 {
-                    *self = Number::NaN;
-                } else {
-                    *self = Number::from_int((*a as i64) % (*b as i64));
-                }
-            }
-            (Number::Integer64(a), Number::Integer64(b)) => {
-                if *b == 0 {
-                    *self = Number::NaN;
-                } else {
-                    *self = Number::from_int(*a % *b);
-                }
+        match (self.is_integer_variant(), rhs.is_integer_variant()) {
+            (true, true) if !cfg!(feature = "f64-only") => {
+                *self = self
+                    .integer_op(&rhs, BinOp::Rem)
+                    .unwrap_or(Number::invalid_op(NanOrigin::RemByZero));
             }
             _ => {
+                if let Some(result) = self.exact_op(&rhs, BinOp::Rem) {
+                    *self = result;
+                    return;
+                }
                 let a_f = self.to_f64();
                 let b_f = rhs.to_f64();
                 if b_f == 0.0 {
-                    *self = Number::NaN;
+                    *self = Number::invalid_op(NanOrigin::RemByZero);
                 } else {
                     *self = Number::from_float(a_f % b_f);
                 }
@@ -438,44 +997,145 @@ impl From<i8> for Number {
 }
 impl From<i16> for Number {
     fn from(value: i16) -> Self {
-        Number::from_int(value as i64)
+        Number::from_i64(value as i64)
     }
 }
 impl From<i32> for Number {
     fn from(value: i32) -> Self {
-        Number::from_int(value as i64)
+        Number::from_i64(value as i64)
     }
 }
+#[cfg(not(feature = "small"))]
 impl From<i64> for Number {
     fn from(value: i64) -> Self {
-        Number::from_int(value)
+        Number::from_i64(value)
     }
 }
 impl From<u8> for Number {
     fn from(value: u8) -> Self {
-        Number::from_int(value as i64)
+        Number::from_i64(value as i64)
     }
 }
 impl From<u16> for Number {
     fn from(value: u16) -> Self {
-        Number::from_int(value as i64)
+        Number::from_i64(value as i64)
     }
 }
 impl From<u32> for Number {
     fn from(value: u32) -> Self {
-        Number::from_int(value as i64)
+        Number::from_i64(value as i64)
     }
 }
+#[cfg(not(feature = "small"))]
 impl From<u64> for Number {
     fn from(value: u64) -> Self {
-        Number::from_int(value as i64)
+        if value > i64::MAX as u64 {
+            Number::UnsignedInteger64(value)
+        } else {
+            Number::from_i64(value as i64)
+        }
+    }
+}
+#[cfg(not(feature = "small"))]
+impl From<i128> for Number {
+    fn from(value: i128) -> Self {
+        Number::from_wide_int(value)
+    }
+}
+#[cfg(not(feature = "small"))]
+impl From<u128> for Number {
+    fn from(value: u128) -> Self {
+        if value > i128::MAX as u128 {
+            #[cfg(feature = "bigint")]
+            return Number::BigInteger(BigInt::from(value));
+            #[cfg(not(feature = "bigint"))]
+            return Number::Float64(value as f64);
+        }
+        Number::from_wide_int(value as i128)
+    }
+}
+// usize / isize 在所有支持的平台上都不超过 64 位，大于 i64::MAX 的长度得到 UnsignedInteger64
+impl From<usize> for Number {
+    fn from(value: usize) -> Self {
+        Number::from_wide_int(value as i128)
+    }
+}
+impl From<isize> for Number {
+    fn from(value: isize) -> Self {
+        Number::from_wide_int(value as i128)
+    }
+}
+// NonZero 包装类型按内部整数转换
+impl From<std::num::NonZeroI8> for Number {
+    fn from(value: std::num::NonZeroI8) -> Self {
+        Number::from(value.get())
+    }
+}
+impl From<std::num::NonZeroI16> for Number {
+    fn from(value: std::num::NonZeroI16) -> Self {
+        Number::from(value.get())
+    }
+}
+impl From<std::num::NonZeroI32> for Number {
+    fn from(value: std::num::NonZeroI32) -> Self {
+        Number::from(value.get())
+    }
+}
+#[cfg(not(feature = "small"))]
+impl From<std::num::NonZeroI64> for Number {
+    fn from(value: std::num::NonZeroI64) -> Self {
+        Number::from(value.get())
+    }
+}
+#[cfg(not(feature = "small"))]
+impl From<std::num::NonZeroI128> for Number {
+    fn from(value: std::num::NonZeroI128) -> Self {
+        Number::from(value.get())
+    }
+}
+impl From<std::num::NonZeroIsize> for Number {
+    fn from(value: std::num::NonZeroIsize) -> Self {
+        Number::from(value.get())
+    }
+}
+impl From<std::num::NonZeroU8> for Number {
+    fn from(value: std::num::NonZeroU8) -> Self {
+        Number::from(value.get())
+    }
+}
+impl From<std::num::NonZeroU16> for Number {
+    fn from(value: std::num::NonZeroU16) -> Self {
+        Number::from(value.get())
+    }
+}
+impl From<std::num::NonZeroU32> for Number {
+    fn from(value: std::num::NonZeroU32) -> Self {
+        Number::from(value.get())
+    }
+}
+#[cfg(not(feature = "small"))]
+impl From<std::num::NonZeroU64> for Number {
+    fn from(value: std::num::NonZeroU64) -> Self {
+        Number::from(value.get())
+    }
+}
+#[cfg(not(feature = "small"))]
+impl From<std::num::NonZeroU128> for Number {
+    fn from(value: std::num::NonZeroU128) -> Self {
+        Number::from(value.get())
+    }
+}
+impl From<std::num::NonZeroUsize> for Number {
+    fn from(value: std::num::NonZeroUsize) -> Self {
+        Number::from(value.get())
     }
 }
 impl From<f32> for Number {
     fn from(value: f32) -> Self {
-        Number::from_float(value as f64)
+        Number::from_f32(value)
     }
 }
+#[cfg(not(feature = "small"))]
 impl From<f64> for Number {
     fn from(value: f64) -> Self {
         Number::from_float(value)
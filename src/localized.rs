@@ -0,0 +1,73 @@
+use crate::Number;
+use crate::config::{self, FormatOptions, NumberConfig, Plain};
+use crate::display::terminating_places;
+
+impl Number {
+    // 按 options 的分组符、小数点和小数位数输出，例如 de 区域下 1234567.89 为 "1.234.567,89"。
+    // 非有限值未指定 non_finite_tokens 时与 Display 相同，可以用 Number::parse_with(s, &options.parse_config()) 读回
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        config::with_current(|config| self.format_with_config(options, config))
+    }
+    pub(crate) fn format_with_config(
+        &self,
+        options: &FormatOptions,
+        config: &NumberConfig,
+    ) -> String {
+        let tokens = options
+            .non_finite_tokens
+            .as_ref()
+            .unwrap_or(&config.format.non_finite_tokens);
+        match self {
+            Number::PositiveInfinity => return tokens.infinity().to_string(),
+            Number::NegativeInfinity => return tokens.negative_infinity().to_string(),
+            Number::NaN => return tokens.nan().to_string(),
+            _ if !self.is_finite() => return Plain(self, config).to_string(),
+            _ => {}
+        }
+        let text = match options.max_fraction_digits {
+            Some(max) => format!("{:.*}", max.max(options.min_fraction_digits), self),
+            None if matches!(self, Number::Rational(..)) => match self.exact_ratio() {
+                Some((_, d)) => match terminating_places(d) {
+                    Some(places) => format!("{:.*}", places, self),
+                    None => self.to_f64().to_string(),
+                },
+                None => self.to_f64().to_string(),
+            },
+            None => Plain(self, config).to_string(),
+        };
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text.as_str()),
+        };
+        // 科学计数法只对尾数分组和补齐小数位，指数部分原样接在最后
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(index) => unsigned.split_at(index),
+            None => (unsigned, ""),
+        };
+        let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let mut frac_part = frac_part.to_string();
+        while frac_part.len() > options.min_fraction_digits && frac_part.ends_with('0') {
+            frac_part.pop();
+        }
+        while frac_part.len() < options.min_fraction_digits {
+            frac_part.push('0');
+        }
+        let mut out = String::with_capacity(text.len() + int_part.len() / 3 + 1);
+        out.push_str(sign);
+        for (i, digit) in int_part.chars().enumerate() {
+            if i > 0
+                && (int_part.len() - i) % 3 == 0
+                && let Some(separator) = options.group_separator
+            {
+                out.push(separator);
+            }
+            out.push(digit);
+        }
+        if !frac_part.is_empty() {
+            out.push(options.decimal_separator);
+            out.push_str(&frac_part);
+        }
+        out.push_str(exponent);
+        out
+    }
+}
@@ -0,0 +1,112 @@
+use crate::Number;
+
+// 字面量在编译期转换为对应变体，不经过运行时解析：
+// num!(1_000_000) 为整数，num!(2.5) 为 Float64，num!(2.5f32) 为 Float32，
+// num!(-3 / 4) 为约分后的 Rational，num!(inf)、num!(-inf)、num!(nan) 为特殊值
+#[macro_export]
+macro_rules! num {
+    (inf) => {
+        $crate::Number::PositiveInfinity
+    };
+    (-inf) => {
+        $crate::Number::NegativeInfinity
+    };
+    (nan) => {
+        $crate::Number::NaN
+    };
+    ($numerator:literal / $denominator:literal) => {
+        const { $crate::Number::rational($numerator, $denominator) }
+    };
+    ($value:literal) => {
+        const {
+            // 整数字面量在 as f64 中按 i32 推断，大整数会触发该 lint；整数的值另从原文解析
+            #[allow(overflowing_literals)]
+            let float = $value as f64;
+            $crate::Number::from_literal(stringify!($value), float)
+        }
+    };
+}
+
+impl Number {
+    // 供 num! 使用：text 是字面量原文，浮点字面量取 float，整数字面量从原文解析
+    #[doc(hidden)]
+    pub const fn from_literal(text: &str, float: f64) -> Number {
+        let bytes = text.as_bytes();
+        if ends_with(bytes, b"f32") {
+            return Number::from_f32(float as f32);
+        }
+        if ends_with(bytes, b"f64") {
+            return Number::from_f64(float);
+        }
+        let mut i = 0;
+        let negative = bytes[0] == b'-';
+        if negative {
+            i += 1;
+            while bytes[i] == b' ' {
+                i += 1;
+            }
+        }
+        let radix = if i + 1 < bytes.len() && bytes[i] == b'0' {
+            match bytes[i + 1] {
+                b'x' => 16,
+                b'o' => 8,
+                b'b' => 2,
+                _ => 10,
+            }
+        } else {
+            10
+        };
+        if radix != 10 {
+            i += 2;
+        } else if !ends_with(bytes, b"size") {
+            // isize/usize 后缀中的 e 不代表指数
+            let mut j = i;
+            while j < bytes.len() {
+                if matches!(bytes[j], b'.' | b'e' | b'E') {
+                    return Number::from_f64(float);
+                }
+                j += 1;
+            }
+        }
+        let mut value: i128 = 0;
+        while i < bytes.len() {
+            let digit = match bytes[i] {
+                b'_' => {
+                    i += 1;
+                    continue;
+                }
+                b @ b'0'..=b'9' => (b - b'0') as i128,
+                b @ b'a'..=b'f' if radix == 16 => (b - b'a' + 10) as i128,
+                b @ b'A'..=b'F' if radix == 16 => (b - b'A' + 10) as i128,
+                // 其余字符是 i32、u64 这样的类型后缀
+                _ => break,
+            };
+            // 负数按负方向累加，i128::MIN 也能表示
+            let digit = if negative { -digit } else { digit };
+            value = match value.checked_mul(radix) {
+                Some(v) => match v.checked_add(digit) {
+                    Some(v) => v,
+                    None => panic!("integer literal is out of range for Number"),
+                },
+                None => panic!("integer literal is out of range for Number"),
+            };
+            i += 1;
+        }
+        Number::from_wide_int(value)
+    }
+}
+
+const fn ends_with(bytes: &[u8], suffix: &[u8]) -> bool {
+    if bytes.len() < suffix.len() {
+        return false;
+    }
+    let offset = bytes.len() - suffix.len();
+    let mut i = 0;
+    while i < suffix.len() {
+        if bytes[offset + i] != suffix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,379 @@
+use std::error::Error;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, TAU};
+use std::fmt;
+
+use crate::Number;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    DomainError,
+    PoleError,
+    Overflow,
+    LossOfPrecision,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::DomainError => write!(f, "argument outside the function's domain"),
+            MathError::PoleError => write!(f, "function has a pole at this argument"),
+            MathError::Overflow => write!(f, "result overflows"),
+            MathError::LossOfPrecision => write!(f, "argument cannot be represented exactly"),
+        }
+    }
+}
+
+impl Error for MathError {}
+
+impl Number {
+    pub fn try_sqrt(&self) -> Result<Number, MathError> {
+        let x = self.math_arg()?;
+        if x < 0.0 {
+            return Err(MathError::DomainError);
+        }
+        math_result(x, x.sqrt())
+    }
+    pub fn try_ln(&self) -> Result<Number, MathError> {
+        let x = self.log_arg()?;
+        math_result(x, x.ln())
+    }
+    pub fn try_log2(&self) -> Result<Number, MathError> {
+        let x = self.log_arg()?;
+        math_result(x, x.log2())
+    }
+    pub fn try_log10(&self) -> Result<Number, MathError> {
+        let x = self.log_arg()?;
+        math_result(x, x.log10())
+    }
+    pub fn try_exp(&self) -> Result<Number, MathError> {
+        let x = self.math_arg()?;
+        math_result(x, x.exp())
+    }
+    pub fn try_asin(&self) -> Result<Number, MathError> {
+        let x = self.math_arg()?;
+        if !(-1.0..=1.0).contains(&x) {
+            return Err(MathError::DomainError);
+        }
+        math_result(x, x.asin())
+    }
+    pub fn try_acos(&self) -> Result<Number, MathError> {
+        let x = self.math_arg()?;
+        if !(-1.0..=1.0).contains(&x) {
+            return Err(MathError::DomainError);
+        }
+        math_result(x, x.acos())
+    }
+    pub fn try_acosh(&self) -> Result<Number, MathError> {
+        let x = self.math_arg()?;
+        if x < 1.0 {
+            return Err(MathError::DomainError);
+        }
+        math_result(x, x.acosh())
+    }
+    pub fn try_atanh(&self) -> Result<Number, MathError> {
+        let x = self.math_arg()?;
+        if x == 1.0 || x == -1.0 {
+            return Err(MathError::PoleError);
+        }
+        if !(-1.0..=1.0).contains(&x) {
+            return Err(MathError::DomainError);
+        }
+        math_result(x, x.atanh())
+    }
+    pub fn sin_deg(&self) -> Number {
+        let x = self.to_f64();
+        if !x.is_finite() {
+            return Number::NaN;
+        }
+        float_result(sin_deg_reduced(x.rem_euclid(360.0)))
+    }
+    pub fn cos_deg(&self) -> Number {
+        let x = self.to_f64();
+        if !x.is_finite() {
+            return Number::NaN;
+        }
+        float_result(sin_deg_reduced(
+            (x.rem_euclid(360.0) + 90.0).rem_euclid(360.0),
+        ))
+    }
+    pub fn tan_deg(&self) -> Number {
+        let x = self.to_f64();
+        if !x.is_finite() {
+            return Number::NaN;
+        }
+        let r = x.rem_euclid(180.0);
+        match r {
+            0.0 => float_result(0.0),
+            45.0 => float_result(1.0),
+            90.0 => Number::NaN,
+            135.0 => float_result(-1.0),
+            _ => {
+                let sin = sin_deg_reduced(r);
+                let cos = sin_deg_reduced((r + 90.0).rem_euclid(360.0));
+                float_result(sin / cos)
+            }
+        }
+    }
+    pub fn asin_deg(&self) -> Number {
+        let x = self.to_f64();
+        if x.is_nan() || !(-1.0..=1.0).contains(&x) {
+            return Number::NaN;
+        }
+        float_result(asin_deg_exact(x))
+    }
+    pub fn acos_deg(&self) -> Number {
+        let x = self.to_f64();
+        if x.is_nan() || !(-1.0..=1.0).contains(&x) {
+            return Number::NaN;
+        }
+        let exact = asin_deg_exact(x);
+        if EXACT_SINES.iter().any(|&(_, sin)| sin == x.abs()) {
+            float_result(90.0 - exact)
+        } else {
+            float_result(x.acos().to_degrees())
+        }
+    }
+    pub fn atan_deg(&self) -> Number {
+        let x = self.to_f64();
+        if x.is_nan() {
+            return Number::NaN;
+        }
+        let magnitude = match x.abs() {
+            0.0 => 0.0,
+            FRAC_1_SQRT_3 => 30.0,
+            1.0 => 45.0,
+            SQRT_3 => 60.0,
+            f64::INFINITY => 90.0,
+            a => a.atan().to_degrees(),
+        };
+        float_result(magnitude.copysign(x))
+    }
+    pub fn rem_two_pi(&self) -> Number {
+        let x = self.to_f64();
+        if !x.is_finite() {
+            return Number::NaN;
+        }
+        let (quadrant, remainder) = reduce_quadrant(x);
+        let angle = quadrant as f64 * FRAC_PI_2 + remainder;
+        if angle < 0.0 {
+            float_result(angle + TAU)
+        } else if angle >= TAU {
+            float_result(angle - TAU)
+        } else {
+            float_result(angle)
+        }
+    }
+    pub fn sin_cos(&self) -> (Number, Number) {
+        let x = self.to_f64();
+        if !x.is_finite() {
+            return (Number::NaN, Number::NaN);
+        }
+        let (quadrant, remainder) = reduce_quadrant(x);
+        let (sin, cos) = remainder.sin_cos();
+        let (sin, cos) = match quadrant {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        };
+        (float_result(sin), float_result(cos))
+    }
+    pub fn gamma(&self) -> Number {
+        // 正整数参数直接给出精确的阶乘
+        if let Some(n) = self.int_value()
+            && (1..=34).contains(&n)
+        {
+            return Number::from_wide_int((1..n).product::<i128>());
+        }
+        let x = self.to_f64();
+        if x.is_nan() || (x <= 0.0 && x == x.floor()) {
+            return Number::NaN;
+        }
+        float_result(libm::tgamma(x))
+    }
+    pub fn ln_gamma(&self) -> Number {
+        let x = self.to_f64();
+        if x.is_nan() {
+            return Number::NaN;
+        }
+        float_result(libm::lgamma(x))
+    }
+    pub fn erf(&self) -> Number {
+        float_result(libm::erf(self.to_f64()))
+    }
+    pub fn erfc(&self) -> Number {
+        float_result(libm::erfc(self.to_f64()))
+    }
+    pub fn beta(a: &Number, b: &Number) -> Number {
+        let (x, y) = (a.to_f64(), b.to_f64());
+        if x.is_nan() || y.is_nan() {
+            return Number::NaN;
+        }
+        let (ln_x, sign_x) = libm::lgamma_r(x);
+        let (ln_y, sign_y) = libm::lgamma_r(y);
+        let (ln_xy, sign_xy) = libm::lgamma_r(x + y);
+        let sign = (sign_x * sign_y * sign_xy) as f64;
+        float_result(sign * (ln_x + ln_y - ln_xy).exp())
+    }
+    // 把任意 f64 函数提升到 Number 上，结果重新收窄
+    pub fn apply(&self, f: impl Fn(f64) -> f64) -> Number {
+        float_result(f(self.to_f64()))
+    }
+    pub fn apply_checked(&self, f: impl Fn(f64) -> f64) -> Result<Number, MathError> {
+        let x = self.math_arg()?;
+        math_result(x, f(x))
+    }
+    fn math_arg(&self) -> Result<f64, MathError> {
+        if self.is_nan() {
+            return Err(MathError::DomainError);
+        }
+        if self.loses_precision_as_f64() {
+            return Err(MathError::LossOfPrecision);
+        }
+        Ok(self.to_f64())
+    }
+    fn log_arg(&self) -> Result<f64, MathError> {
+        let x = self.math_arg()?;
+        if x == 0.0 {
+            return Err(MathError::PoleError);
+        }
+        if x < 0.0 {
+            return Err(MathError::DomainError);
+        }
+        Ok(x)
+    }
+}
+
+// 1/(2π) 的前 1280 位，用于 Payne–Hanek 规约
+const FRAC_1_TAU_BITS: [u64; 20] = [
+    0x28BE60DB9391054A,
+    0x7F09D5F47D4D3770,
+    0x36D8A5664F10E410,
+    0x7F9458EAF7AEF158,
+    0x6DC91B8E909374B8,
+    0x01924BBA82746487,
+    0x3F877AC72C4A69CF,
+    0xBA208D7D4BAED121,
+    0x3A671C09AD17DF90,
+    0x4E64758E60D4CE7D,
+    0x272117E2EF7E4A0E,
+    0xC7FE25FFF7816603,
+    0xFBCBC462D6829B47,
+    0xDB4D9FB3C9F2C26D,
+    0xD3D18FD9A797FA8B,
+    0x5D49EEB1FAF97C5E,
+    0xCF41CE7DE294A4BA,
+    0x9AFED7EC47E35742,
+    0x1580CC11BF1EDAEA,
+    0xFC33EF0826BD0D87,
+];
+
+// 把 x 写成 q·π/2 + r，其中 q ∈ 0..4，r ∈ [-π/4, π/4]
+fn reduce_quadrant(x: f64) -> (u32, f64) {
+    if x.abs() <= FRAC_PI_4 {
+        return (0, x);
+    }
+    let bits = x.abs().to_bits();
+    let mantissa = (bits & ((1u64 << 52) - 1)) | (1u64 << 52);
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    // x/(2π) = m · 2^e · C，整数部分对取模无贡献，只需 C 在 2^-e 之后的位
+    let p0 = mantissa as u128 * frac_1_tau_window(exponent) as u128;
+    let p1 = mantissa as u128 * frac_1_tau_window(exponent + 64) as u128;
+    let p2 = mantissa as u128 * frac_1_tau_window(exponent + 128) as u128;
+    let mid = (p2 >> 64) + (p1 as u64 as u128);
+    let high = (p0 as u64)
+        .wrapping_add((p1 >> 64) as u64)
+        .wrapping_add((mid >> 64) as u64);
+    let turn = ((high as u128) << 64) | (mid as u64 as u128);
+    let quadrant = (turn.wrapping_add(1 << 125) >> 126) as u32;
+    let remainder = turn.wrapping_sub((quadrant as u128) << 126) as i128;
+    // 用双倍精度完成 remainder · 2π，避免两次舍入
+    let scale = 2f64.powi(-128);
+    let high_part = remainder as f64;
+    let low_part = (remainder - high_part as i128) as f64;
+    let product = high_part * TAU;
+    let error = high_part.mul_add(TAU, -product) + high_part * TAU_LOW + low_part * TAU;
+    let remainder = (product + error) * scale;
+    if x < 0.0 {
+        ((4 - quadrant) % 4, -remainder)
+    } else {
+        (quadrant, remainder)
+    }
+}
+
+// 取 1/(2π) 小数点后第 offset+1 到 offset+64 位
+fn frac_1_tau_window(offset: i32) -> u64 {
+    if offset <= -64 {
+        return 0;
+    }
+    if offset < 0 {
+        return FRAC_1_TAU_BITS[0] >> -offset;
+    }
+    let word = |i: usize| FRAC_1_TAU_BITS.get(i).copied().unwrap_or(0);
+    let index = (offset / 64) as usize;
+    let shift = offset % 64;
+    if shift == 0 {
+        word(index)
+    } else {
+        (word(index) << shift) | (word(index + 1) >> (64 - shift))
+    }
+}
+
+const TAU_LOW: f64 = 2.4492935982947064e-16;
+const FRAC_SQRT_3_2: f64 = 0.8660254037844386;
+const FRAC_1_SQRT_3: f64 = 0.5773502691896258;
+const SQRT_3: f64 = 1.7320508075688772;
+const EXACT_SINES: [(f64, f64); 5] = [
+    (0.0, 0.0),
+    (30.0, 0.5),
+    (45.0, std::f64::consts::FRAC_1_SQRT_2),
+    (60.0, FRAC_SQRT_3_2),
+    (90.0, 1.0),
+];
+
+// 输入已规约到 [0, 360)
+fn sin_deg_reduced(r: f64) -> f64 {
+    let (sign, r) = if r >= 180.0 {
+        (-1.0, r - 180.0)
+    } else {
+        (1.0, r)
+    };
+    let r = if r > 90.0 { 180.0 - r } else { r };
+    let value = EXACT_SINES
+        .iter()
+        .find(|&&(angle, _)| angle == r)
+        .map(|&(_, sin)| sin)
+        .unwrap_or_else(|| r.to_radians().sin());
+    if value == 0.0 { 0.0 } else { sign * value }
+}
+
+fn asin_deg_exact(x: f64) -> f64 {
+    let magnitude = EXACT_SINES
+        .iter()
+        .find(|&&(_, sin)| sin == x.abs())
+        .map(|&(angle, _)| angle)
+        .unwrap_or_else(|| x.abs().asin().to_degrees());
+    magnitude.copysign(x)
+}
+
+fn float_result(value: f64) -> Number {
+    if value.is_finite() {
+        Number::from_float(value)
+    } else {
+        Number::from_f64(value)
+    }
+}
+
+fn math_result(arg: f64, value: f64) -> Result<Number, MathError> {
+    if value.is_nan() {
+        Err(MathError::DomainError)
+    } else if value.is_infinite() {
+        if arg.is_finite() {
+            Err(MathError::Overflow)
+        } else {
+            Ok(Number::from_f64(value))
+        }
+    } else {
+        Ok(Number::from_float(value))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::Number;
+
+// 批量转换，便于从纯 f64 / i64 代码逐步迁移
+impl Number {
+    // 有限值按 from_float 收窄，NaN 与 ±∞ 转为对应的变体
+    pub fn wrap(value: f64) -> Number {
+        if value.is_finite() {
+            Number::from_float(value)
+        } else {
+            Number::from_f64(value)
+        }
+    }
+    pub fn wrap_slice(values: &[f64]) -> Vec<Number> {
+        values.iter().map(|&v| Number::wrap(v)).collect()
+    }
+    #[cfg(not(feature = "small"))]
+    pub fn wrap_i64_slice(values: &[i64]) -> Vec<Number> {
+        values.iter().map(|&v| Number::from_i64(v)).collect()
+    }
+    pub fn unwrap_or_nan(numbers: &[Number]) -> Vec<f64> {
+        numbers.iter().map(Number::to_f64).collect()
+    }
+}
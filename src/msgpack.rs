@@ -0,0 +1,175 @@
+// MessagePack 编码。整数按最短的格式写出（fixint、int 8..64、uint 8..64），
+// Float64 写成 float 64，Float32、Float16、BFloat16 写成 float 32，NaN 和 ±∞ 写成 float 32，
+// 带载荷的 NaN 按 float 64 保留位模式。没有原生类型的值（Rational、Decimal、Fixed64、
+// 超出 64 位的整数、BigInteger）写成扩展类型 Number::MSGPACK_EXT_TYPE，负载为 to_le_bytes 的编码。
+// Extended 按 f64 写出。
+
+use crate::Number;
+use crate::codec::{DecodeError, Reader};
+use crate::nan;
+
+impl Number {
+    pub const MSGPACK_EXT_TYPE: i8 = 0x4e;
+
+    pub fn to_msgpack(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_number(&mut out, self);
+        out
+    }
+    // bytes 必须恰好是一个数字，多余的字节报 TrailingBytes
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Number, DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let number = read_number(&mut reader)?;
+        reader.finish()?;
+        Ok(number)
+    }
+    // 从 bytes 开头读出一个数字，同时返回占用的字节数
+    pub fn read_msgpack(bytes: &[u8]) -> Result<(Number, usize), DecodeError> {
+        let mut reader = Reader::new(bytes);
+        let number = read_number(&mut reader)?;
+        Ok((number, reader.position()))
+    }
+}
+
+fn write_number(out: &mut Vec<u8>, number: &Number) {
+    match number {
+        Number::PositiveInfinity => write_f32(out, f32::INFINITY),
+        Number::NegativeInfinity => write_f32(out, f32::NEG_INFINITY),
+        Number::NaN => write_f32(out, f32::NAN),
+        Number::Float64(v) => write_f64(out, *v),
+        Number::Float32(v) => write_f32(out, *v),
+        #[cfg(feature = "half")]
+        Number::Float16(v) => write_f32(out, v.to_f32()),
+        #[cfg(feature = "half")]
+        Number::BFloat16(v) => write_f32(out, v.to_f32()),
+        #[cfg(feature = "extended")]
+        Number::Extended(v) => write_f64(out, v.to_f64()),
+        _ => match number.int_value() {
+            Some(v) if v >= i64::MIN as i128 && v <= u64::MAX as i128 => write_int(out, v),
+            _ => write_ext(out, &number.to_le_bytes()),
+        },
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, value: i128) {
+    if value >= 0 {
+        if value < 0x80 {
+            out.push(value as u8);
+        } else if let Ok(v) = u8::try_from(value) {
+            out.extend_from_slice(&[0xcc, v]);
+        } else if let Ok(v) = u16::try_from(value) {
+            out.push(0xcd);
+            out.extend_from_slice(&v.to_be_bytes());
+        } else if let Ok(v) = u32::try_from(value) {
+            out.push(0xce);
+            out.extend_from_slice(&v.to_be_bytes());
+        } else {
+            out.push(0xcf);
+            out.extend_from_slice(&(value as u64).to_be_bytes());
+        }
+    } else if value >= -32 {
+        out.push(value as i8 as u8);
+    } else if let Ok(v) = i8::try_from(value) {
+        out.extend_from_slice(&[0xd0, v as u8]);
+    } else if let Ok(v) = i16::try_from(value) {
+        out.push(0xd1);
+        out.extend_from_slice(&v.to_be_bytes());
+    } else if let Ok(v) = i32::try_from(value) {
+        out.push(0xd2);
+        out.extend_from_slice(&v.to_be_bytes());
+    } else {
+        out.push(0xd3);
+        out.extend_from_slice(&(value as i64).to_be_bytes());
+    }
+}
+
+fn write_f32(out: &mut Vec<u8>, value: f32) {
+    out.push(0xca);
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn write_f64(out: &mut Vec<u8>, value: f64) {
+    if !value.is_finite() && !nan::has_payload(value) {
+        return write_f32(out, value as f32);
+    }
+    out.push(0xcb);
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+// 负载长度为 1、2、4、8、16 时用 fixext，否则用 ext 8/16/32
+fn write_ext(out: &mut Vec<u8>, payload: &[u8]) {
+    match payload.len() {
+        1 => out.push(0xd4),
+        2 => out.push(0xd5),
+        4 => out.push(0xd6),
+        8 => out.push(0xd7),
+        16 => out.push(0xd8),
+        len => {
+            if let Ok(len) = u8::try_from(len) {
+                out.extend_from_slice(&[0xc7, len]);
+            } else if let Ok(len) = u16::try_from(len) {
+                out.push(0xc8);
+                out.extend_from_slice(&len.to_be_bytes());
+            } else {
+                out.push(0xc9);
+                out.extend_from_slice(&(len as u32).to_be_bytes());
+            }
+        }
+    }
+    out.push(Number::MSGPACK_EXT_TYPE as u8);
+    out.extend_from_slice(payload);
+}
+
+fn read_number(reader: &mut Reader<'_>) -> Result<Number, DecodeError> {
+    let marker = reader.byte()?;
+    Ok(match marker {
+        0x00..=0x7f => Number::from_i64(marker as i64),
+        0xe0..=0xff => Number::from_i64(marker as i8 as i64),
+        0xcc => Number::from_i64(reader.byte()? as i64),
+        0xcd => Number::from_i64(u16::from_be_bytes(reader.array()?) as i64),
+        0xce => Number::from_i64(u32::from_be_bytes(reader.array()?) as i64),
+        0xcf => Number::from_wide_int(u64::from_be_bytes(reader.array()?) as i128),
+        0xd0 => Number::from_i64(reader.byte()? as i8 as i64),
+        0xd1 => Number::from_i64(i16::from_be_bytes(reader.array()?) as i64),
+        0xd2 => Number::from_i64(i32::from_be_bytes(reader.array()?) as i64),
+        0xd3 => Number::from_i64(i64::from_be_bytes(reader.array()?)),
+        0xca => {
+            let v = f32::from_be_bytes(reader.array()?);
+            if v.is_finite() {
+                Number::Float32(v)
+            } else {
+                Number::from_f64(v as f64)
+            }
+        }
+        0xcb => Number::from_f64(f64::from_be_bytes(reader.array()?)),
+        0xd4 => read_ext(reader, 1)?,
+        0xd5 => read_ext(reader, 2)?,
+        0xd6 => read_ext(reader, 4)?,
+        0xd7 => read_ext(reader, 8)?,
+        0xd8 => read_ext(reader, 16)?,
+        0xc7 => {
+            let len = reader.byte()? as usize;
+            read_ext(reader, len)?
+        }
+        0xc8 => {
+            let len = u16::from_be_bytes(reader.array()?) as usize;
+            read_ext(reader, len)?
+        }
+        0xc9 => {
+            let len = u32::from_be_bytes(reader.array()?) as usize;
+            read_ext(reader, len)?
+        }
+        _ => return Err(DecodeError::UnsupportedType(marker)),
+    })
+}
+
+fn read_ext(reader: &mut Reader<'_>, len: usize) -> Result<Number, DecodeError> {
+    let ext_type = reader.byte()?;
+    if ext_type != Number::MSGPACK_EXT_TYPE as u8 {
+        return Err(DecodeError::UnknownTag(ext_type));
+    }
+    let mut payload = Reader::new(reader.take(len)?);
+    let number = payload.number()?;
+    payload.finish()?;
+    Ok(number)
+}
@@ -0,0 +1,107 @@
+use crate::Number;
+use crate::config;
+
+// 携带来源的 NaN 是 payload 为 MARKER | code 的静默 NaN
+const QUIET_BIT: u64 = 1 << 51;
+const PAYLOAD_MASK: u64 = QUIET_BIT - 1;
+const MARKER: u64 = 0x4E61 << 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NanOrigin {
+    // 没有记录来源，例如 Number::NaN 或外部传入的普通 NaN
+    Unspecified,
+    InfMinusInf,
+    ZeroTimesInf,
+    DivZeroZero,
+    InfDivInf,
+    RemByZero,
+    RemOfInfinity,
+    // 外部传入、带有非零 payload 的 NaN
+    Payload,
+}
+
+impl NanOrigin {
+    fn code(self) -> u64 {
+        match self {
+            NanOrigin::Unspecified => 0,
+            NanOrigin::InfMinusInf => 1,
+            NanOrigin::ZeroTimesInf => 2,
+            NanOrigin::DivZeroZero => 3,
+            NanOrigin::InfDivInf => 4,
+            NanOrigin::RemByZero => 5,
+            NanOrigin::RemOfInfinity => 6,
+            NanOrigin::Payload => 7,
+        }
+    }
+    fn from_payload(payload: u64) -> NanOrigin {
+        if payload == 0 {
+            return NanOrigin::Unspecified;
+        }
+        if payload & !0xFF != MARKER {
+            return NanOrigin::Payload;
+        }
+        match payload & 0xFF {
+            1 => NanOrigin::InfMinusInf,
+            2 => NanOrigin::ZeroTimesInf,
+            3 => NanOrigin::DivZeroZero,
+            4 => NanOrigin::InfDivInf,
+            5 => NanOrigin::RemByZero,
+            6 => NanOrigin::RemOfInfinity,
+            _ => NanOrigin::Payload,
+        }
+    }
+}
+
+impl Number {
+    pub fn nan_with_origin(origin: NanOrigin) -> Number {
+        if origin == NanOrigin::Unspecified {
+            return Number::NaN;
+        }
+        Number::Float64(f64::from_bits(
+            f64::NAN.to_bits() | QUIET_BIT | MARKER | origin.code(),
+        ))
+    }
+    // NaN 的 payload 位（不含静默位）；不是 NaN 时返回 None
+    pub fn nan_payload(&self) -> Option<u64> {
+        if !self.is_nan() {
+            return None;
+        }
+        Some(match self {
+            Number::Float64(v) => v.to_bits() & PAYLOAD_MASK,
+            Number::Float32(v) => (v.to_bits() & ((1 << 22) - 1)) as u64,
+            #[cfg(feature = "half")]
+            Number::Float16(v) => (v.to_bits() & ((1 << 9) - 1)) as u64,
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => (v.to_bits() & ((1 << 6) - 1)) as u64,
+            _ => 0,
+        })
+    }
+    pub fn nan_origin(&self) -> Option<NanOrigin> {
+        match self {
+            Number::Float64(v) if v.is_nan() => {
+                Some(NanOrigin::from_payload(v.to_bits() & PAYLOAD_MASK))
+            }
+            _ => match self.nan_payload()? {
+                0 => Some(NanOrigin::Unspecified),
+                _ => Some(NanOrigin::Payload),
+            },
+        }
+    }
+    // 无效运算的结果：默认为 Number::NaN，配置要求记录来源时为带来源的 NaN
+    pub(crate) fn invalid_op(origin: NanOrigin) -> Number {
+        if config::with_current(|config| config.nan_origins) {
+            Number::nan_with_origin(origin)
+        } else {
+            Number::NaN
+        }
+    }
+    // 按 IEEE 754 的习惯传播第一个 NaN 操作数，保留其 payload
+    pub(crate) fn propagate_nan(a: &Number, b: &Number) -> Number {
+        if a.is_nan() { a.clone() } else { b.clone() }
+    }
+}
+
+// 普通的默认 NaN 折叠为 Number::NaN，带 payload 的 NaN 保留原始位
+pub(crate) const fn has_payload(value: f64) -> bool {
+    value.to_bits() & PAYLOAD_MASK != 0
+}
@@ -0,0 +1,103 @@
+use crate::{Dual, MathError, Number};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimizeOptions {
+    pub learning_rate: Number,
+    // 步长（梯度下降）或区间宽度（黄金分割）小于该值即视为收敛
+    pub tolerance: Number,
+    pub max_iterations: u32,
+}
+
+impl Default for MinimizeOptions {
+    fn default() -> Self {
+        MinimizeOptions {
+            learning_rate: Number::from(0.1),
+            tolerance: Number::from_f64(1e-9),
+            max_iterations: 10_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimum {
+    pub x: Number,
+    pub value: Number,
+    pub iterations: u32,
+    pub converged: bool,
+}
+
+// 梯度下降：f 以对偶数求值，梯度取自结果的 deriv，不需要手写导数
+pub fn minimize(
+    f: impl Fn(Dual) -> Dual,
+    x0: &Number,
+    options: &MinimizeOptions,
+) -> Result<Minimum, MathError> {
+    let rate = options.learning_rate.to_f64();
+    let tolerance = options.tolerance.to_f64();
+    let mut x = x0.to_f64();
+    for iteration in 1..=options.max_iterations {
+        let y = f(Dual::variable(Number::from_f64(x)));
+        let gradient = y.deriv.to_f64();
+        if !gradient.is_finite() {
+            return Err(MathError::DomainError);
+        }
+        let step = rate * gradient;
+        x -= step;
+        if step.abs() < tolerance {
+            return Ok(evaluate(&f, x, iteration, true));
+        }
+    }
+    Ok(evaluate(&f, x, options.max_iterations, false))
+}
+
+// 黄金分割搜索：只需函数值，要求 f 在 [lower, upper] 上单峰
+pub fn golden_section(
+    f: impl Fn(&Number) -> Number,
+    lower: &Number,
+    upper: &Number,
+    options: &MinimizeOptions,
+) -> Result<Minimum, MathError> {
+    let (mut a, mut b) = (lower.to_f64(), upper.to_f64());
+    if a >= b || !a.is_finite() || !b.is_finite() {
+        return Err(MathError::DomainError);
+    }
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let tolerance = options.tolerance.to_f64();
+    let eval = |x: f64| f(&Number::from_f64(x)).to_f64();
+    let (mut c, mut d) = (b - ratio * (b - a), a + ratio * (b - a));
+    let (mut fc, mut fd) = (eval(c), eval(d));
+    for iteration in 1..=options.max_iterations {
+        if fc.is_nan() || fd.is_nan() {
+            return Err(MathError::DomainError);
+        }
+        if fc < fd {
+            (b, d, fd) = (d, c, fc);
+            c = b - ratio * (b - a);
+            fc = eval(c);
+        } else {
+            (a, c, fc) = (c, d, fd);
+            d = a + ratio * (b - a);
+            fd = eval(d);
+        }
+        if b - a < tolerance {
+            let x = (a + b) / 2.0;
+            return Ok(found(x, eval(x), iteration, true));
+        }
+    }
+    let x = (a + b) / 2.0;
+    Ok(found(x, eval(x), options.max_iterations, false))
+}
+
+fn evaluate(f: &impl Fn(Dual) -> Dual, x: f64, iterations: u32, converged: bool) -> Minimum {
+    let value = f(Dual::constant(Number::from_f64(x))).value.to_f64();
+    found(x, value, iterations, converged)
+}
+
+fn found(x: f64, value: f64, iterations: u32, converged: bool) -> Minimum {
+    Minimum {
+        x: Number::from_f64(x),
+        value: Number::from_f64(value),
+        iterations,
+        converged,
+    }
+}
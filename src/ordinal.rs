@@ -0,0 +1,40 @@
+use crate::Number;
+
+impl Number {
+    // 序数写法，locale 为 "en"、"zh-CN" 这样的区域标签（只看语言部分，不区分大小写）：
+    // en 为 1st、2nd、3rd、11th，zh 和 ja 为 第1，de 为 1.，fr 为 1er、2e，
+    // es、pt 为 1.º，it 为 1º，nl 为 1e。
+    // 值不是整数或语言不支持时返回 None；3.0 这样的整数值浮点数按整数处理
+    pub fn to_ordinal_string(&self, locale: &str) -> Option<String> {
+        let digits = if self.is_integer_variant() {
+            self.plain_string()
+        } else {
+            self.as_i64()?.to_string()
+        };
+        let tag = locale.to_ascii_lowercase().replace('_', "-");
+        let language = tag.split('-').next().unwrap_or_default();
+        Some(match language {
+            "en" => format!("{}{}", digits, english_suffix(&digits)),
+            "zh" | "ja" => format!("第{}", digits),
+            "de" => format!("{}.", digits),
+            "fr" if digits == "1" => "1er".to_string(),
+            "fr" => format!("{}e", digits),
+            "es" | "pt" => format!("{}.º", digits),
+            "it" => format!("{}º", digits),
+            "nl" => format!("{}e", digits),
+            _ => return None,
+        })
+    }
+}
+
+// 以 11、12、13 结尾时为 th，否则看个位
+fn english_suffix(digits: &str) -> &'static str {
+    let tens = digits.len() >= 2 && digits.as_bytes()[digits.len() - 2] == b'1';
+    match digits.as_bytes().last() {
+        _ if tens => "th",
+        Some(b'1') => "st",
+        Some(b'2') => "nd",
+        Some(b'3') => "rd",
+        _ => "th",
+    }
+}
@@ -0,0 +1,333 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, RemAssign, Sub, SubAssign};
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+
+use crate::Number;
+use crate::width::CoerceError;
+
+// 指数全 1、静默位和第 50 位都为 1 的 NaN 用来装箱其他变体，普通的 NaN（包括 f64::NAN
+// 和 0.0 / 0.0 得到的负 NaN）第 50 位为 0，按原样存放
+const BOXED: u64 = 0x7ffc_0000_0000_0000;
+const TAG_SHIFT: u32 = 46;
+const PAYLOAD_BITS: u32 = 46;
+// Decimal 和 Fixed64 的尾数位数，其上 6 位存放标度或小数位数
+const MANTISSA_BITS: u32 = 40;
+// Rational 的分母位数，其上 24 位存放有符号的分子
+const DENOMINATOR_BITS: u32 = 22;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Tag {
+    PositiveInfinity,
+    NegativeInfinity,
+    NaN,
+    Integer8,
+    Integer16,
+    Integer32,
+    #[cfg(not(feature = "small"))]
+    Integer64,
+    UnsignedInteger64,
+    Integer128,
+    Float32,
+    Rational,
+    Decimal,
+    Fixed64,
+    #[cfg(feature = "half")]
+    Float16,
+    #[cfg(feature = "half")]
+    BFloat16,
+}
+
+const TAGS: &[Tag] = &[
+    Tag::PositiveInfinity,
+    Tag::NegativeInfinity,
+    Tag::NaN,
+    Tag::Integer8,
+    Tag::Integer16,
+    Tag::Integer32,
+    #[cfg(not(feature = "small"))]
+    Tag::Integer64,
+    Tag::UnsignedInteger64,
+    Tag::Integer128,
+    Tag::Float32,
+    Tag::Rational,
+    Tag::Decimal,
+    Tag::Fixed64,
+    #[cfg(feature = "half")]
+    Tag::Float16,
+    #[cfg(feature = "half")]
+    Tag::BFloat16,
+];
+
+// NaN 装箱的 8 字节数值，用于存放大量数值的 Vec，内存是 Number 的四分之一（启用 bigint 时更少）。
+// Float64 直接存放位模式；其余变体装进 NaN 的 46 位负载：整数在 ±2^45 以内，
+// Rational 的分子在 ±2^23、分母在 2^22 以内，Decimal 和 Fixed64 的尾数在 ±2^39 以内。
+// 转换是无损的，读回的 Number 与原值的变体和负载都相同；运算借道 Number，结果放不下时降为 f64
+#[derive(Clone, Copy)]
+pub struct PackedNumber(u64);
+
+const _: () = assert!(std::mem::size_of::<PackedNumber>() == 8);
+
+impl PackedNumber {
+    pub fn from_i32(value: i32) -> Self {
+        PackedNumber::boxed(Tag::Integer32, value as u32 as u64)
+    }
+    // 与装箱区重叠的少数 NaN 位模式换成 f64::NAN
+    pub fn from_f64(value: f64) -> Self {
+        if value.to_bits() & BOXED == BOXED {
+            PackedNumber(f64::NAN.to_bits())
+        } else {
+            PackedNumber(value.to_bits())
+        }
+    }
+    pub fn to_number(&self) -> Number {
+        let Some(tag) = self.tag() else {
+            return Number::Float64(f64::from_bits(self.0));
+        };
+        let payload = self.0 & mask(PAYLOAD_BITS);
+        let high = (payload >> MANTISSA_BITS) as u8;
+        match tag {
+            Tag::PositiveInfinity => Number::PositiveInfinity,
+            Tag::NegativeInfinity => Number::NegativeInfinity,
+            Tag::NaN => Number::NaN,
+            Tag::Integer8 => Number::Integer8(payload as i8),
+            Tag::Integer16 => Number::Integer16(payload as i16),
+            Tag::Integer32 => Number::Integer32(payload as i32),
+            #[cfg(not(feature = "small"))]
+            Tag::Integer64 => Number::Integer64(sign_extend(payload, PAYLOAD_BITS)),
+            Tag::UnsignedInteger64 => Number::UnsignedInteger64(payload),
+            Tag::Integer128 => Number::Integer128(sign_extend(payload, PAYLOAD_BITS) as i128),
+            Tag::Float32 => Number::Float32(f32::from_bits(payload as u32)),
+            Tag::Rational => Number::Rational(
+                sign_extend(payload >> DENOMINATOR_BITS, PAYLOAD_BITS - DENOMINATOR_BITS),
+                (payload & mask(DENOMINATOR_BITS)) as i64,
+            ),
+            Tag::Decimal => {
+                Number::Decimal(sign_extend(payload, MANTISSA_BITS) as i128, high as u32)
+            }
+            Tag::Fixed64 => Number::Fixed64 {
+                raw: sign_extend(payload, MANTISSA_BITS),
+                frac_bits: high,
+            },
+            #[cfg(feature = "half")]
+            Tag::Float16 => Number::Float16(f16::from_bits(payload as u16)),
+            #[cfg(feature = "half")]
+            Tag::BFloat16 => Number::BFloat16(bf16::from_bits(payload as u16)),
+        }
+    }
+    pub fn to_bits(&self) -> u64 {
+        self.0
+    }
+    fn boxed(tag: Tag, payload: u64) -> Self {
+        PackedNumber(BOXED | (tag as u64) << TAG_SHIFT | payload & mask(PAYLOAD_BITS))
+    }
+    fn tag(&self) -> Option<Tag> {
+        if self.0 & BOXED != BOXED {
+            return None;
+        }
+        TAGS.get(((self.0 >> TAG_SHIFT) & 0xf) as usize).copied()
+    }
+    // 运算结果的收窄：能无损放下就保留，否则取最近的 f64
+    fn narrow(number: Number) -> Self {
+        PackedNumber::try_from(number.clone())
+            .unwrap_or_else(|_| PackedNumber::from_f64(number.to_f64()))
+    }
+}
+
+fn mask(bits: u32) -> u64 {
+    (1 << bits) - 1
+}
+
+fn sign_extend(payload: u64, bits: u32) -> i64 {
+    ((payload << (64 - bits)) as i64) >> (64 - bits)
+}
+
+// value 能用 bits 位补码表示时返回其低 bits 位
+fn fit_signed(value: i128, bits: u32) -> Result<u64, CoerceError> {
+    let limit = 1i128 << (bits - 1);
+    if (-limit..limit).contains(&value) {
+        Ok(value as u64 & mask(bits))
+    } else {
+        Err(CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for PackedNumber {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        let boxed = PackedNumber::boxed;
+        Ok(match value {
+            Number::PositiveInfinity => boxed(Tag::PositiveInfinity, 0),
+            Number::NegativeInfinity => boxed(Tag::NegativeInfinity, 0),
+            Number::NaN => boxed(Tag::NaN, 0),
+            Number::Integer8(v) => boxed(Tag::Integer8, v as u8 as u64),
+            Number::Integer16(v) => boxed(Tag::Integer16, v as u16 as u64),
+            Number::Integer32(v) => boxed(Tag::Integer32, v as u32 as u64),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => boxed(Tag::Integer64, fit_signed(v as i128, PAYLOAD_BITS)?),
+            Number::UnsignedInteger64(v) if v <= mask(PAYLOAD_BITS) => {
+                boxed(Tag::UnsignedInteger64, v)
+            }
+            Number::UnsignedInteger64(_) => return Err(CoerceError::OutOfRange),
+            Number::Integer128(v) => boxed(Tag::Integer128, fit_signed(v, PAYLOAD_BITS)?),
+            Number::Float64(v) if v.to_bits() & BOXED == BOXED => {
+                return Err(CoerceError::Unsupported);
+            }
+            Number::Float64(v) => PackedNumber(v.to_bits()),
+            Number::Float32(v) => boxed(Tag::Float32, v.to_bits() as u64),
+            Number::Rational(n, d) if d > 0 && (d as u64) <= mask(DENOMINATOR_BITS) => {
+                let n = fit_signed(n as i128, PAYLOAD_BITS - DENOMINATOR_BITS)?;
+                boxed(Tag::Rational, n << DENOMINATOR_BITS | d as u64)
+            }
+            Number::Rational(..) => return Err(CoerceError::OutOfRange),
+            Number::Decimal(m, scale) if scale < 1 << (PAYLOAD_BITS - MANTISSA_BITS) => {
+                let m = fit_signed(m, MANTISSA_BITS)?;
+                boxed(Tag::Decimal, (scale as u64) << MANTISSA_BITS | m)
+            }
+            Number::Decimal(..) => return Err(CoerceError::OutOfRange),
+            Number::Fixed64 { raw, frac_bits }
+                if frac_bits < 1 << (PAYLOAD_BITS - MANTISSA_BITS) =>
+            {
+                let raw = fit_signed(raw as i128, MANTISSA_BITS)?;
+                boxed(Tag::Fixed64, (frac_bits as u64) << MANTISSA_BITS | raw)
+            }
+            Number::Fixed64 { .. } => return Err(CoerceError::OutOfRange),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => boxed(Tag::Float16, v.to_bits() as u64),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => boxed(Tag::BFloat16, v.to_bits() as u64),
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(_) => return Err(CoerceError::Unsupported),
+            #[cfg(feature = "extended")]
+            Number::Extended(_) => return Err(CoerceError::Unsupported),
+        })
+    }
+}
+
+impl From<PackedNumber> for Number {
+    fn from(value: PackedNumber) -> Self {
+        value.to_number()
+    }
+}
+
+impl From<i8> for PackedNumber {
+    fn from(value: i8) -> Self {
+        PackedNumber::boxed(Tag::Integer8, value as u8 as u64)
+    }
+}
+
+impl From<i16> for PackedNumber {
+    fn from(value: i16) -> Self {
+        PackedNumber::boxed(Tag::Integer16, value as u16 as u64)
+    }
+}
+
+impl From<i32> for PackedNumber {
+    fn from(value: i32) -> Self {
+        PackedNumber::from_i32(value)
+    }
+}
+
+impl From<f32> for PackedNumber {
+    fn from(value: f32) -> Self {
+        PackedNumber::boxed(Tag::Float32, value.to_bits() as u64)
+    }
+}
+
+impl From<f64> for PackedNumber {
+    fn from(value: f64) -> Self {
+        PackedNumber::from_f64(value)
+    }
+}
+
+impl fmt::Debug for PackedNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_number(), f)
+    }
+}
+
+impl fmt::Display for PackedNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_number(), f)
+    }
+}
+
+impl PartialEq for PackedNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_number() == other.to_number()
+    }
+}
+
+impl PartialOrd for PackedNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_number().partial_cmp(&other.to_number())
+    }
+}
+
+impl Add for PackedNumber {
+    type Output = PackedNumber;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        PackedNumber::narrow(self.to_number() + rhs.to_number())
+    }
+}
+
+impl Sub for PackedNumber {
+    type Output = PackedNumber;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        PackedNumber::narrow(self.to_number() - rhs.to_number())
+    }
+}
+
+impl Mul for PackedNumber {
+    type Output = PackedNumber;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        PackedNumber::narrow(self.to_number() * rhs.to_number())
+    }
+}
+
+impl Div for PackedNumber {
+    type Output = PackedNumber;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        PackedNumber::narrow(self.to_number() / rhs.to_number())
+    }
+}
+
+impl AddAssign for PackedNumber {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for PackedNumber {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for PackedNumber {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for PackedNumber {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl RemAssign for PackedNumber {
+    fn rem_assign(&mut self, rhs: Self) {
+        let mut number = self.to_number();
+        number %= rhs.to_number();
+        *self = PackedNumber::narrow(number);
+    }
+}
@@ -0,0 +1,574 @@
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+
+use crate::Number;
+use crate::config::{self, NumberConfig, ParseConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidDigit,
+    Overflow,
+    Empty,
+    TrailingGarbage,
+    // parse_lossless：文本表示的值无法精确保存
+    Inexact,
+}
+
+// position 是出错处在输入字符串中的字节偏移
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseNumberError {
+    pub kind: ParseErrorKind,
+    pub position: usize,
+}
+
+impl ParseNumberError {
+    pub(crate) fn new(kind: ParseErrorKind, position: usize) -> Self {
+        ParseNumberError { kind, position }
+    }
+    // 子串的错误换算为整个输入中的位置
+    pub(crate) fn offset(self, by: usize) -> Self {
+        ParseNumberError::new(self.kind, self.position + by)
+    }
+}
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::InvalidDigit => {
+                write!(f, "invalid digit at position {}", self.position)
+            }
+            ParseErrorKind::Overflow => {
+                write!(f, "number out of range at position {}", self.position)
+            }
+            ParseErrorKind::Empty => write!(f, "cannot parse a number from an empty string"),
+            ParseErrorKind::TrailingGarbage => {
+                write!(f, "unexpected characters at position {}", self.position)
+            }
+            ParseErrorKind::Inexact => write!(f, "number cannot be represented exactly"),
+        }
+    }
+}
+
+impl Error for ParseNumberError {}
+
+impl std::str::FromStr for Number {
+    type Err = ParseNumberError;
+
+    fn from_str(s: &str) -> Result<Self, ParseNumberError> {
+        Number::parse(s)
+    }
+}
+
+impl Number {
+    pub fn parse(s: &str) -> Result<Self, ParseNumberError> {
+        config::with_current(|config| Self::parse_with_config(s, config))
+    }
+    // 旧的字符串错误接口，错误信息与改为 ParseNumberError 之前相同
+    #[deprecated(
+        since = "0.1.1",
+        note = "use `Number::parse`, which returns a structured `ParseNumberError`"
+    )]
+    pub fn parse_legacy(s: &str) -> Result<Self, String> {
+        Self::parse(s).map_err(|_| format!("Cannot parse '{}' as a number", s))
+    }
+    pub fn parse_with_config(s: &str, config: &NumberConfig) -> Result<Self, ParseNumberError> {
+        match s.trim().to_lowercase().as_str() {
+            "inf" | "infinity" | "+inf" | "+infinity" => return Ok(Number::PositiveInfinity),
+            "-inf" | "-infinity" => return Ok(Number::NegativeInfinity),
+            "nan" => return Ok(Number::NaN),
+            _ => {}
+        }
+        if config.parse.percent
+            && let Some(mantissa) = s.strip_suffix('%')
+        {
+            return parse_scaled(mantissa.trim_end(), 2, true, config);
+        }
+        if config.parse.si_suffixes
+            && let Some((mantissa, exponent, negative)) = split_si_suffix(s)
+        {
+            return parse_scaled(mantissa, exponent, negative, config);
+        }
+        if let Some(result) = parse_prefixed(s) {
+            return result;
+        }
+        match Normalized::new(s, &config.parse) {
+            Some(Ok(normalized)) => Self::parse_canonical(&normalized.text, config)
+                .map_err(|err| normalized.original(err)),
+            Some(Err(err)) => Err(err),
+            None => Self::parse_canonical(s, config),
+        }
+    }
+    // 只替换当前配置中的解析选项，例如 Number::parse_with("1.234,56", &ParseConfig::locale("de")?)
+    pub fn parse_with(s: &str, options: &ParseConfig) -> Result<Self, ParseNumberError> {
+        let config = NumberConfig {
+            parse: options.clone(),
+            ..NumberConfig::current()
+        };
+        Self::parse_with_config(s, &config)
+    }
+    // 按 delimiter 切分整列并逐条解析，忽略字段两端的空白；末尾多出的一个空字段（如结尾换行）不计。
+    // 出错时返回该字段的序号（从 0 开始），error.position 相对于去掉空白后的字段
+    pub fn parse_many(text: &str, delimiter: char) -> Result<Vec<Self>, (usize, ParseNumberError)> {
+        let mut fields: Vec<&str> = text.split(delimiter).collect();
+        if fields.last().is_some_and(|f| f.trim().is_empty()) {
+            fields.pop();
+        }
+        config::with_current(|config| {
+            fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    Self::parse_with_config(field.trim(), config).map_err(|err| (index, err))
+                })
+                .collect()
+        })
+    }
+    // s 已去掉分组符，小数点为 '.'
+    fn parse_canonical(s: &str, config: &NumberConfig) -> Result<Self, ParseNumberError> {
+        // 整数没有 -0，读作浮点数的负零，使 Display 输出的 "-0" 能读回原值
+        if let Some(zeros) = s.strip_prefix('-')
+            && !zeros.is_empty()
+            && zeros.bytes().all(|b| b == b'0')
+        {
+            return Ok(Self::from_float(-0.0));
+        }
+        if let Ok(value) = s.parse::<i64>() {
+            return Ok(Self::from_i64(value));
+        }
+        if let Ok(value) = s.parse::<u64>() {
+            return Ok(Self::from_wide_int(value as i128));
+        }
+        if let Ok(value) = s.parse::<i128>() {
+            return Ok(Self::from_wide_int(value));
+        }
+        #[cfg(feature = "bigint")]
+        if let Ok(value) = s.parse::<BigInt>() {
+            return Ok(Self::from(value));
+        }
+        if s.contains('@') {
+            return Self::parse_fixed(s);
+        }
+        if let Some((numerator, denominator)) = s.split_once('/')
+            && let (Ok(n), Ok(d)) = (numerator.trim().parse::<i64>(), denominator.trim().parse())
+        {
+            return Ok(Self::rational(n, d));
+        }
+        if let Some(value) = exact_scientific(s) {
+            return Ok(value);
+        }
+        if let Ok(value) = s.parse::<f64>() {
+            if config.parse.exact_decimals
+                && let Ok(decimal) = Self::parse_decimal(s)
+            {
+                return Ok(decimal);
+            }
+            return Ok(Self::from_float(value));
+        }
+        #[cfg(feature = "half")]
+        if let Some(value) = Number::parse_half_suffix(s) {
+            return Ok(value);
+        }
+        Err(syntax_error(s))
+    }
+    // radix 进制（2 到 36）的整数：数字为 0-9 和 a-z（不区分大小写），可带符号，
+    // 数字之间可以用 '_' 分隔。与 i64::from_str_radix 一样，radix 超出范围时 panic
+    pub fn parse_radix(s: &str, radix: u32) -> Result<Self, ParseNumberError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be between 2 and 36, got {}",
+            radix
+        );
+        if s.is_empty() {
+            return Err(ParseNumberError::new(ParseErrorKind::Empty, 0));
+        }
+        let sign = sign_len(s);
+        parse_digits(&s[sign..], radix, s.starts_with('-')).map_err(|err| err.offset(sign))
+    }
+    // 解析结果必须与文本表示的精确值相等，例如没有启用精确十进制时 "0.1" 会报错
+    pub fn parse_lossless(s: &str) -> Result<Self, ParseNumberError> {
+        let value = Self::parse(s)?;
+        if value.is_integer_variant() || matches!(value, Number::Rational(..) | Number::Decimal(..))
+        {
+            return Ok(value);
+        }
+        let text = s.split_once('@').map_or(s, |(text, _)| text);
+        let inexact = ParseNumberError::new(ParseErrorKind::Inexact, 0);
+        let exact = Self::parse_decimal(text).map_err(|_| inexact)?;
+        if !exact.is_finite() {
+            return Ok(value);
+        }
+        match value.exact_ratio() {
+            Some(ratio) if exact.exact_ratio() == Some(ratio) => Ok(value),
+            _ => Err(inexact),
+        }
+    }
+}
+
+// 去掉 '_' 和分组符、把小数点换成 '.' 之后的文本，positions 记录每个字节在原文中的位置
+struct Normalized {
+    text: String,
+    positions: Vec<usize>,
+}
+
+impl Normalized {
+    // 没有需要处理的字符时返回 None；分组不合法时报错
+    fn new(s: &str, config: &ParseConfig) -> Option<Result<Normalized, ParseNumberError>> {
+        if config.decimal_separator == '.'
+            && !s.contains('_')
+            && !s.chars().any(|c| is_group_separator(c, config))
+        {
+            return None;
+        }
+        let mut normalized = Normalized {
+            text: String::with_capacity(s.len()),
+            positions: Vec::with_capacity(s.len() + 1),
+        };
+        let result = match s.split_once('/') {
+            Some((numerator, denominator)) => {
+                normalized.number(numerator, 0, config).and_then(|()| {
+                    normalized.push('/', numerator.len());
+                    normalized.number(denominator, numerator.len() + 1, config)
+                })
+            }
+            None => normalized.number(s, 0, config),
+        };
+        normalized.positions.push(s.len());
+        Some(result.map(|()| normalized))
+    }
+    fn push(&mut self, c: char, position: usize) {
+        self.text.push(c);
+        self.positions.push(position);
+    }
+    // 规范化后文本中的错误位置换算回原文
+    fn original(&self, err: ParseNumberError) -> ParseNumberError {
+        let position = self.positions[err.position.min(self.positions.len() - 1)];
+        ParseNumberError::new(err.kind, position)
+    }
+    // 符号、整数部分、小数部分和指数；之后只允许跟 "f16" 这样的后缀或 "@Q16.16" 格式
+    fn number(
+        &mut self,
+        s: &str,
+        offset: usize,
+        config: &ParseConfig,
+    ) -> Result<(), ParseNumberError> {
+        let invalid = |kind, i: usize| Err(ParseNumberError::new(kind, offset + i));
+        let mut chars = s.char_indices().peekable();
+        if let Some(&(i, c @ ('+' | '-'))) = chars.peek() {
+            self.push(c, offset + i);
+            chars.next();
+        }
+        let mut digits = 0;
+        // 当前分组的位数和上一个分组符的位置
+        let mut group_digits = 0;
+        let mut last_group: Option<usize> = None;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_ascii_digit() {
+                self.push(c, offset + i);
+                digits += 1;
+                group_digits += 1;
+            } else if is_group_separator(c, config) {
+                let valid = match last_group {
+                    None => (1..=3).contains(&group_digits),
+                    Some(_) => group_digits == 3,
+                };
+                if !valid {
+                    return invalid(ParseErrorKind::InvalidDigit, i);
+                }
+                last_group = Some(i);
+                group_digits = 0;
+            } else if c != '_' || digits == 0 {
+                break;
+            }
+            chars.next();
+        }
+        if let Some(i) = last_group
+            && group_digits != 3
+        {
+            return invalid(ParseErrorKind::InvalidDigit, i);
+        }
+        if let Some(&(i, c)) = chars.peek()
+            && c == config.decimal_separator
+        {
+            self.push('.', offset + i);
+            chars.next();
+            while let Some(&(i, c)) = chars.peek() {
+                if c.is_ascii_digit() {
+                    self.push(c, offset + i);
+                    digits += 1;
+                } else if c != '_' || digits == 0 {
+                    break;
+                }
+                chars.next();
+            }
+        }
+        let Some(&(i, c)) = chars.peek() else {
+            return Ok(());
+        };
+        if digits == 0 {
+            return invalid(ParseErrorKind::InvalidDigit, i);
+        }
+        if matches!(c, 'e' | 'E') {
+            let mut exponent_digits = 0;
+            let mut text = String::from("e");
+            let mut positions = vec![offset + i];
+            let mut end = s.len();
+            for (j, c) in s[i + 1..].char_indices() {
+                let j = i + 1 + j;
+                if c.is_ascii_digit() || (j == i + 1 && matches!(c, '+' | '-')) {
+                    exponent_digits += usize::from(c.is_ascii_digit());
+                    text.push(c);
+                    positions.push(offset + j);
+                } else if c != '_' || exponent_digits == 0 {
+                    end = j;
+                    break;
+                }
+            }
+            // "1e" 这样没有指数数字的写法原样留给后续解析报错
+            if exponent_digits > 0 {
+                self.text.push_str(&text);
+                self.positions.extend(positions);
+                return self.suffix(&s[end..], offset + end);
+            }
+        }
+        if c.is_alphabetic() || c == '@' {
+            return self.suffix(&s[i..], offset + i);
+        }
+        invalid(ParseErrorKind::TrailingGarbage, i)
+    }
+    fn suffix(&mut self, rest: &str, offset: usize) -> Result<(), ParseNumberError> {
+        for (i, c) in rest.char_indices() {
+            for _ in 0..c.len_utf8() {
+                self.positions.push(offset + i);
+            }
+            self.text.push(c);
+        }
+        Ok(())
+    }
+}
+
+// 空格和撇号各有几种写法，粘贴来的数据里常常混用
+fn is_group_separator(c: char, config: &ParseConfig) -> bool {
+    const SPACES: &[char] = &[' ', '\u{a0}', '\u{202f}'];
+    const APOSTROPHES: &[char] = &['\'', '\u{2019}'];
+    let Some(group) = config.group_separator else {
+        return false;
+    };
+    if c == config.decimal_separator {
+        return false;
+    }
+    c == group
+        || [SPACES, APOSTROPHES]
+            .iter()
+            .any(|set| set.contains(&c) && set.contains(&group))
+}
+
+// "1e10"、"25E+3"、"1.0e6" 这样整数尾数（小数部分全为 0）乘以非负幂次的写法是精确整数，
+// 结果在 i128 范围内时不经过 f64
+fn exact_scientific(s: &str) -> Option<Number> {
+    let (mantissa, exponent) = s.split_once(['e', 'E'])?;
+    let exponent = exponent.strip_prefix('+').unwrap_or(exponent);
+    if exponent.is_empty() || !exponent.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if !frac_part.bytes().all(|b| b == b'0') {
+        return None;
+    }
+    let int_value = int_part.parse::<i128>().ok()?;
+    if int_value == 0 {
+        return Some(Number::from_i64(0));
+    }
+    let value = 10i128
+        .checked_pow(exponent.parse().ok()?)
+        .and_then(|power| int_value.checked_mul(power))?;
+    Some(Number::from_wide_int(value))
+}
+
+// 数量级后缀对应的 10 的幂次，negative 表示负幂次；后缀前可以有空格
+fn split_si_suffix(s: &str) -> Option<(&str, u32, bool)> {
+    let suffix = s.chars().last()?;
+    let (exponent, negative) = match suffix {
+        'k' => (3, false),
+        'M' => (6, false),
+        'G' => (9, false),
+        'T' => (12, false),
+        'm' => (3, true),
+        'µ' | 'μ' | 'u' => (6, true),
+        'n' => (9, true),
+        _ => return None,
+    };
+    Some((
+        s[..s.len() - suffix.len_utf8()].trim_end(),
+        exponent,
+        negative,
+    ))
+}
+
+// 百分数和数量级后缀：先按精确十进制解析再乘以 10 的幂，
+// 结果是整数时取整数变体，否则按 exact_decimals 决定是否保留 Decimal
+fn parse_scaled(
+    mantissa: &str,
+    exponent: u32,
+    negative: bool,
+    config: &NumberConfig,
+) -> Result<Number, ParseNumberError> {
+    let exact = NumberConfig {
+        parse: ParseConfig {
+            exact_decimals: true,
+            si_suffixes: false,
+            percent: false,
+            ..config.parse.clone()
+        },
+        ..config.clone()
+    };
+    let value = Number::parse_with_config(mantissa, &exact)?;
+    let factor = if negative {
+        Number::Decimal(1, exponent)
+    } else {
+        Number::from_wide_int(10i128.pow(exponent))
+    };
+    Ok(match value * factor {
+        Number::Decimal(m, scale) if m % 10i128.pow(scale) == 0 => {
+            Number::from_wide_int(m / 10i128.pow(scale))
+        }
+        scaled @ Number::Decimal(..) if !config.parse.exact_decimals => {
+            Number::from_float(scaled.to_f64())
+        }
+        scaled => scaled,
+    })
+}
+
+// 带 "0x"、"0o"、"0b" 前缀（大小写均可）的整数，前缀前可以有符号；没有前缀时返回 None
+fn parse_prefixed(s: &str) -> Option<Result<Number, ParseNumberError>> {
+    let sign = sign_len(s);
+    let radix = match s[sign..].get(..2)? {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    let digits = &s[sign + 2..];
+    Some(parse_digits(digits, radix, s.starts_with('-')).map_err(|err| err.offset(sign + 2)))
+}
+
+// digits 只含 radix 进制的数字，第一个数字之后可以夹 '_'；
+// 超出 i128 时转为 BigInteger（未启用 bigint 时报 Overflow）
+fn parse_digits(digits: &str, radix: u32, negative: bool) -> Result<Number, ParseNumberError> {
+    if digits.is_empty() {
+        return Err(ParseNumberError::new(ParseErrorKind::InvalidDigit, 0));
+    }
+    let mut value: i128 = 0;
+    let mut overflow = false;
+    for (i, c) in digits.char_indices() {
+        if c == '_' && i > 0 {
+            continue;
+        }
+        let digit =
+            c.to_digit(radix)
+                .ok_or(ParseNumberError::new(ParseErrorKind::InvalidDigit, i))? as i128;
+        // 负数按负方向累加，i128::MIN 也能表示
+        let digit = if negative { -digit } else { digit };
+        match value
+            .checked_mul(radix as i128)
+            .and_then(|v| v.checked_add(digit))
+        {
+            Some(v) => value = v,
+            // 继续检查其余的数字，无效数字优先于溢出报告
+            None => overflow = true,
+        }
+    }
+    if overflow {
+        return big_digits(digits, radix, negative);
+    }
+    Ok(Number::from_wide_int(value))
+}
+
+#[cfg(feature = "bigint")]
+fn big_digits(digits: &str, radix: u32, negative: bool) -> Result<Number, ParseNumberError> {
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+    let value = BigInt::parse_bytes(digits.as_bytes(), radix)
+        .ok_or(ParseNumberError::new(ParseErrorKind::InvalidDigit, 0))?;
+    Ok(Number::from(if negative { -value } else { value }))
+}
+
+#[cfg(not(feature = "bigint"))]
+fn big_digits(_: &str, _: u32, _: bool) -> Result<Number, ParseNumberError> {
+    Err(ParseNumberError::new(ParseErrorKind::Overflow, 0))
+}
+
+// 解析失败时定位原因：不是数字开头的为 InvalidDigit，合法数字后还有字符的为 TrailingGarbage，
+// 写法合法但超出可表示范围的为 Overflow
+pub(crate) fn syntax_error(s: &str) -> ParseNumberError {
+    if s.trim().is_empty() {
+        return ParseNumberError::new(ParseErrorKind::Empty, 0);
+    }
+    if let Some((numerator, denominator)) = s.split_once('/') {
+        let bad_side =
+            integer_error(numerator, 0).or_else(|| integer_error(denominator, numerator.len() + 1));
+        // 两边都是整数时只可能是超出 i64
+        return bad_side.unwrap_or(ParseNumberError::new(ParseErrorKind::Overflow, 0));
+    }
+    let end = decimal_prefix(s);
+    let kind = if end == sign_len(s) {
+        ParseErrorKind::InvalidDigit
+    } else if end < s.len() {
+        ParseErrorKind::TrailingGarbage
+    } else {
+        return ParseNumberError::new(ParseErrorKind::Overflow, 0);
+    };
+    ParseNumberError::new(kind, end)
+}
+
+fn sign_len(s: &str) -> usize {
+    usize::from(s.starts_with(['+', '-']))
+}
+
+// 分数的一侧：两端可以有空白，中间必须是带可选符号的整数
+fn integer_error(side: &str, offset: usize) -> Option<ParseNumberError> {
+    let leading = side.len() - side.trim_start().len();
+    let text = side.trim();
+    let start = sign_len(text);
+    let digits = text[start..].bytes().take_while(u8::is_ascii_digit).count();
+    let end = start + digits;
+    if digits == 0 || end < text.len() {
+        Some(ParseNumberError::new(
+            ParseErrorKind::InvalidDigit,
+            offset + leading + end,
+        ))
+    } else {
+        None
+    }
+}
+
+// 最长的十进制数字前缀 [+-]digits[.digits][e[+-]digits] 的长度，没有数字时为符号的长度
+fn decimal_prefix(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let digits_from = |i: usize| bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let mut i = sign_len(s);
+    let int_digits = digits_from(i);
+    i += int_digits;
+    let mut frac_digits = 0;
+    if bytes.get(i) == Some(&b'.') {
+        frac_digits = digits_from(i + 1);
+        if int_digits + frac_digits > 0 {
+            i += 1 + frac_digits;
+        }
+    }
+    if int_digits + frac_digits == 0 {
+        return sign_len(s);
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        let mut j = i + 1;
+        if matches!(bytes.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        let exponent_digits = digits_from(j);
+        if exponent_digits > 0 {
+            i = j + exponent_digits;
+        }
+    }
+    i
+}
@@ -0,0 +1,143 @@
+use crate::Number;
+
+// 精确路径允许的结果位数，超过时改用 f64，避免巨大的指数耗尽内存
+const MAX_EXACT_BITS: u64 = 1 << 20;
+
+impl Number {
+    // 底数为整数、Rational、Decimal 或 Fixed64 且指数为整数值时用平方求幂精确计算，
+    // 溢出时与乘法一样按 OverflowPolicy 升级；负指数再取倒数，与除法的结果类型相同。
+    // 其余情况按 f64 的 powf 计算，特殊值与 IEEE 754 的 pow 一致：x^0 为 1（包括 0^0、∞^0 和 NaN^0），
+    // 1^y 为 1，0 的负数次幂为 ∞，负底数的非整数次幂为 NaN（需要复数结果时用 pow_complex）
+    pub fn pow(&self, exponent: impl Into<Number>) -> Number {
+        let exponent = exponent.into();
+        if self.is_exact()
+            && let Some(n) = exponent.as_i64()
+            && self.exact_pow_bits().saturating_mul(n.unsigned_abs()) <= MAX_EXACT_BITS
+        {
+            let mut result = Number::from(1);
+            let mut square = self.clone();
+            let mut k = n.unsigned_abs();
+            while k > 0 {
+                if k & 1 == 1 {
+                    result *= square.clone();
+                }
+                k >>= 1;
+                if k > 0 {
+                    square = square.clone() * square;
+                }
+            }
+            return if n < 0 {
+                Number::from(1) / result
+            } else {
+                result
+            };
+        }
+        let value = self.to_f64().powf(exponent.to_f64());
+        if value.is_nan() && (self.is_nan() || exponent.is_nan()) {
+            return Number::propagate_nan(self, &exponent);
+        }
+        if value.is_finite() {
+            Number::from_float(value)
+        } else {
+            Number::from_f64(value)
+        }
+    }
+    pub fn sqrt(&self) -> Number {
+        self.nth_root(2)
+    }
+    pub fn cbrt(&self) -> Number {
+        self.nth_root(3)
+    }
+    // 整数、Rational 和 Decimal 是完全 n 次幂时返回精确结果，如 144 的平方根为整数 12、
+    // 8/27 的立方根为 2/3；其余按 f64 计算。负数的奇数次方根为负数，偶数次方根和 0 次方根为 NaN
+    pub fn nth_root(&self, n: u32) -> Number {
+        if self.is_nan() {
+            return self.clone();
+        }
+        if n == 0 {
+            return Number::NaN;
+        }
+        if let Some(root) = self.exact_root(n) {
+            return root;
+        }
+        let x = self.to_f64();
+        let value = match n {
+            1 => x,
+            2 => x.sqrt(),
+            3 => x.cbrt(),
+            // 与 sqrt 一样保留 -0.0 的符号
+            _ if x == 0.0 => x,
+            _ if x < 0.0 && n.is_multiple_of(2) => f64::NAN,
+            _ if x < 0.0 => -(-x).powf(1.0 / n as f64),
+            _ => x.powf(1.0 / n as f64),
+        };
+        if value.is_finite() {
+            Number::from_float(value)
+        } else {
+            Number::from_f64(value)
+        }
+    }
+    fn exact_root(&self, n: u32) -> Option<Number> {
+        let signed_root = |value: i128| {
+            if value < 0 && n.is_multiple_of(2) {
+                return None;
+            }
+            let root = exact_root(value.unsigned_abs(), n)? as i128;
+            Some(if value < 0 { -root } else { root })
+        };
+        #[cfg(feature = "bigint")]
+        if let Number::BigInteger(v) = self {
+            if v.sign() == num_bigint::Sign::Minus && n.is_multiple_of(2) {
+                return None;
+            }
+            let root = v.nth_root(n);
+            return (root.pow(n) == *v).then(|| Number::from(root));
+        }
+        match self {
+            Number::Rational(p, q) => {
+                let (p, q) = (signed_root(*p as i128)?, signed_root(*q as i128)?);
+                Some(Number::rational(p as i64, q as i64))
+            }
+            Number::Decimal(m, scale) if scale.is_multiple_of(n) => {
+                Some(Number::Decimal(signed_root(*m)?, scale / n))
+            }
+            _ => Some(Number::from_wide_int(signed_root(self.int_value()?)?)),
+        }
+    }
+    fn is_exact(&self) -> bool {
+        self.is_integer_variant()
+            || matches!(
+                self,
+                Number::Rational(..) | Number::Decimal(..) | Number::Fixed64 { .. }
+            )
+    }
+    // 底数的分子、分母中较大者的位数，结果的位数不超过它乘以指数。0 和 ±1 的幂不会增长，记为 0
+    fn exact_pow_bits(&self) -> u64 {
+        match self.exact_ratio() {
+            Some((n, d)) => match n.unsigned_abs().max(d.unsigned_abs()) {
+                0 | 1 => 0,
+                magnitude => (128 - magnitude.leading_zeros()) as u64,
+            },
+            None => self.to_f64().abs().log2().abs().ceil() as u64 + 1,
+        }
+    }
+}
+
+// x 是完全 n 次幂时返回其 n 次方根。牛顿迭代从不小于真实根的 2 的幂开始，单调下降到向下取整的根
+fn exact_root(x: u128, n: u32) -> Option<u128> {
+    if x < 2 || n == 1 {
+        return Some(x);
+    }
+    let bits = 128 - x.leading_zeros();
+    let mut root: u128 = 1 << bits.div_ceil(n);
+    loop {
+        // r^(n-1) 溢出时 x / r^(n-1) 为 0
+        let quotient = root.checked_pow(n - 1).map_or(0, |power| x / power);
+        let next = ((n - 1) as u128 * root + quotient) / n as u128;
+        if next >= root {
+            break;
+        }
+        root = next;
+    }
+    (root.checked_pow(n) == Some(x)).then_some(root)
+}
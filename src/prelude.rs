@@ -0,0 +1,17 @@
+// 常用类型、trait 和宏：use my_r_num::prelude::*;
+
+pub use crate::num;
+pub use crate::{
+    ArithmeticError, CoerceError, ExactWidth, FormatConfig, MathChain, MathError, Number,
+    NumberArray, NumberConfig, NumberContext, NumberKind, OverflowPolicy, ParseConfig,
+    ParseNumberError, RoundingMode,
+};
+
+#[cfg(feature = "extended")]
+pub use crate::ExtendedNumber;
+#[cfg(feature = "stats")]
+pub use crate::distributions::Distribution;
+#[cfg(feature = "expr")]
+pub use crate::expr::Expr;
+#[cfg(feature = "units")]
+pub use crate::units::{Quantity, Unit};
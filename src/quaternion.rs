@@ -0,0 +1,125 @@
+use std::fmt;
+use std::ops::Mul;
+
+use crate::{Number, NumberArray};
+
+// 分量顺序为 [w, x, y, z]，w 是实部
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(any(feature = "bigint", feature = "extended")), derive(Copy))]
+pub struct Quaternion(pub NumberArray<4>);
+
+impl Quaternion {
+    pub fn new(w: Number, x: Number, y: Number, z: Number) -> Self {
+        Quaternion(NumberArray::new([w, x, y, z]))
+    }
+    pub fn identity() -> Self {
+        Quaternion::new(
+            Number::from(1),
+            Number::from(0),
+            Number::from(0),
+            Number::from(0),
+        )
+    }
+    // 绕 axis 旋转 angle 弧度；axis 不要求是单位向量
+    pub fn from_axis_angle(axis: &NumberArray<3>, angle: &Number) -> Self {
+        let half = angle.to_f64() / 2.0;
+        let length = axis.dot(axis).to_f64().sqrt();
+        let factor = Number::from_f64(half.sin() / length);
+        let [x, y, z] = axis.scale(&factor).into_inner();
+        Quaternion::new(Number::from_f64(half.cos()), x, y, z)
+    }
+    pub fn w(&self) -> &Number {
+        &self.0[0]
+    }
+    pub fn x(&self) -> &Number {
+        &self.0[1]
+    }
+    pub fn y(&self) -> &Number {
+        &self.0[2]
+    }
+    pub fn z(&self) -> &Number {
+        &self.0[3]
+    }
+    pub fn dot(&self, other: &Self) -> Number {
+        self.0.dot(&other.0)
+    }
+    pub fn norm(&self) -> Number {
+        Number::from_f64(self.dot(self).to_f64().sqrt())
+    }
+    pub fn conjugate(&self) -> Self {
+        let [w, x, y, z] = self.0.clone().into_inner();
+        let minus_one = Number::from(-1);
+        Quaternion::new(
+            w,
+            minus_one.clone() * x,
+            minus_one.clone() * y,
+            minus_one * z,
+        )
+    }
+    // 零四元数没有方向，按除零语义得到 NaN 分量
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        Quaternion(self.0.map(|c| c.clone() / norm.clone()))
+    }
+    pub fn rotate(&self, v: &NumberArray<3>) -> NumberArray<3> {
+        let [x, y, z] = v.clone().into_inner();
+        let p = Quaternion::new(Number::from(0), x, y, z);
+        let q = self.normalize();
+        let [_, x, y, z] = (q.clone() * p * q.conjugate()).0.into_inner();
+        NumberArray::new([x, y, z])
+    }
+    // 球面线性插值，t 取 0 到 1；两端先归一化并走较短的弧
+    pub fn slerp(&self, other: &Self, t: &Number) -> Self {
+        let a = self.normalize();
+        let mut b = other.normalize();
+        let mut cos = a.dot(&b).to_f64();
+        if cos < 0.0 {
+            b = Quaternion(b.0.scale(&Number::from(-1)));
+            cos = -cos;
+        }
+        let t = t.to_f64();
+        // 夹角很小时 sin(theta) 接近 0，退化为线性插值
+        let (wa, wb) = if cos > 0.9995 {
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        let blended = a.0.scale(&Number::from_f64(wa)) + b.0.scale(&Number::from_f64(wb));
+        Quaternion(blended).normalize()
+    }
+}
+
+impl From<NumberArray<4>> for Quaternion {
+    fn from(components: NumberArray<4>) -> Self {
+        Quaternion(components)
+    }
+}
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// Hamilton 积，不满足交换律
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        let [a1, b1, c1, d1] = self.0.into_inner();
+        let [a2, b2, c2, d2] = rhs.0.into_inner();
+        let w = a1.clone() * a2.clone()
+            - b1.clone() * b2.clone()
+            - c1.clone() * c2.clone()
+            - d1.clone() * d2.clone();
+        let x = a1.clone() * b2.clone() + b1.clone() * a2.clone() + c1.clone() * d2.clone()
+            - d1.clone() * c2.clone();
+        let y = a1.clone() * c2.clone() - b1.clone() * d2.clone()
+            + c1.clone() * a2.clone()
+            + d1.clone() * b2.clone();
+        let z = a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2;
+        Quaternion::new(w, x, y, z)
+    }
+}
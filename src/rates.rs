@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use crate::{ArithmeticError, Number};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZeroDivisionPolicy {
+    #[default]
+    Error,
+    NaN,
+    Zero,
+    Infinity,
+}
+
+// (to - from) / |from| * 100，from 为负数时仍按变化方向给出符号
+pub fn percent_change(
+    from: &Number,
+    to: &Number,
+    policy: ZeroDivisionPolicy,
+) -> Result<Number, ArithmeticError> {
+    let delta = to.clone() - from.clone();
+    let base = if *from < Number::from(0) {
+        Number::from(0) - from.clone()
+    } else {
+        from.clone()
+    };
+    Ok(divide(&delta, &base, policy)? * Number::from(100))
+}
+
+pub fn ratio_of(
+    part: &Number,
+    whole: &Number,
+    policy: ZeroDivisionPolicy,
+) -> Result<Number, ArithmeticError> {
+    divide(part, whole, policy)
+}
+
+// 每秒速率；时长按纳秒精度转为有理数秒，避免 f64 误差
+pub fn per(
+    amount: &Number,
+    duration: Duration,
+    policy: ZeroDivisionPolicy,
+) -> Result<Number, ArithmeticError> {
+    let seconds = match i64::try_from(duration.as_nanos()) {
+        Ok(nanos) => Number::rational(nanos, 1_000_000_000),
+        Err(_) => Number::from_float(duration.as_secs_f64()),
+    };
+    divide(amount, &seconds, policy)
+}
+
+fn divide(
+    numerator: &Number,
+    denominator: &Number,
+    policy: ZeroDivisionPolicy,
+) -> Result<Number, ArithmeticError> {
+    if numerator.is_nan() || denominator.is_nan() {
+        return Err(ArithmeticError::NaNOperand);
+    }
+    if *denominator != Number::from(0) {
+        return Ok(numerator.clone() / denominator.clone());
+    }
+    divide_by_zero(numerator, policy)
+}
+
+pub(crate) fn divide_by_zero(
+    numerator: &Number,
+    policy: ZeroDivisionPolicy,
+) -> Result<Number, ArithmeticError> {
+    match policy {
+        ZeroDivisionPolicy::Error => Err(ArithmeticError::DivisionByZero),
+        ZeroDivisionPolicy::NaN => Ok(Number::NaN),
+        ZeroDivisionPolicy::Zero => Ok(Number::from(0)),
+        ZeroDivisionPolicy::Infinity => Ok(if *numerator > Number::from(0) {
+            Number::PositiveInfinity
+        } else if *numerator < Number::from(0) {
+            Number::NegativeInfinity
+        } else {
+            Number::NaN
+        }),
+    }
+}
@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+
+use crate::{BinOp, Number};
+
+impl Number {
+    pub const fn rational(numerator: i64, denominator: i64) -> Number {
+        match reduce_ratio(numerator as i128, denominator as i128) {
+            Some((n, 1)) => Number::from_wide_int(n),
+            Some((n, d)) => Number::Rational(n as i64, d as i64),
+            None if numerator > 0 => Number::PositiveInfinity,
+            None if numerator < 0 => Number::NegativeInfinity,
+            None => Number::NaN,
+        }
+    }
+    pub fn div_rational(self, rhs: Self) -> Number {
+        if let (Some((a, b)), Some((c, d))) = (self.ratio_parts(), rhs.ratio_parts())
+            && let Some(result) = ratio_op(a, b, c, d, BinOp::Div)
+        {
+            return result;
+        }
+        self / rhs
+    }
+    pub fn to_rational(&self) -> Option<Number> {
+        if let Some((n, d)) = self.ratio_parts() {
+            return Number::from_ratio(n, d);
+        }
+        let x = self.to_f64();
+        if !x.is_finite() || !self.is_float_variant() {
+            return None;
+        }
+        if x == x.trunc() {
+            return (x.abs() < i128::MAX as f64).then(|| Number::from_wide_int(x as i128));
+        }
+        // 有限小数部分的浮点数都是二进分数 m / 2^k
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let mut mantissa = (bits & ((1u64 << 52) - 1)) as i128;
+        let mut exponent = if biased == 0 {
+            -1074
+        } else {
+            mantissa |= 1 << 52;
+            biased - 1075
+        };
+        while mantissa & 1 == 0 {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+        if -exponent > 62 {
+            return None;
+        }
+        let numerator = if x < 0.0 { -mantissa } else { mantissa };
+        Number::from_ratio(numerator, 1i128 << -exponent)
+    }
+    pub fn to_float(&self) -> Number {
+        let x = self.to_f64();
+        if x.is_finite() {
+            Number::from_float(x)
+        } else {
+            Number::from_f64(x)
+        }
+    }
+    // 有理数参与的运算：两边都能写成 i64 分数时给出精确结果
+    pub(crate) fn rational_op(&self, rhs: &Self, op: BinOp) -> Option<Number> {
+        if !matches!(self, Number::Rational(..)) && !matches!(rhs, Number::Rational(..)) {
+            return None;
+        }
+        let (a, b) = self.ratio_parts()?;
+        let (c, d) = rhs.ratio_parts()?;
+        ratio_op(a, b, c, d, op)
+    }
+    pub(crate) fn rational_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !matches!(self, Number::Rational(..)) && !matches!(other, Number::Rational(..)) {
+            return None;
+        }
+        let (a, b) = self.ratio_parts()?;
+        let (c, d) = other.ratio_parts()?;
+        Some((a * d).cmp(&(c * b)))
+    }
+    fn ratio_parts(&self) -> Option<(i128, i128)> {
+        match self {
+            Number::Rational(n, d) => Some((*n as i128, *d as i128)),
+            _ => self
+                .int_value()
+                .filter(|v| *v >= i64::MIN as i128 && *v <= i64::MAX as i128)
+                .map(|v| (v, 1)),
+        }
+    }
+    pub(crate) fn from_ratio(numerator: i128, denominator: i128) -> Option<Number> {
+        match reduce_ratio(numerator, denominator)? {
+            (n, 1) => Some(Number::from_wide_int(n)),
+            (n, d) => Some(Number::Rational(n as i64, d as i64)),
+        }
+    }
+}
+
+// 约分并把符号移到分子；分母不为 1 时两部分都必须在 i64 范围内
+const fn reduce_ratio(numerator: i128, denominator: i128) -> Option<(i128, i128)> {
+    if denominator == 0 {
+        return None;
+    }
+    let (numerator, denominator) = if denominator < 0 {
+        match (numerator.checked_neg(), denominator.checked_neg()) {
+            (Some(n), Some(d)) => (n, d),
+            _ => return None,
+        }
+    } else {
+        (numerator, denominator)
+    };
+    let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+    let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+    if denominator == 1 {
+        return Some((numerator, 1));
+    }
+    if numerator < i64::MIN as i128 || numerator > i64::MAX as i128 {
+        return None;
+    }
+    if denominator > i64::MAX as i128 {
+        return None;
+    }
+    Some((numerator, denominator))
+}
+
+fn ratio_op(a: i128, b: i128, c: i128, d: i128, op: BinOp) -> Option<Number> {
+    let (numerator, denominator) = match op {
+        BinOp::Add => ((a * d).checked_add(c * b)?, b * d),
+        BinOp::Sub => ((a * d).checked_sub(c * b)?, b * d),
+        BinOp::Mul => (a * c, b * d),
+        BinOp::Div => (a * d, b * c),
+        BinOp::Rem => {
+            if c == 0 {
+                return None;
+            }
+            let quotient = (a * d) / (b * c);
+            let product = quotient.checked_mul(c)?.checked_mul(b)?;
+            ((a * d).checked_sub(product)?, b * d)
+        }
+    };
+    Number::from_ratio(numerator, denominator)
+}
+
+pub(crate) const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
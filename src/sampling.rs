@@ -0,0 +1,82 @@
+use crate::Number;
+
+pub fn apply_temperature(logits: &[Number], t: Number) -> Vec<Number> {
+    logits
+        .iter()
+        .map(|logit| logit.clone() / t.clone())
+        .collect()
+}
+
+pub fn softmax(logits: &[Number]) -> Vec<Number> {
+    if logits.is_empty() {
+        return Vec::new();
+    }
+    if logits.iter().any(|l| l.is_nan()) {
+        return vec![Number::NaN; logits.len()];
+    }
+    let max = logits
+        .iter()
+        .map(|l| l.to_f64())
+        .fold(f64::NEG_INFINITY, f64::max);
+    if max.is_infinite() {
+        // 存在无穷大时退化为在最大值上的均匀分布
+        return one_hot_max(logits, max);
+    }
+    let exps: Vec<f64> = logits.iter().map(|l| (l.to_f64() - max).exp()).collect();
+    let sum: f64 = exps.iter().sum();
+    exps.into_iter()
+        .map(|e| Number::from_float(e / sum))
+        .collect()
+}
+
+pub fn softmax_with_temperature(logits: &[Number], t: Number) -> Vec<Number> {
+    if t.is_finite() && t.to_f64() == 0.0 {
+        let max = logits
+            .iter()
+            .map(|l| l.to_f64())
+            .fold(f64::NEG_INFINITY, f64::max);
+        return one_hot_max(logits, max);
+    }
+    softmax(&apply_temperature(logits, t))
+}
+
+pub fn normalize(weights: &[Number]) -> Vec<Number> {
+    let sum = weights
+        .iter()
+        .fold(Number::from(0), |acc, w| acc + w.clone());
+    if sum.is_finite() && sum.to_f64() == 0.0 {
+        return vec![Number::NaN; weights.len()];
+    }
+    weights.iter().map(|w| w.clone() / sum.clone()).collect()
+}
+
+pub fn normalize_in_place(weights: &mut [Number]) {
+    let normalized = normalize(weights);
+    weights.clone_from_slice(&normalized);
+}
+
+pub fn temper_weights(weights: &[Number], t: Number) -> Vec<Number> {
+    if weights.iter().any(|w| w.is_nan() || w.to_f64() < 0.0) {
+        return vec![Number::NaN; weights.len()];
+    }
+    let inv_t = 1.0 / t.to_f64();
+    let powered: Vec<Number> = weights
+        .iter()
+        .map(|w| Number::from_float(w.to_f64().powf(inv_t)))
+        .collect();
+    normalize(&powered)
+}
+
+fn one_hot_max(logits: &[Number], max: f64) -> Vec<Number> {
+    let count = logits.iter().filter(|l| l.to_f64() == max).count();
+    logits
+        .iter()
+        .map(|l| {
+            if l.to_f64() == max {
+                Number::from(1) / Number::from_i64(count as i64)
+            } else {
+                Number::from(0)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,44 @@
+// JSON Schema 与 serde_impl 的输出一致：有限值为 number，NaN 和 ±∞ 按生成 schema 时
+// FormatConfig::non_finite 的设置描述为字符串或 null；超出 i128 的 BigInteger 为数字串
+
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::Number;
+use crate::config::{self, NonFiniteRepr};
+
+impl JsonSchema for Number {
+    fn schema_name() -> Cow<'static, str> {
+        "Number".into()
+    }
+    fn schema_id() -> Cow<'static, str> {
+        "my_r_num::Number".into()
+    }
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let mut variants = vec![json_schema!({ "type": "number" })];
+        let description = match config::with_current(|config| config.format.non_finite) {
+            NonFiniteRepr::Strings => {
+                variants.push(json_schema!({
+                    "type": "string",
+                    "enum": ["NaN", "Infinity", "-Infinity"]
+                }));
+                "A number. NaN and ±infinity are written as the strings \"NaN\", \"Infinity\" and \"-Infinity\"."
+            }
+            NonFiniteRepr::Null => {
+                variants.push(json_schema!({ "type": "null" }));
+                "A number. NaN and ±infinity are written as null."
+            }
+        };
+        #[cfg(feature = "bigint")]
+        variants.push(json_schema!({
+            "type": "string",
+            "pattern": "^-?[0-9]+$",
+            "description": "An integer too large for a JSON number."
+        }));
+        json_schema!({
+            "description": description,
+            "oneOf": variants
+        })
+    }
+}
@@ -0,0 +1,125 @@
+// 整数写成整数，浮点写成浮点数；Rational、Decimal、Fixed64 和 Extended 按 f64 写出。
+// NaN 和正负无穷按当前配置的 FormatConfig::non_finite 写成字符串或 null。
+// 反序列化接受任意数字、这些字符串、null 以及 Number::parse 能解析的字符串；
+// 启用 arbitrary-precision 时按 JSON 原文精确解析
+
+use std::fmt;
+
+#[cfg(feature = "bigint")]
+use num_traits::ToPrimitive;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Number;
+use crate::config::{self, NonFiniteRepr};
+
+impl Serialize for Number {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Number::PositiveInfinity => serialize_non_finite(serializer, "Infinity"),
+            Number::NegativeInfinity => serialize_non_finite(serializer, "-Infinity"),
+            Number::NaN => serialize_non_finite(serializer, "NaN"),
+            Number::Integer8(v) => serializer.serialize_i8(*v),
+            Number::Integer16(v) => serializer.serialize_i16(*v),
+            Number::Integer32(v) => serializer.serialize_i32(*v),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => serializer.serialize_i64(*v),
+            Number::UnsignedInteger64(v) => serializer.serialize_u64(*v),
+            Number::Integer128(v) => serializer.serialize_i128(*v),
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => match v.to_i128() {
+                Some(v) => serializer.serialize_i128(v),
+                None => serializer.serialize_str(&v.to_string()),
+            },
+            Number::Float32(v) if v.is_finite() => serializer.serialize_f32(*v),
+            #[cfg(feature = "half")]
+            Number::Float16(v) if v.is_finite() => serializer.serialize_f32(v.to_f32()),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) if v.is_finite() => serializer.serialize_f32(v.to_f32()),
+            _ => {
+                let value = self.to_f64();
+                if value.is_nan() {
+                    serialize_non_finite(serializer, "NaN")
+                } else if value == f64::INFINITY {
+                    serialize_non_finite(serializer, "Infinity")
+                } else if value == f64::NEG_INFINITY {
+                    serialize_non_finite(serializer, "-Infinity")
+                } else {
+                    serializer.serialize_f64(value)
+                }
+            }
+        }
+    }
+}
+
+fn serialize_non_finite<S: Serializer>(serializer: S, token: &str) -> Result<S::Ok, S::Error> {
+    match config::with_current(|config| config.format.non_finite) {
+        NonFiniteRepr::Strings => serializer.serialize_str(token),
+        NonFiniteRepr::Null => serializer.serialize_unit(),
+    }
+}
+
+impl<'de> Deserialize<'de> for Number {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Number, D::Error> {
+        deserializer.deserialize_any(NumberVisitor)
+    }
+}
+
+struct NumberVisitor;
+
+impl<'de> Visitor<'de> for NumberVisitor {
+    type Value = Number;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a number, a numeric string or null")
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Number, E> {
+        Ok(Number::from_i64(v))
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Number, E> {
+        Ok(Number::from_wide_int(v as i128))
+    }
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Number, E> {
+        Ok(Number::from_wide_int(v))
+    }
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Number, E> {
+        match i128::try_from(v) {
+            Ok(v) => Ok(Number::from_wide_int(v)),
+            Err(_) => Number::parse(&v.to_string()).map_err(E::custom),
+        }
+    }
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<Number, E> {
+        Ok(Number::from_f32(v))
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Number, E> {
+        Ok(Number::from_float(v))
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Number, E> {
+        match v {
+            "NaN" => Ok(Number::NaN),
+            "Infinity" => Ok(Number::PositiveInfinity),
+            "-Infinity" => Ok(Number::NegativeInfinity),
+            _ => Number::parse(v).map_err(E::custom),
+        }
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Number, E> {
+        Ok(Number::NaN)
+    }
+    fn visit_none<E: de::Error>(self) -> Result<Number, E> {
+        Ok(Number::NaN)
+    }
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Number, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+    // arbitrary_precision 下 serde_json 把数字表示为带私有键的单项映射，值是原始数字串
+    #[cfg(feature = "arbitrary-precision")]
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Number, A::Error> {
+        match map.next_key::<String>()? {
+            Some(key) if key == "$serde_json::private::Number" => {
+                let digits: String = map.next_value()?;
+                crate::json::from_json_digits(&digits).map_err(de::Error::custom)
+            }
+            _ => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+
+use crate::Number;
+
+impl Number {
+    // 保留 figures 位有效数字的定点写法，按精确值舍入（逢中间值取偶），末尾的有效 0 保留，
+    // 例如 0.0012 取 3 位为 "0.00120"，12345 取 2 位为 "12000"。figures 为 0 时 panic
+    pub fn to_string_sig_figs(&self, figures: usize) -> String {
+        assert!(figures > 0, "significant figures must be at least 1");
+        if !self.is_finite() {
+            return self.to_string();
+        }
+        let scientific = self.exp_string(Some(figures - 1), 'e');
+        let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+        let exponent: i64 = exponent.parse().unwrap_or(0);
+        let (sign, mantissa) = match mantissa.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", mantissa),
+        };
+        let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+        let int_len = exponent + 1;
+        let body = if int_len <= 0 {
+            format!("0.{}{}", "0".repeat(-int_len as usize), digits)
+        } else if int_len as usize >= digits.len() {
+            format!("{}{}", digits, "0".repeat(int_len as usize - digits.len()))
+        } else {
+            let (int_part, frac_part) = digits.split_at(int_len as usize);
+            format!("{}.{}", int_part, frac_part)
+        };
+        format!("{}{}", sign, body)
+    }
+    // 舍入到 figures 位有效数字，保持数值种类：整数仍为整数，浮点数保持原来的宽度，
+    // Decimal、Rational、Fixed64 变为 Decimal（超出范围时为 Float64）
+    pub fn round_sig_figs(&self, figures: usize) -> Number {
+        assert!(figures > 0, "significant figures must be at least 1");
+        if !self.is_finite() {
+            return self.clone();
+        }
+        let text = self.to_string_sig_figs(figures);
+        let as_f64 = || text.parse::<f64>().unwrap_or(f64::NAN);
+        match self {
+            Number::Float64(_) => Number::Float64(as_f64()),
+            Number::Float32(_) => Number::Float32(text.parse().unwrap_or(f32::NAN)),
+            #[cfg(feature = "half")]
+            Number::Float16(_) => Number::Float16(f16::from_f64(as_f64())),
+            #[cfg(feature = "half")]
+            Number::BFloat16(_) => Number::BFloat16(bf16::from_f64(as_f64())),
+            // 有效数字多于整数位数时小数部分全是 0
+            _ if self.is_integer_variant() => {
+                let int_part = text.split('.').next().unwrap_or(&text);
+                Number::parse_radix(int_part, 10).unwrap_or_else(|_| Number::Float64(as_f64()))
+            }
+            #[cfg(feature = "extended")]
+            Number::Extended(_) => Number::Float64(as_f64()),
+            _ => Number::parse_decimal(&text).unwrap_or_else(|_| Number::Float64(as_f64())),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::Number;
+
+impl Number {
+    // 整数、Rational、Decimal 和 Fixed64 保持原来的宽度，i8::MIN 这样取反会溢出的值
+    // 按乘以 -1 的规则升级（Integer8(-128) 为 Integer16(128)）。浮点数清除符号位，-0.0 为 0.0，
+    // NaN 保持原样，-∞ 为 ∞
+    pub fn abs(&self) -> Number {
+        if self.is_nan() || !self.is_sign_negative() {
+            return self.clone();
+        }
+        let widened = || Number::from(-1) * self.clone();
+        match self {
+            Number::NegativeInfinity => Number::PositiveInfinity,
+            Number::Rational(n, d) => n
+                .checked_abs()
+                .map_or_else(widened, |n| Number::Rational(n, *d)),
+            Number::Decimal(m, scale) => m
+                .checked_abs()
+                .map_or_else(widened, |m| Number::Decimal(m, *scale)),
+            Number::Fixed64 { raw, frac_bits } => {
+                raw.checked_abs()
+                    .map_or_else(widened, |raw| Number::Fixed64 {
+                        raw,
+                        frac_bits: *frac_bits,
+                    })
+            }
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => Number::BigInteger(-v),
+            Number::Integer128(v) => v.checked_abs().map_or_else(widened, Number::Integer128),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => v.checked_abs().map_or_else(widened, Number::Integer64),
+            Number::Integer32(v) => v.checked_abs().map_or_else(widened, Number::Integer32),
+            Number::Integer16(v) => v.checked_abs().map_or_else(widened, Number::Integer16),
+            Number::Integer8(v) => v.checked_abs().map_or_else(widened, Number::Integer8),
+            Number::Float64(v) => Number::Float64(v.abs()),
+            Number::Float32(v) => Number::Float32(v.abs()),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => Number::Float16(half::f16::from_bits(v.to_bits() & 0x7fff)),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => Number::BFloat16(half::bf16::from_bits(v.to_bits() & 0x7fff)),
+            _ => widened(),
+        }
+    }
+    // 整数和精确类型返回 Integer8 的 -1、0 或 1；浮点数与 f64::signum 相同，
+    // 保持浮点类型，±0.0 为 ±1.0，±∞ 为 ±1.0，NaN 保持原样
+    pub fn signum(&self) -> Number {
+        match self {
+            Number::NaN => Number::NaN,
+            Number::PositiveInfinity => Number::from_float(1.0),
+            Number::NegativeInfinity => Number::from_float(-1.0),
+            Number::Float64(v) if v.is_nan() => self.clone(),
+            Number::Float64(v) => Number::Float64(v.signum()),
+            Number::Float32(v) => Number::Float32(v.signum()),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => Number::Float16(v.signum()),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => Number::BFloat16(v.signum()),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => Number::from_f64(v.to_f64().signum()),
+            _ if self.is_sign_negative() => Number::from(-1),
+            _ if *self == Number::from(0) => Number::from(0),
+            _ => Number::from(1),
+        }
+    }
+    // 大小取自 self，符号取自 sign 的符号位（包括 -0.0 和带符号位的 NaN）。
+    // 整数、Rational 和 Decimal 的 0 没有符号，结果仍为 0
+    pub fn copysign(&self, sign: &Number) -> Number {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            return self.clone();
+        }
+        // abs 不改变 NaN，NaN 的符号位由下面取反
+        if self.is_sign_negative() && !self.is_nan() {
+            return self.abs();
+        }
+        match self.clone() {
+            Number::NaN => Number::NaN,
+            Number::PositiveInfinity | Number::NegativeInfinity => Number::NegativeInfinity,
+            Number::Rational(n, d) => Number::Rational(-n, d),
+            Number::Decimal(m, scale) => Number::Decimal(-m, scale),
+            Number::Fixed64 { raw, frac_bits } => Number::Fixed64 {
+                raw: -raw,
+                frac_bits,
+            },
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => Number::from(-v),
+            Number::Integer128(v) => Number::Integer128(-v),
+            Number::UnsignedInteger64(v) => Number::from_wide_int(-(v as i128)),
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(v) => Number::Integer64(-v),
+            Number::Integer32(v) => Number::Integer32(-v),
+            Number::Integer16(v) => Number::Integer16(-v),
+            Number::Integer8(v) => Number::Integer8(-v),
+            Number::Float64(v) => Number::Float64(-v),
+            Number::Float32(v) => Number::Float32(-v),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => Number::Float16(-v),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => Number::BFloat16(-v),
+            #[cfg(feature = "extended")]
+            Number::Extended(v) => Number::from(-1) * Number::Extended(v),
+        }
+    }
+}
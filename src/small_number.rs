@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, RemAssign, Sub, SubAssign};
+
+use crate::Number;
+use crate::width::{CoerceError, NumberKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Tag {
+    Integer,
+    Float,
+}
+
+// 只容纳 i32 / f32 的紧凑数值，标签加 4 字节负载共 5 字节、按 1 字节对齐，
+// 适合存放大量小数值的稠密列；运算借道 Number，结果放不下时降为 f32
+#[derive(Clone, Copy)]
+pub struct SmallNumber {
+    tag: Tag,
+    bits: [u8; 4],
+}
+
+const _: () = assert!(std::mem::size_of::<SmallNumber>() == 5);
+
+impl SmallNumber {
+    pub fn from_i32(value: i32) -> Self {
+        SmallNumber {
+            tag: Tag::Integer,
+            bits: value.to_ne_bytes(),
+        }
+    }
+    pub fn from_f32(value: f32) -> Self {
+        SmallNumber {
+            tag: Tag::Float,
+            bits: value.to_ne_bytes(),
+        }
+    }
+    pub fn as_i32(&self) -> Option<i32> {
+        match self.tag {
+            Tag::Integer => Some(i32::from_ne_bytes(self.bits)),
+            Tag::Float => None,
+        }
+    }
+    pub fn as_f32(&self) -> Option<f32> {
+        match self.tag {
+            Tag::Integer => None,
+            Tag::Float => Some(f32::from_ne_bytes(self.bits)),
+        }
+    }
+    pub fn is_integer(&self) -> bool {
+        self.tag == Tag::Integer
+    }
+    pub fn is_float(&self) -> bool {
+        self.tag == Tag::Float
+    }
+    pub fn to_number(&self) -> Number {
+        match self.tag {
+            Tag::Integer => Number::from(i32::from_ne_bytes(self.bits)),
+            Tag::Float => Number::from_exact_width(f32::from_ne_bytes(self.bits)),
+        }
+    }
+    // 运算结果的收窄：能无损放下就保留，否则取最近的 f32
+    fn narrow(number: Number) -> Self {
+        SmallNumber::try_from(number.clone())
+            .unwrap_or_else(|_| SmallNumber::from_f32(number.to_f64() as f32))
+    }
+}
+
+impl TryFrom<Number> for SmallNumber {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        if value.is_integer_variant() {
+            return match value.coerce_to(NumberKind::Integer32)? {
+                Number::Integer32(v) => Ok(SmallNumber::from_i32(v)),
+                _ => Err(CoerceError::Unsupported),
+            };
+        }
+        // 浮点直接按位收窄，保留 -0.0 与 NaN
+        if value.is_float_variant() || !value.is_finite() {
+            let x = value.to_f64();
+            let narrowed = x as f32;
+            return if x.is_nan() || narrowed as f64 == x {
+                Ok(SmallNumber::from_f32(narrowed))
+            } else {
+                Err(CoerceError::Inexact)
+            };
+        }
+        match value
+            .coerce_to(NumberKind::Integer32)
+            .or_else(|_| value.coerce_to(NumberKind::Float32))?
+        {
+            Number::Integer32(v) => Ok(SmallNumber::from_i32(v)),
+            Number::Float32(v) => Ok(SmallNumber::from_f32(v)),
+            _ => Err(CoerceError::Unsupported),
+        }
+    }
+}
+
+impl From<SmallNumber> for Number {
+    fn from(value: SmallNumber) -> Self {
+        value.to_number()
+    }
+}
+
+impl From<i8> for SmallNumber {
+    fn from(value: i8) -> Self {
+        SmallNumber::from_i32(value as i32)
+    }
+}
+
+impl From<i16> for SmallNumber {
+    fn from(value: i16) -> Self {
+        SmallNumber::from_i32(value as i32)
+    }
+}
+
+impl From<i32> for SmallNumber {
+    fn from(value: i32) -> Self {
+        SmallNumber::from_i32(value)
+    }
+}
+
+impl From<f32> for SmallNumber {
+    fn from(value: f32) -> Self {
+        SmallNumber::from_f32(value)
+    }
+}
+
+impl fmt::Debug for SmallNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.tag {
+            Tag::Integer => write!(f, "Integer32({:?})", i32::from_ne_bytes(self.bits)),
+            Tag::Float => write!(f, "Float32({:?})", f32::from_ne_bytes(self.bits)),
+        }
+    }
+}
+
+impl fmt::Display for SmallNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_number())
+    }
+}
+
+impl PartialEq for SmallNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_number() == other.to_number()
+    }
+}
+
+impl PartialOrd for SmallNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_number().partial_cmp(&other.to_number())
+    }
+}
+
+impl Add for SmallNumber {
+    type Output = SmallNumber;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        SmallNumber::narrow(self.to_number() + rhs.to_number())
+    }
+}
+
+impl Sub for SmallNumber {
+    type Output = SmallNumber;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        SmallNumber::narrow(self.to_number() - rhs.to_number())
+    }
+}
+
+impl Mul for SmallNumber {
+    type Output = SmallNumber;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        SmallNumber::narrow(self.to_number() * rhs.to_number())
+    }
+}
+
+impl Div for SmallNumber {
+    type Output = SmallNumber;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        SmallNumber::narrow(self.to_number() / rhs.to_number())
+    }
+}
+
+impl AddAssign for SmallNumber {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for SmallNumber {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for SmallNumber {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for SmallNumber {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl RemAssign for SmallNumber {
+    fn rem_assign(&mut self, rhs: Self) {
+        let mut number = self.to_number();
+        number %= rhs.to_number();
+        *self = SmallNumber::narrow(number);
+    }
+}
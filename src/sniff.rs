@@ -0,0 +1,142 @@
+use crate::{Number, NumberConfig};
+
+// 粘贴导入时判断 "1.234" 是 1234（欧洲写法）还是 1.234（美式写法）的提示
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SniffHints {
+    // 区域设置的小数点，'.' 或 ','；只在文本本身无法区分时起作用
+    pub decimal_separator: Option<char>,
+    // 这一列通常的小数位数，例如金额为 2
+    pub decimal_places: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SniffCandidate {
+    pub value: Number,
+    // 被当作小数点的字符，整数读法为 None
+    pub decimal_separator: Option<char>,
+    // 0 到 1，两种读法都成立时二者之和为 1
+    pub confidence: f64,
+}
+
+impl Number {
+    pub fn sniff(s: &str) -> Vec<SniffCandidate> {
+        Number::sniff_with(s, &SniffHints::default())
+    }
+    // 按可信度从高到低返回候选读法，无法识别为数字时返回空
+    pub fn sniff_with(s: &str, hints: &SniffHints) -> Vec<SniffCandidate> {
+        let text = s.trim();
+        let (sign, body) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text.strip_prefix('+').unwrap_or(text)),
+        };
+        // 空格、不换行空格、撇号和下划线只可能是分组符，直接去掉
+        let body: String = body
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '\u{a0}' | '\u{202f}' | '\'' | '_'))
+            .collect();
+        if body.is_empty()
+            || !body
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+        {
+            // 不是分组数字的写法时交给 parse，例如 "1e5"、"inf"、"1/3"
+            return match Number::parse_with_config(text, &NumberConfig::default()) {
+                Ok(value) => vec![candidate(value, None, 1.0)],
+                Err(_) => Vec::new(),
+            };
+        }
+        let last_dot = body.rfind('.');
+        let last_comma = body.rfind(',');
+        let separators = body.matches(['.', ',']).count();
+        let reading = |decimal: Option<char>, confidence: f64| {
+            read(sign, &body, decimal).map(|value| candidate(value, decimal, confidence))
+        };
+        let mut candidates: Vec<SniffCandidate> = match (last_dot, last_comma) {
+            (None, None) => reading(None, 1.0).into_iter().collect(),
+            // 两种都出现时靠后的是小数点
+            (Some(dot), Some(comma)) => {
+                let decimal = if dot > comma { '.' } else { ',' };
+                reading(Some(decimal), 0.95).into_iter().collect()
+            }
+            (Some(position), None) | (None, Some(position)) => {
+                let separator = body[position..].chars().next().unwrap_or('.');
+                let (int_part, frac_part) = body.split_at(position);
+                let frac_part = &frac_part[1..];
+                if separators > 1 {
+                    // 同一符号出现多次只能是分组符
+                    reading(None, 0.95).into_iter().collect()
+                } else if frac_part.len() != 3 || int_part.is_empty() || int_part == "0" {
+                    reading(Some(separator), 0.95).into_iter().collect()
+                } else {
+                    let as_decimal = decimal_weight(separator, hints);
+                    let mut both: Vec<SniffCandidate> = [
+                        reading(Some(separator), as_decimal),
+                        reading(None, 1.0 - as_decimal),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                    // 整数部分超过三位时分组读法不成立，只剩小数读法
+                    if let [only] = &mut both[..] {
+                        only.confidence = 0.95;
+                    }
+                    both
+                }
+            }
+        };
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        candidates
+    }
+}
+
+fn candidate(value: Number, decimal_separator: Option<char>, confidence: f64) -> SniffCandidate {
+    SniffCandidate {
+        value,
+        decimal_separator,
+        confidence,
+    }
+}
+
+// 恰好三位小数且整数部分不超过三位时两种读法都成立，由提示决定倾向
+fn decimal_weight(separator: char, hints: &SniffHints) -> f64 {
+    let mut weight: f64 = 0.5;
+    match hints.decimal_separator {
+        Some(c) if c == separator => weight += 0.25,
+        Some(_) => weight -= 0.25,
+        None => {}
+    }
+    match hints.decimal_places {
+        Some(3) => weight += 0.15,
+        Some(_) => weight -= 0.15,
+        None => {}
+    }
+    weight.clamp(0.05, 0.95)
+}
+
+// decimal 为小数点字符，其余的 '.' 和 ',' 按分组符校验：首组 1 到 3 位，其后每组 3 位
+fn read(sign: &str, body: &str, decimal: Option<char>) -> Option<Number> {
+    let (int_part, frac_part) = match decimal {
+        Some(c) => {
+            let (int_part, frac_part) = body.rsplit_once(c)?;
+            (int_part, Some(frac_part))
+        }
+        None => (body, None),
+    };
+    let groups: Vec<&str> = int_part.split(['.', ',']).collect();
+    let grouped = groups.len() > 1;
+    if grouped
+        && (groups[0].is_empty() || groups[0].len() > 3 || groups[1..].iter().any(|g| g.len() != 3))
+    {
+        return None;
+    }
+    if frac_part.is_some_and(|f| f.is_empty() || f.contains(['.', ','])) {
+        return None;
+    }
+    let digits: String = groups.concat();
+    let normalized = match frac_part {
+        Some(frac) => format!("{}{}.{}", sign, digits, frac),
+        None => format!("{}{}", sign, digits),
+    };
+    // 已经规范为 '.' 小数点，不能再按当前线程的区域设置解析
+    Number::parse_with_config(&normalized, &NumberConfig::default()).ok()
+}
@@ -0,0 +1,96 @@
+use crate::distributions::{regularized_beta, regularized_gamma_upper};
+use crate::{MathError, Number};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    pub statistic: Number,
+    pub p_value: Number,
+    pub degrees_of_freedom: Number,
+}
+
+pub fn mean(xs: &[Number]) -> Option<Number> {
+    if xs.is_empty() {
+        return None;
+    }
+    let sum: f64 = xs.iter().map(|x| x.to_f64()).sum();
+    Some(Number::from_f64(sum / xs.len() as f64))
+}
+
+pub fn variance(xs: &[Number]) -> Option<Number> {
+    mean_and_variance(xs).map(|(_, var)| Number::from_f64(var))
+}
+
+// Welch t 检验，不假设两组方差相同，返回双侧 p 值
+pub fn t_test(xs: &[Number], ys: &[Number]) -> Result<TestResult, MathError> {
+    let (mx, vx) = mean_and_variance(xs).ok_or(MathError::DomainError)?;
+    let (my, vy) = mean_and_variance(ys).ok_or(MathError::DomainError)?;
+    let (nx, ny) = (xs.len() as f64, ys.len() as f64);
+    let (sx, sy) = (vx / nx, vy / ny);
+    let se = (sx + sy).sqrt();
+    if se == 0.0 {
+        return Err(MathError::PoleError);
+    }
+    let df = (sx + sy).powi(2) / (sx * sx / (nx - 1.0) + sy * sy / (ny - 1.0));
+    Ok(t_result((mx - my) / se, df))
+}
+
+pub fn paired_t_test(xs: &[Number], ys: &[Number]) -> Result<TestResult, MathError> {
+    if xs.len() != ys.len() {
+        return Err(MathError::DomainError);
+    }
+    let diffs: Vec<Number> = xs
+        .iter()
+        .zip(ys)
+        .map(|(x, y)| Number::from_f64(x.to_f64() - y.to_f64()))
+        .collect();
+    let (md, vd) = mean_and_variance(&diffs).ok_or(MathError::DomainError)?;
+    let n = diffs.len() as f64;
+    let se = (vd / n).sqrt();
+    if se == 0.0 {
+        return Err(MathError::PoleError);
+    }
+    Ok(t_result(md / se, n - 1.0))
+}
+
+pub fn chi_squared(observed: &[Number], expected: &[Number]) -> Result<TestResult, MathError> {
+    if observed.len() != expected.len() || observed.len() < 2 {
+        return Err(MathError::DomainError);
+    }
+    let mut statistic = 0.0;
+    for (o, e) in observed.iter().zip(expected) {
+        let (o, e) = (o.to_f64(), e.to_f64());
+        if !o.is_finite() || !e.is_finite() || o < 0.0 {
+            return Err(MathError::DomainError);
+        }
+        if e <= 0.0 {
+            return Err(MathError::PoleError);
+        }
+        statistic += (o - e) * (o - e) / e;
+    }
+    let df = (observed.len() - 1) as f64;
+    Ok(TestResult {
+        statistic: Number::from_f64(statistic),
+        p_value: Number::from_f64(regularized_gamma_upper(df / 2.0, statistic / 2.0)),
+        degrees_of_freedom: Number::from_f64(df),
+    })
+}
+
+fn t_result(t: f64, df: f64) -> TestResult {
+    let p = regularized_beta(df / (df + t * t), df / 2.0, 0.5);
+    TestResult {
+        statistic: Number::from_f64(t),
+        p_value: Number::from_f64(p),
+        degrees_of_freedom: Number::from_f64(df),
+    }
+}
+
+// 样本均值与无偏方差，至少需要两个有限值
+fn mean_and_variance(xs: &[Number]) -> Option<(f64, f64)> {
+    if xs.len() < 2 || xs.iter().any(|x| !x.is_finite()) {
+        return None;
+    }
+    let n = xs.len() as f64;
+    let mean = xs.iter().map(|x| x.to_f64()).sum::<f64>() / n;
+    let ss: f64 = xs.iter().map(|x| (x.to_f64() - mean).powi(2)).sum();
+    Some((mean, ss / (n - 1.0)))
+}
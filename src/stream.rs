@@ -0,0 +1,147 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+
+use crate::{Number, ParseErrorKind, ParseNumberError};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    // offset 是出错的数字在流中的字节偏移，error.position 相对于该数字
+    Parse {
+        offset: u64,
+        error: ParseNumberError,
+    },
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse { offset, error } => {
+                write!(f, "number at byte {}: {}", offset, error)
+            }
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+// 从字节流中逐个读出以空白或逗号分隔的数字，只缓存当前的一个数字，不读入整个输入
+pub struct NumberStream<R> {
+    reader: BufReader<R>,
+    token: Vec<u8>,
+    offset: u64,
+}
+
+impl<R: Read> NumberStream<R> {
+    pub fn new(reader: R) -> Self {
+        NumberStream {
+            reader: BufReader::new(reader),
+            token: Vec::new(),
+            offset: 0,
+        }
+    }
+    // 下一个数字的起始位置，出错后可据此定位
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+    // 跳过分隔符，到达末尾时返回 false
+    fn skip_delimiters(&mut self) -> io::Result<bool> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(false);
+            }
+            let skipped = buf.iter().take_while(|&&b| is_delimiter(b)).count();
+            let found = skipped < buf.len();
+            self.reader.consume(skipped);
+            self.offset += skipped as u64;
+            if found {
+                return Ok(true);
+            }
+        }
+    }
+    fn read_token(&mut self) -> io::Result<()> {
+        self.token.clear();
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(());
+            }
+            let len = buf.iter().take_while(|&&b| !is_delimiter(b)).count();
+            self.token.extend_from_slice(&buf[..len]);
+            let ended = len < buf.len();
+            self.reader.consume(len);
+            self.offset += len as u64;
+            if ended {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = u8>> NumberStream<ByteIter<I>> {
+    pub fn from_bytes(bytes: impl IntoIterator<IntoIter = I>) -> Self {
+        NumberStream::new(ByteIter(bytes.into_iter()))
+    }
+}
+
+impl<R: Read> Iterator for NumberStream<R> {
+    type Item = Result<Number, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.skip_delimiters() {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(err) => return Some(Err(err.into())),
+        }
+        let start = self.offset;
+        if let Err(err) = self.read_token() {
+            return Some(Err(err.into()));
+        }
+        let parse_error = |error| StreamError::Parse {
+            offset: start,
+            error,
+        };
+        let result = match std::str::from_utf8(&self.token) {
+            Ok(text) => Number::parse(text).map_err(parse_error),
+            Err(err) => Err(parse_error(ParseNumberError::new(
+                ParseErrorKind::InvalidDigit,
+                err.valid_up_to(),
+            ))),
+        };
+        Some(result)
+    }
+}
+
+// 把字节迭代器当作 Read 使用
+pub struct ByteIter<I>(I);
+
+impl<I: Iterator<Item = u8>> Read for ByteIter<I> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut len = 0;
+        for (slot, byte) in buf.iter_mut().zip(&mut self.0) {
+            *slot = byte;
+            len += 1;
+        }
+        Ok(len)
+    }
+}
+
+fn is_delimiter(byte: u8) -> bool {
+    byte.is_ascii_whitespace() || byte == b','
+}
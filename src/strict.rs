@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::rational::gcd;
+use crate::{BinOp, Number};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticError {
+    Overflow,
+    DivisionByZero,
+    InexactResult,
+    InvalidNonFinite,
+    NaNOperand,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::Overflow => write!(f, "arithmetic overflow"),
+            ArithmeticError::DivisionByZero => write!(f, "division by zero"),
+            ArithmeticError::InexactResult => write!(f, "result cannot be represented exactly"),
+            ArithmeticError::InvalidNonFinite => {
+                write!(f, "invalid combination of non-finite values")
+            }
+            ArithmeticError::NaNOperand => write!(f, "operand is NaN"),
+        }
+    }
+}
+
+impl Error for ArithmeticError {}
+
+impl Number {
+    pub fn try_add(self, rhs: Self) -> Result<Number, ArithmeticError> {
+        check_nan(&self, &rhs)?;
+        if self.is_integer_variant() && rhs.is_integer_variant() {
+            return self
+                .integer_op(&rhs, BinOp::Add)
+                .ok_or(ArithmeticError::Overflow);
+        }
+        if self.is_infinite() && rhs.is_infinite() && is_negative(&self) != is_negative(&rhs) {
+            return Err(ArithmeticError::InvalidNonFinite);
+        }
+        let result = self.clone() + rhs.clone();
+        check_float_result(&self, &rhs, result)
+    }
+    pub fn try_sub(self, rhs: Self) -> Result<Number, ArithmeticError> {
+        check_nan(&self, &rhs)?;
+        if self.is_integer_variant() && rhs.is_integer_variant() {
+            return self
+                .integer_op(&rhs, BinOp::Sub)
+                .ok_or(ArithmeticError::Overflow);
+        }
+        if self.is_infinite() && rhs.is_infinite() && is_negative(&self) == is_negative(&rhs) {
+            return Err(ArithmeticError::InvalidNonFinite);
+        }
+        let result = self.clone() - rhs.clone();
+        check_float_result(&self, &rhs, result)
+    }
+    pub fn try_mul(self, rhs: Self) -> Result<Number, ArithmeticError> {
+        check_nan(&self, &rhs)?;
+        if self.is_integer_variant() && rhs.is_integer_variant() {
+            return self
+                .integer_op(&rhs, BinOp::Mul)
+                .ok_or(ArithmeticError::Overflow);
+        }
+        if (self.is_infinite() && rhs.to_f64() == 0.0)
+            || (rhs.is_infinite() && self.to_f64() == 0.0)
+        {
+            return Err(ArithmeticError::InvalidNonFinite);
+        }
+        let result = self.clone() * rhs.clone();
+        check_float_result(&self, &rhs, result)
+    }
+    pub fn try_div(self, rhs: Self) -> Result<Number, ArithmeticError> {
+        check_nan(&self, &rhs)?;
+        if rhs.to_f64() == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        if self.is_integer_variant() && rhs.is_integer_variant() {
+            if let Some(result) = self.integer_op(&rhs, BinOp::Div) {
+                return Ok(result);
+            }
+            return match (self.int_value(), rhs.int_value()) {
+                (Some(a), Some(b)) if a.checked_rem(b).is_none() => Err(ArithmeticError::Overflow),
+                (Some(a), Some(b)) if is_exact_dyadic_quotient(a, b) => Ok(self / rhs),
+                _ => Err(ArithmeticError::InexactResult),
+            };
+        }
+        if self.is_infinite() && rhs.is_infinite() {
+            return Err(ArithmeticError::InvalidNonFinite);
+        }
+        let result = self.clone() / rhs.clone();
+        check_float_result(&self, &rhs, result)
+    }
+    pub fn try_rem(self, rhs: Self) -> Result<Number, ArithmeticError> {
+        check_nan(&self, &rhs)?;
+        if rhs.to_f64() == 0.0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        if self.is_infinite() || rhs.is_infinite() {
+            return Err(ArithmeticError::InvalidNonFinite);
+        }
+        if self.is_integer_variant() && rhs.is_integer_variant() {
+            return self
+                .integer_op(&rhs, BinOp::Rem)
+                .ok_or(ArithmeticError::Overflow);
+        }
+        let mut result = self.clone();
+        result %= rhs.clone();
+        check_float_result(&self, &rhs, result)
+    }
+}
+
+fn check_nan(a: &Number, b: &Number) -> Result<(), ArithmeticError> {
+    if a.is_nan() || b.is_nan() {
+        Err(ArithmeticError::NaNOperand)
+    } else {
+        Ok(())
+    }
+}
+
+fn is_negative(n: &Number) -> bool {
+    n.to_f64().is_sign_negative()
+}
+
+fn check_float_result(a: &Number, b: &Number, result: Number) -> Result<Number, ArithmeticError> {
+    // 整数参与浮点运算时必须能被 f64 精确表示
+    if a.loses_precision_as_f64() || b.loses_precision_as_f64() {
+        return Err(ArithmeticError::InexactResult);
+    }
+    if result.is_nan() {
+        return Err(ArithmeticError::InvalidNonFinite);
+    }
+    if a.is_finite() && b.is_finite() && result.is_infinite() {
+        return Err(ArithmeticError::Overflow);
+    }
+    Ok(result)
+}
+
+fn is_exact_dyadic_quotient(a: i128, b: i128) -> bool {
+    let divisor = gcd(a.unsigned_abs(), b.unsigned_abs());
+    let numerator = a.unsigned_abs() / divisor;
+    let denominator = b.unsigned_abs() / divisor;
+    denominator.is_power_of_two() && numerator <= (1u128 << f64::MANTISSA_DIGITS)
+}
@@ -0,0 +1,105 @@
+use std::fmt::Write;
+
+use crate::{Number, NumberConfig, OpResult};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    pub op: &'static str,
+    pub lhs: Number,
+    pub rhs: Number,
+    pub result: Number,
+    pub exact: bool,
+}
+
+impl TraceStep {
+    // 结果变体与两个操作数都不同，说明发生了类型提升或收窄
+    pub fn promoted(&self) -> bool {
+        !self.result.same_kind(&self.lhs) && !self.result.same_kind(&self.rhs)
+    }
+}
+
+// 记录一串运算，便于对数值流水线做 golden 文件测试
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trace {
+    steps: Vec<TraceStep>,
+}
+
+impl Trace {
+    pub fn new() -> Self {
+        Trace::default()
+    }
+    pub fn steps(&self) -> &[TraceStep] {
+        &self.steps
+    }
+    pub fn add(&mut self, lhs: &Number, rhs: &Number) -> Number {
+        self.record("add", lhs, rhs, lhs.add_tracked(rhs))
+    }
+    pub fn sub(&mut self, lhs: &Number, rhs: &Number) -> Number {
+        self.record("sub", lhs, rhs, lhs.sub_tracked(rhs))
+    }
+    pub fn mul(&mut self, lhs: &Number, rhs: &Number) -> Number {
+        self.record("mul", lhs, rhs, lhs.mul_tracked(rhs))
+    }
+    pub fn div(&mut self, lhs: &Number, rhs: &Number) -> Number {
+        self.record("div", lhs, rhs, lhs.div_tracked(rhs))
+    }
+    pub fn rem(&mut self, lhs: &Number, rhs: &Number) -> Number {
+        self.record("rem", lhs, rhs, lhs.rem_tracked(rhs))
+    }
+    // 字段顺序固定、数值以字符串输出，同样的运算总是得到逐字节相同的 JSON
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"version\":1,\"steps\":[");
+        for (index, step) in self.steps.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "{{\"index\":{},\"op\":\"{}\",\"lhs\":{},\"rhs\":{},\"result\":{},\"exact\":{},\"promoted\":{}}}",
+                index,
+                step.op,
+                json_number(&step.lhs),
+                json_number(&step.rhs),
+                json_number(&step.result),
+                step.exact,
+                step.promoted()
+            );
+        }
+        out.push_str("]}");
+        out
+    }
+    fn record(&mut self, op: &'static str, lhs: &Number, rhs: &Number, result: OpResult) -> Number {
+        self.steps.push(TraceStep {
+            op,
+            lhs: lhs.clone(),
+            rhs: rhs.clone(),
+            result: result.value.clone(),
+            exact: result.exact,
+        });
+        result.value
+    }
+}
+
+// 用默认格式输出，不受全局或局部格式配置影响
+fn json_number(n: &Number) -> String {
+    format!(
+        "{{\"kind\":\"{}\",\"value\":\"{}\"}}",
+        json_escape(n.type_name()),
+        json_escape(&n.to_string_with_config(&NumberConfig::default()))
+    )
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
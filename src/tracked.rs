@@ -0,0 +1,120 @@
+use crate::{BinOp, Number, NumberKind};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpResult {
+    pub value: Number,
+    // 结果是否等于数学上的精确值（没有舍入、没有溢出截断）
+    pub exact: bool,
+}
+
+impl Number {
+    pub fn add_tracked(&self, rhs: &Self) -> OpResult {
+        self.tracked(rhs, BinOp::Add, self.clone() + rhs.clone())
+    }
+    pub fn sub_tracked(&self, rhs: &Self) -> OpResult {
+        self.tracked(rhs, BinOp::Sub, self.clone() - rhs.clone())
+    }
+    pub fn mul_tracked(&self, rhs: &Self) -> OpResult {
+        self.tracked(rhs, BinOp::Mul, self.clone() * rhs.clone())
+    }
+    pub fn div_tracked(&self, rhs: &Self) -> OpResult {
+        self.tracked(rhs, BinOp::Div, self.clone() / rhs.clone())
+    }
+    pub fn rem_tracked(&self, rhs: &Self) -> OpResult {
+        let mut value = self.clone();
+        value %= rhs.clone();
+        self.tracked(rhs, BinOp::Rem, value)
+    }
+    fn tracked(&self, rhs: &Self, op: BinOp, value: Number) -> OpResult {
+        let exact = is_exact(self, rhs, op, &value);
+        OpResult { value, exact }
+    }
+}
+
+fn is_exact(a: &Number, b: &Number, op: BinOp, value: &Number) -> bool {
+    if value.is_nan() {
+        return false;
+    }
+    if !a.is_finite() || !b.is_finite() {
+        // 无穷参与的运算在扩展实数上是精确的
+        return true;
+    }
+    if !value.is_finite() {
+        return false;
+    }
+    #[cfg(feature = "bigint")]
+    if a.is_integer_variant()
+        && b.is_integer_variant()
+        && !matches!(op, BinOp::Div)
+        && let (Some(x), Some(y), Some(v)) = (a.to_big_int(), b.to_big_int(), value.to_big_int())
+    {
+        let expected = match op {
+            BinOp::Add => x + y,
+            BinOp::Sub => x - y,
+            BinOp::Mul => x * y,
+            _ => x % y,
+        };
+        return expected == v;
+    }
+    if let Some(exact) = ratio_check(a, b, op, value) {
+        return exact;
+    }
+    float_check(a, b, op, value)
+}
+
+// 用精确分数交叉相乘验证；i128 放不下时返回 None
+fn ratio_check(a: &Number, b: &Number, op: BinOp, value: &Number) -> Option<bool> {
+    let (an, ad) = a.exact_ratio()?;
+    let (bn, bd) = b.exact_ratio()?;
+    let (rn, rd) = value.exact_ratio()?;
+    let (numerator, denominator) = match op {
+        BinOp::Add => (
+            an.checked_mul(bd)?.checked_add(bn.checked_mul(ad)?)?,
+            ad.checked_mul(bd)?,
+        ),
+        BinOp::Sub => (
+            an.checked_mul(bd)?.checked_sub(bn.checked_mul(ad)?)?,
+            ad.checked_mul(bd)?,
+        ),
+        BinOp::Mul => (an.checked_mul(bn)?, ad.checked_mul(bd)?),
+        BinOp::Div => (an.checked_mul(bd)?, ad.checked_mul(bn)?),
+        // 商向零取整：q = trunc(a / b)，余数 r = a - q·b，与 % 的符号规则相同
+        BinOp::Rem => {
+            let (num, den) = (an.checked_mul(bd)?, ad.checked_mul(bn)?);
+            let q = num.checked_div(den)?;
+            (
+                num.checked_sub(q.checked_mul(bn)?.checked_mul(ad)?)?,
+                ad.checked_mul(bd)?,
+            )
+        }
+    };
+    Some(rn.checked_mul(denominator)? == numerator.checked_mul(rd)?)
+}
+
+fn float_check(a: &Number, b: &Number, op: BinOp, value: &Number) -> bool {
+    let exact_operand = |n: &Number| n.coerce_to(NumberKind::Float64).is_ok();
+    if !exact_operand(a) || !exact_operand(b) {
+        return false;
+    }
+    let (x, y) = (a.to_f64(), b.to_f64());
+    let (result, error) = match op {
+        BinOp::Add => two_sum(x, y),
+        BinOp::Sub => two_sum(x, -y),
+        BinOp::Mul => {
+            let p = x * y;
+            (p, x.mul_add(y, -p))
+        }
+        BinOp::Div => {
+            let q = x / y;
+            (q, q.mul_add(y, -x))
+        }
+        BinOp::Rem => (x % y, 0.0),
+    };
+    error == 0.0 && value.to_f64() == result
+}
+
+fn two_sum(x: f64, y: f64) -> (f64, f64) {
+    let s = x + y;
+    let v = s - x;
+    (s, (x - (s - v)) + (y - v))
+}
@@ -0,0 +1,264 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::Number;
+
+// 符号、换算到基本单位的比例（分子/分母）、量纲指数 [长度, 质量, 时间, 电流]
+const UNITS: &[(&str, i64, i64, [i32; 4])] = &[
+    ("m", 1, 1, [1, 0, 0, 0]),
+    ("km", 1000, 1, [1, 0, 0, 0]),
+    ("cm", 1, 100, [1, 0, 0, 0]),
+    ("mm", 1, 1000, [1, 0, 0, 0]),
+    ("kg", 1, 1, [0, 1, 0, 0]),
+    ("g", 1, 1000, [0, 1, 0, 0]),
+    ("mg", 1, 1_000_000, [0, 1, 0, 0]),
+    ("s", 1, 1, [0, 0, 1, 0]),
+    ("ms", 1, 1000, [0, 0, 1, 0]),
+    ("min", 60, 1, [0, 0, 1, 0]),
+    ("h", 3600, 1, [0, 0, 1, 0]),
+    ("A", 1, 1, [0, 0, 0, 1]),
+];
+
+// 单位指数的上限，超过时报 ExponentOverflow。表中的量纲指数都是 0 或 1，
+// 单位的项数也有限，dimension 中的累加因此不会溢出
+const MAX_EXPONENT: i32 = 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitError {
+    UnknownUnit(String),
+    DimensionMismatch { lhs: Unit, rhs: Unit },
+    NonIntegerPower,
+    // 指数超出 ±MAX_EXPONENT，例如 (m^2000000000)^2
+    ExponentOverflow,
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitError::UnknownUnit(symbol) => write!(f, "unknown unit '{}'", symbol),
+            UnitError::DimensionMismatch { lhs, rhs } => {
+                write!(f, "incompatible units '{}' and '{}'", lhs, rhs)
+            }
+            UnitError::NonIntegerPower => {
+                write!(
+                    f,
+                    "quantities with units can only be raised to integer powers"
+                )
+            }
+            UnitError::ExponentOverflow => {
+                write!(f, "unit exponent exceeds {}", MAX_EXPONENT)
+            }
+        }
+    }
+}
+
+impl Error for UnitError {}
+
+// 由已知单位符号及其指数组成的复合单位，保留书写顺序，例如 km/h
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Unit {
+    terms: Vec<(&'static str, i32)>,
+}
+
+impl Unit {
+    pub fn dimensionless() -> Self {
+        Unit::default()
+    }
+    // 接受 "km"、"m^2"、"km/h"、"kg*m/s^2" 这样的写法
+    pub fn parse(s: &str) -> Result<Unit, UnitError> {
+        let (numerator, denominator) = match s.split_once('/') {
+            Some((n, d)) => (n, Some(d)),
+            None => (s, None),
+        };
+        let mut unit = Unit::dimensionless();
+        for (part, sign) in [(numerator, 1), (denominator.unwrap_or(""), -1)] {
+            for piece in part.split('*').map(str::trim).filter(|p| !p.is_empty()) {
+                let (symbol, exponent) = match piece.split_once('^') {
+                    Some((symbol, exponent)) => (
+                        symbol.trim(),
+                        exponent
+                            .trim()
+                            .parse::<i32>()
+                            .map_err(|_| UnitError::UnknownUnit(piece.to_string()))?,
+                    ),
+                    None => (piece, 1),
+                };
+                if symbol == "1" && exponent == 1 {
+                    continue;
+                }
+                let base =
+                    lookup(symbol).ok_or_else(|| UnitError::UnknownUnit(symbol.to_string()))?;
+                unit = unit.mul(&Unit {
+                    terms: vec![(base.0, checked_exponent(exponent as i64 * sign)?)],
+                })?;
+            }
+        }
+        Ok(unit)
+    }
+    pub fn is_dimensionless(&self) -> bool {
+        self.dimension() == [0; 4]
+    }
+    pub fn same_dimension(&self, other: &Unit) -> bool {
+        self.dimension() == other.dimension()
+    }
+    // 换算到基本单位（m、kg、s、A）的精确比例
+    pub fn factor(&self) -> Number {
+        self.terms
+            .iter()
+            .fold(Number::from(1), |acc, (symbol, exponent)| {
+                let (_, n, d, _) = lookup(symbol).expect("unit symbols come from the table");
+                acc * Number::rational(*n, *d).pow(*exponent)
+            })
+    }
+    pub fn mul(&self, other: &Unit) -> Result<Unit, UnitError> {
+        let mut terms = self.terms.clone();
+        for (symbol, exponent) in &other.terms {
+            match terms.iter_mut().find(|(s, _)| s == symbol) {
+                Some((_, e)) => *e = checked_exponent(*e as i64 + *exponent as i64)?,
+                None => terms.push((symbol, checked_exponent(*exponent as i64)?)),
+            }
+        }
+        terms.retain(|(_, e)| *e != 0);
+        Ok(Unit { terms })
+    }
+    pub fn div(&self, other: &Unit) -> Result<Unit, UnitError> {
+        self.mul(&other.powi(-1)?)
+    }
+    pub fn powi(&self, n: i32) -> Result<Unit, UnitError> {
+        let mut terms = self
+            .terms
+            .iter()
+            .map(|(s, e)| Ok((*s, checked_exponent(*e as i64 * n as i64)?)))
+            .collect::<Result<Vec<_>, UnitError>>()?;
+        terms.retain(|(_, e)| *e != 0);
+        Ok(Unit { terms })
+    }
+    fn dimension(&self) -> [i32; 4] {
+        let mut dimension = [0; 4];
+        for (symbol, exponent) in &self.terms {
+            let (_, _, _, base) = lookup(symbol).expect("unit symbols come from the table");
+            for (d, b) in dimension.iter_mut().zip(base) {
+                *d += b * exponent;
+            }
+        }
+        dimension
+    }
+}
+
+fn checked_exponent(exponent: i64) -> Result<i32, UnitError> {
+    i32::try_from(exponent)
+        .ok()
+        .filter(|e| e.unsigned_abs() <= MAX_EXPONENT as u32)
+        .ok_or(UnitError::ExponentOverflow)
+}
+
+fn lookup(symbol: &str) -> Option<&'static (&'static str, i64, i64, [i32; 4])> {
+    UNITS.iter().find(|(s, ..)| *s == symbol)
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_terms = |f: &mut fmt::Formatter<'_>, positive: bool| -> fmt::Result {
+            let mut first = true;
+            for (symbol, exponent) in self.terms.iter().filter(|(_, e)| (*e > 0) == positive) {
+                if !first {
+                    write!(f, "*")?;
+                }
+                first = false;
+                match exponent.abs() {
+                    1 => write!(f, "{}", symbol)?,
+                    e => write!(f, "{}^{}", symbol, e)?,
+                }
+            }
+            Ok(())
+        };
+        let has_positive = self.terms.iter().any(|(_, e)| *e > 0);
+        let has_negative = self.terms.iter().any(|(_, e)| *e < 0);
+        if has_positive {
+            write_terms(f, true)?;
+        } else {
+            write!(f, "1")?;
+        }
+        if has_negative {
+            write!(f, "/")?;
+            write_terms(f, false)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantity {
+    pub value: Number,
+    pub unit: Unit,
+}
+
+impl Quantity {
+    pub fn new(value: Number, unit: Unit) -> Self {
+        Quantity { value, unit }
+    }
+    pub fn dimensionless(value: Number) -> Self {
+        Quantity::new(value, Unit::dimensionless())
+    }
+    pub fn convert_to(&self, unit: &Unit) -> Result<Quantity, UnitError> {
+        if !self.unit.same_dimension(unit) {
+            return Err(self.mismatch(unit));
+        }
+        if self.unit == *unit {
+            return Ok(self.clone());
+        }
+        let value = self.value.clone() * self.unit.factor() / unit.factor();
+        Ok(Quantity::new(value, unit.clone()))
+    }
+    // 加减和取余先把右侧换算到左侧的单位
+    pub fn add(&self, rhs: &Quantity) -> Result<Quantity, UnitError> {
+        let rhs = self.aligned(rhs)?;
+        Ok(Quantity::new(self.value.clone() + rhs, self.unit.clone()))
+    }
+    pub fn sub(&self, rhs: &Quantity) -> Result<Quantity, UnitError> {
+        let rhs = self.aligned(rhs)?;
+        Ok(Quantity::new(self.value.clone() - rhs, self.unit.clone()))
+    }
+    pub fn rem(&self, rhs: &Quantity) -> Result<Quantity, UnitError> {
+        let rhs = self.aligned(rhs)?;
+        let mut value = self.value.clone();
+        value %= rhs;
+        Ok(Quantity::new(value, self.unit.clone()))
+    }
+    pub fn mul(&self, rhs: &Quantity) -> Result<Quantity, UnitError> {
+        let unit = self.unit.mul(&rhs.unit)?;
+        Ok(Quantity::new(self.value.clone() * rhs.value.clone(), unit))
+    }
+    pub fn div(&self, rhs: &Quantity) -> Result<Quantity, UnitError> {
+        let unit = self.unit.div(&rhs.unit)?;
+        Ok(Quantity::new(self.value.clone() / rhs.value.clone(), unit))
+    }
+    fn aligned(&self, rhs: &Quantity) -> Result<Number, UnitError> {
+        if !self.unit.same_dimension(&rhs.unit) {
+            return Err(self.mismatch(&rhs.unit));
+        }
+        Ok(rhs.convert_to(&self.unit)?.value)
+    }
+    fn mismatch(&self, other: &Unit) -> UnitError {
+        UnitError::DimensionMismatch {
+            lhs: self.unit.clone(),
+            rhs: other.clone(),
+        }
+    }
+}
+
+impl From<Number> for Quantity {
+    fn from(value: Number) -> Self {
+        Quantity::dimensionless(value)
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.unit.terms.is_empty() {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{} {}", self.value, self.unit)
+        }
+    }
+}
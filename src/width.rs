@@ -0,0 +1,594 @@
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(feature = "bigint")]
+use num_traits::ToPrimitive;
+
+use crate::Number;
+use crate::rational::gcd;
+
+// 能一一对应到某个 Number 变体的原生类型
+pub trait ExactWidth {
+    fn into_exact_number(self) -> Number;
+}
+
+impl Number {
+    // 保留传入值的宽度，不做自动收窄：from_exact_width(100i64) 得到 Integer64
+    pub fn from_exact_width<T: ExactWidth>(value: T) -> Number {
+        value.into_exact_number()
+    }
+}
+
+impl ExactWidth for i8 {
+    fn into_exact_number(self) -> Number {
+        Number::Integer8(self)
+    }
+}
+
+impl ExactWidth for i16 {
+    fn into_exact_number(self) -> Number {
+        Number::Integer16(self)
+    }
+}
+
+impl ExactWidth for i32 {
+    fn into_exact_number(self) -> Number {
+        Number::Integer32(self)
+    }
+}
+
+#[cfg(not(feature = "small"))]
+impl ExactWidth for i64 {
+    fn into_exact_number(self) -> Number {
+        Number::Integer64(self)
+    }
+}
+
+impl ExactWidth for u64 {
+    fn into_exact_number(self) -> Number {
+        Number::UnsignedInteger64(self)
+    }
+}
+
+impl ExactWidth for i128 {
+    fn into_exact_number(self) -> Number {
+        Number::Integer128(self)
+    }
+}
+
+impl ExactWidth for f32 {
+    fn into_exact_number(self) -> Number {
+        if self.is_finite() {
+            Number::Float32(self)
+        } else {
+            Number::from_f64(self as f64)
+        }
+    }
+}
+
+impl ExactWidth for f64 {
+    fn into_exact_number(self) -> Number {
+        Number::from_f64(self)
+    }
+}
+
+#[cfg(feature = "half")]
+impl ExactWidth for f16 {
+    fn into_exact_number(self) -> Number {
+        Number::from(self)
+    }
+}
+
+#[cfg(feature = "half")]
+impl ExactWidth for bf16 {
+    fn into_exact_number(self) -> Number {
+        Number::from(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberKind {
+    PositiveInfinity,
+    NegativeInfinity,
+    NaN,
+    Rational,
+    Decimal,
+    Fixed64,
+    #[cfg(feature = "bigint")]
+    BigInteger,
+    Integer128,
+    UnsignedInteger64,
+    #[cfg(not(feature = "small"))]
+    Integer64,
+    Integer32,
+    Integer16,
+    Integer8,
+    Float64,
+    Float32,
+    #[cfg(feature = "extended")]
+    Extended,
+    #[cfg(feature = "half")]
+    Float16,
+    #[cfg(feature = "half")]
+    BFloat16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoerceError {
+    OutOfRange,
+    Inexact,
+    Fractional,
+    NonFinite,
+    Unsupported,
+}
+
+impl fmt::Display for CoerceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoerceError::OutOfRange => write!(f, "value is out of range for the target kind"),
+            CoerceError::Inexact => write!(f, "value cannot be represented exactly"),
+            CoerceError::Fractional => write!(f, "value has a fractional part"),
+            CoerceError::NonFinite => write!(f, "non-finite value has no finite representation"),
+            CoerceError::Unsupported => write!(f, "conversion to this kind is not supported"),
+        }
+    }
+}
+
+impl Error for CoerceError {}
+
+// 降为 f32 时数值发生了变化；rounded 是实际得到的 f32
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrecisionLoss {
+    pub rounded: f32,
+}
+
+impl fmt::Display for PrecisionLoss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value changed when narrowed to f32 ({})", self.rounded)
+    }
+}
+
+impl Error for PrecisionLoss {}
+
+impl Number {
+    pub fn kind(&self) -> NumberKind {
+        match self {
+            Number::PositiveInfinity => NumberKind::PositiveInfinity,
+            Number::NegativeInfinity => NumberKind::NegativeInfinity,
+            Number::NaN => NumberKind::NaN,
+            Number::Rational(..) => NumberKind::Rational,
+            Number::Decimal(..) => NumberKind::Decimal,
+            Number::Fixed64 { .. } => NumberKind::Fixed64,
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(_) => NumberKind::BigInteger,
+            Number::Integer128(_) => NumberKind::Integer128,
+            Number::UnsignedInteger64(_) => NumberKind::UnsignedInteger64,
+            #[cfg(not(feature = "small"))]
+            Number::Integer64(_) => NumberKind::Integer64,
+            Number::Integer32(_) => NumberKind::Integer32,
+            Number::Integer16(_) => NumberKind::Integer16,
+            Number::Integer8(_) => NumberKind::Integer8,
+            Number::Float64(_) => NumberKind::Float64,
+            Number::Float32(_) => NumberKind::Float32,
+            #[cfg(feature = "extended")]
+            Number::Extended(_) => NumberKind::Extended,
+            #[cfg(feature = "half")]
+            Number::Float16(_) => NumberKind::Float16,
+            #[cfg(feature = "half")]
+            Number::BFloat16(_) => NumberKind::BFloat16,
+        }
+    }
+    #[cfg(not(feature = "small"))]
+    pub fn widen_to_i64(&self) -> Result<Number, CoerceError> {
+        self.coerce_to(NumberKind::Integer64)
+    }
+    pub fn widen_to_f64(&self) -> Result<Number, CoerceError> {
+        self.coerce_to(NumberKind::Float64)
+    }
+    // 无损地转换到指定变体，做不到时返回错误而不是静默舍入
+    pub fn coerce_to(&self, kind: NumberKind) -> Result<Number, CoerceError> {
+        if self.kind() == kind {
+            return Ok(self.clone());
+        }
+        if !self.is_finite() {
+            return coerce_non_finite(self.to_f64(), kind);
+        }
+        let (n, d) = self.exact_ratio().ok_or(CoerceError::OutOfRange)?;
+        let integer = || {
+            if d == 1 {
+                Ok(n)
+            } else {
+                Err(CoerceError::Fractional)
+            }
+        };
+        let out_of_range = |_| CoerceError::OutOfRange;
+        match kind {
+            NumberKind::Integer8 => Ok(Number::Integer8(
+                integer()?.try_into().map_err(out_of_range)?,
+            )),
+            NumberKind::Integer16 => Ok(Number::Integer16(
+                integer()?.try_into().map_err(out_of_range)?,
+            )),
+            NumberKind::Integer32 => Ok(Number::Integer32(
+                integer()?.try_into().map_err(out_of_range)?,
+            )),
+            #[cfg(not(feature = "small"))]
+            NumberKind::Integer64 => Ok(Number::Integer64(
+                integer()?.try_into().map_err(out_of_range)?,
+            )),
+            NumberKind::UnsignedInteger64 => Ok(Number::UnsignedInteger64(
+                integer()?.try_into().map_err(out_of_range)?,
+            )),
+            NumberKind::Integer128 => Ok(Number::Integer128(integer()?)),
+            #[cfg(feature = "bigint")]
+            NumberKind::BigInteger => Ok(Number::BigInteger(integer()?.into())),
+            NumberKind::Rational => Ok(Number::Rational(
+                n.try_into().map_err(out_of_range)?,
+                d.try_into().map_err(out_of_range)?,
+            )),
+            NumberKind::Decimal => ratio_to_decimal(n, d),
+            NumberKind::Float64 => Ok(Number::Float64(ratio_to_f64(n, d)?)),
+            NumberKind::Float32 => {
+                let x = ratio_to_f64(n, d)?;
+                let narrowed = x as f32;
+                if narrowed as f64 == x {
+                    Ok(Number::Float32(narrowed))
+                } else {
+                    Err(CoerceError::Inexact)
+                }
+            }
+            #[cfg(feature = "half")]
+            NumberKind::Float16 => {
+                let x = ratio_to_f64(n, d)?;
+                let narrowed = f16::from_f64(x);
+                if narrowed.to_f64() == x {
+                    Ok(Number::Float16(narrowed))
+                } else {
+                    Err(CoerceError::Inexact)
+                }
+            }
+            #[cfg(feature = "half")]
+            NumberKind::BFloat16 => {
+                let x = ratio_to_f64(n, d)?;
+                let narrowed = bf16::from_f64(x);
+                if narrowed.to_f64() == x {
+                    Ok(Number::BFloat16(narrowed))
+                } else {
+                    Err(CoerceError::Inexact)
+                }
+            }
+            NumberKind::PositiveInfinity | NumberKind::NegativeInfinity | NumberKind::NaN => {
+                Err(CoerceError::OutOfRange)
+            }
+            _ => Err(CoerceError::Unsupported),
+        }
+    }
+    // 在同一类别内选出能无损表示当前值的最小变体
+    pub fn shrink_to_fit(&self) -> Number {
+        match self {
+            Number::Decimal(m, scale) => {
+                let (mut m, mut scale) = (*m, *scale);
+                while scale > 0 && m % 10 == 0 {
+                    m /= 10;
+                    scale -= 1;
+                }
+                if scale == 0 {
+                    Number::from_wide_int(m)
+                } else {
+                    Number::Decimal(m, scale)
+                }
+            }
+            Number::Fixed64 { raw, frac_bits } => {
+                let shift = raw.trailing_zeros().min(*frac_bits as u32);
+                Number::Fixed64 {
+                    raw: raw >> shift,
+                    frac_bits: frac_bits - shift as u8,
+                }
+            }
+            _ if self.is_float_variant() => {
+                let x = self.to_f64();
+                if !x.is_finite() {
+                    return Number::from_f64(x);
+                }
+                #[cfg(feature = "half")]
+                if let Some(number) = Number::from_half_float(x) {
+                    return number;
+                }
+                if x as f32 as f64 == x {
+                    Number::Float32(x as f32)
+                } else {
+                    Number::Float64(x)
+                }
+            }
+            _ => match self.int_value() {
+                Some(v) => Number::from_wide_int(v),
+                None => self.clone(),
+            },
+        }
+    }
+    // 有限值的精确分数形式 n/d（d > 0 且已约分），放不进 i128 时返回 None
+    pub(crate) fn exact_ratio(&self) -> Option<(i128, i128)> {
+        let (n, d) = match self {
+            Number::Rational(n, d) => (*n as i128, *d as i128),
+            Number::Decimal(m, scale) => (*m, 10i128.checked_pow(*scale)?),
+            Number::Fixed64 { raw, frac_bits } => (*raw as i128, 1i128 << frac_bits),
+            #[cfg(feature = "bigint")]
+            Number::BigInteger(v) => (v.to_i128()?, 1),
+            _ if self.is_float_variant() => float_ratio(self.to_f64())?,
+            _ => (self.int_value()?, 1),
+        };
+        let divisor = gcd(n.unsigned_abs(), d.unsigned_abs()) as i128;
+        Some((n / divisor, d / divisor))
+    }
+}
+
+impl Number {
+    // 只有值能被目标类型精确表示时才返回 Some，例如 Float64(3.0).as_i64() == Some(3)
+    pub fn as_i64(&self) -> Option<i64> {
+        self.exact_i128().ok()?.try_into().ok()
+    }
+    pub fn as_i32(&self) -> Option<i32> {
+        self.exact_i128().ok()?.try_into().ok()
+    }
+    // 用作下标或长度：负数、有小数部分或超出 usize 时返回错误
+    pub fn to_usize(&self) -> Result<usize, CoerceError> {
+        self.exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+    pub fn as_f32(&self) -> Option<f32> {
+        self.exact_f32().ok()
+    }
+    pub fn to_f32(&self) -> f32 {
+        match self {
+            Number::Float32(v) => *v,
+            _ => self.to_f64() as f32,
+        }
+    }
+    pub fn to_f32_checked(&self) -> Result<f32, PrecisionLoss> {
+        self.exact_f32().map_err(|_| PrecisionLoss {
+            rounded: self.to_f32(),
+        })
+    }
+    pub fn as_f64_exact(&self) -> Option<f64> {
+        self.exact_f64().ok()
+    }
+    // 精确的整数值：有小数部分、非有限值或超出 i128 时返回对应错误
+    pub(crate) fn exact_i128(&self) -> Result<i128, CoerceError> {
+        if let Some(v) = self.int_value() {
+            return Ok(v);
+        }
+        match self.coerce_to(NumberKind::Integer128)? {
+            Number::Integer128(v) => Ok(v),
+            _ => Err(CoerceError::Unsupported),
+        }
+    }
+    // 浮点变体直接按位取值，保留 -0.0、无穷与 NaN
+    pub(crate) fn exact_f64(&self) -> Result<f64, CoerceError> {
+        if self.is_float_variant() || !self.is_finite() {
+            return Ok(self.to_f64());
+        }
+        match self.coerce_to(NumberKind::Float64)? {
+            Number::Float64(x) => Ok(x),
+            _ => Err(CoerceError::Unsupported),
+        }
+    }
+    pub(crate) fn exact_f32(&self) -> Result<f32, CoerceError> {
+        let x = self.exact_f64()?;
+        if x.is_nan() || x as f32 as f64 == x {
+            Ok(x as f32)
+        } else {
+            Err(CoerceError::Inexact)
+        }
+    }
+}
+
+impl TryFrom<Number> for i8 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for i16 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for i32 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for i64 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for isize {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for u8 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for u16 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for u32 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for u64 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value
+            .exact_i128()?
+            .try_into()
+            .map_err(|_| CoerceError::OutOfRange)
+    }
+}
+
+impl TryFrom<Number> for usize {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value.to_usize()
+    }
+}
+
+impl TryFrom<Number> for f32 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value.exact_f32()
+    }
+}
+
+impl TryFrom<Number> for f64 {
+    type Error = CoerceError;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        value.exact_f64()
+    }
+}
+
+fn coerce_non_finite(x: f64, kind: NumberKind) -> Result<Number, CoerceError> {
+    match kind {
+        NumberKind::Float64 => Ok(Number::Float64(x)),
+        NumberKind::Float32 => Ok(Number::Float32(x as f32)),
+        #[cfg(feature = "half")]
+        NumberKind::Float16 => Ok(Number::Float16(f16::from_f64(x))),
+        #[cfg(feature = "half")]
+        NumberKind::BFloat16 => Ok(Number::BFloat16(bf16::from_f64(x))),
+        NumberKind::PositiveInfinity if x == f64::INFINITY => Ok(Number::PositiveInfinity),
+        NumberKind::NegativeInfinity if x == f64::NEG_INFINITY => Ok(Number::NegativeInfinity),
+        NumberKind::NaN if x.is_nan() => Ok(Number::NaN),
+        _ => Err(CoerceError::NonFinite),
+    }
+}
+
+fn float_ratio(x: f64) -> Option<(i128, i128)> {
+    if x == 0.0 {
+        return Some((0, 1));
+    }
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let (mantissa, exponent) = if biased == 0 {
+        ((bits & ((1u64 << 52) - 1)) as i128, -1074)
+    } else {
+        (
+            ((bits & ((1u64 << 52) - 1)) | (1u64 << 52)) as i128,
+            biased - 1075,
+        )
+    };
+    let mantissa = if x < 0.0 { -mantissa } else { mantissa };
+    if exponent >= 0 {
+        Some((
+            mantissa.checked_mul(1i128.checked_shl(exponent as u32)?)?,
+            1,
+        ))
+    } else {
+        let shift = mantissa.trailing_zeros().min((-exponent) as u32);
+        let denominator_shift = (-exponent) as u32 - shift;
+        (denominator_shift < 127).then(|| (mantissa >> shift, 1i128 << denominator_shift))
+    }
+}
+
+// 只有分母是 2 的幂且分子不超过 53 位时才能精确转为 f64
+fn ratio_to_f64(n: i128, d: i128) -> Result<f64, CoerceError> {
+    if d.count_ones() != 1 {
+        return Err(CoerceError::Inexact);
+    }
+    let numerator = n as f64;
+    if numerator as i128 != n {
+        return Err(CoerceError::Inexact);
+    }
+    let x = numerator / d as f64;
+    if float_ratio(x) == Some((n, d)) {
+        Ok(x)
+    } else {
+        Err(CoerceError::Inexact)
+    }
+}
+
+// 分母只含因子 2 和 5 时才是有限小数
+fn ratio_to_decimal(n: i128, d: i128) -> Result<Number, CoerceError> {
+    let (mut rest, mut twos, mut fives) = (d, 0u32, 0u32);
+    while rest % 2 == 0 {
+        rest /= 2;
+        twos += 1;
+    }
+    while rest % 5 == 0 {
+        rest /= 5;
+        fives += 1;
+    }
+    if rest != 1 {
+        return Err(CoerceError::Inexact);
+    }
+    let scale = twos.max(fives);
+    let factor = 10i128
+        .checked_pow(scale)
+        .map(|p| p / d)
+        .ok_or(CoerceError::OutOfRange)?;
+    let mantissa = n.checked_mul(factor).ok_or(CoerceError::OutOfRange)?;
+    if scale > 38 {
+        return Err(CoerceError::OutOfRange);
+    }
+    Ok(Number::Decimal(mantissa, scale))
+}
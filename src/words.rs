@@ -0,0 +1,150 @@
+// 把数字读成文字。整数部分交给语言实现，小数部分逐位读出，
+// 例如英文 -3.14 为 "minus three point one four"
+use crate::Number;
+
+// 一种语言的读法，实现 integer 即可，其余方法有英文的默认值
+pub trait Language {
+    // 非负整数的读法
+    fn integer(&self, magnitude: u128) -> String;
+    // 小数部分单个数字的读法
+    fn digit(&self, digit: u8) -> String {
+        self.integer(digit as u128)
+    }
+    fn minus(&self) -> &str {
+        "minus"
+    }
+    fn point(&self) -> &str {
+        "point"
+    }
+    // 词与词之间的分隔，中文为空
+    fn separator(&self) -> &str {
+        " "
+    }
+}
+
+// 美式英语，不加 "and"：1234 为 "one thousand two hundred thirty-four"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct English;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+// 第 i 个元素对应 10^(3i)，u128 的最大值不到 10^39
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+impl Language for English {
+    fn integer(&self, mut magnitude: u128) -> String {
+        if magnitude == 0 {
+            return ONES[0].to_string();
+        }
+        let mut groups = Vec::new();
+        while magnitude > 0 {
+            groups.push((magnitude % 1000) as usize);
+            magnitude /= 1000;
+        }
+        let mut words = Vec::new();
+        for (index, &group) in groups.iter().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+            if group >= 100 {
+                words.push(ONES[group / 100].to_string());
+                words.push("hundred".to_string());
+            }
+            match group % 100 {
+                0 => {}
+                n if n < 20 => words.push(ONES[n].to_string()),
+                n if n % 10 == 0 => words.push(TENS[n / 10].to_string()),
+                n => words.push(format!("{}-{}", TENS[n / 10], ONES[n % 10])),
+            }
+            if index > 0 {
+                words.push(SCALES[index].to_string());
+            }
+        }
+        words.join(" ")
+    }
+}
+
+impl Number {
+    // 非有限值和整数部分超出 i128 的值返回 None。非整数按其十进制写法逐位读出，
+    // 浮点数使用最短的往返表示
+    pub fn to_words<L: Language + ?Sized>(&self, language: &L) -> Option<String> {
+        let (negative, magnitude, fraction) = self.spoken_parts()?;
+        let separator = language.separator();
+        let mut words = Vec::new();
+        if negative {
+            words.push(language.minus().to_string());
+        }
+        words.push(language.integer(magnitude));
+        if !fraction.is_empty() {
+            words.push(language.point().to_string());
+            words.extend(fraction.bytes().map(|b| language.digit(b - b'0')));
+        }
+        Some(words.join(separator))
+    }
+    // 符号、整数部分的绝对值和去掉末尾 0 的小数数字；-0 不带符号
+    fn spoken_parts(&self) -> Option<(bool, u128, String)> {
+        if !self.is_finite() {
+            return None;
+        }
+        #[cfg(feature = "bigint")]
+        if let Number::BigInteger(v) = self {
+            use num_traits::ToPrimitive;
+            let value = v.to_i128()?;
+            return Some((value < 0, value.unsigned_abs(), String::new()));
+        }
+        if let Some(value) = self.int_value() {
+            return Some((value < 0, value.unsigned_abs(), String::new()));
+        }
+        let text = match self {
+            Number::Decimal(..) | Number::Fixed64 { .. } => self.plain_string(),
+            Number::Float32(v) => v.to_string(),
+            #[cfg(feature = "half")]
+            Number::Float16(v) => v.to_f32().to_string(),
+            #[cfg(feature = "half")]
+            Number::BFloat16(v) => v.to_f32().to_string(),
+            _ => self.to_f64().to_string(),
+        };
+        let (int_part, fraction) = text.split_once('.').unwrap_or((&text, ""));
+        let fraction = fraction.trim_end_matches('0');
+        let magnitude = int_part.trim_start_matches('-').parse::<i128>().ok()?;
+        let negative = int_part.starts_with('-') && (magnitude != 0 || !fraction.is_empty());
+        Some((negative, magnitude.unsigned_abs(), fraction.to_string()))
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(all(not(feature = "small"), not(feature = "f64-only")))]
+
+use my_r_num::{Number, OverflowPolicy};
+
+#[test]
+fn integer64_sums_stay_integer64() {
+    let sum = Number::Integer64(40) + Number::Integer64(2);
+    assert!(sum.eq_exact(&Number::Integer64(42)), "{:?}", sum);
+    let sum = Number::Integer64(i64::MAX) + Number::Integer64(i64::MIN);
+    assert!(sum.eq_exact(&Number::Integer64(-1)), "{:?}", sum);
+    let sum = Number::Integer64(5).add_with_policy(&Number::Integer64(-5), OverflowPolicy::Error);
+    assert!(sum.eq_exact(&Number::Integer64(0)), "{:?}", sum);
+    // 超出 i64 时照常升级
+    let sum = Number::Integer64(i64::MAX) + Number::Integer64(1);
+    assert!(
+        sum.eq_exact(&Number::UnsignedInteger64(1 << 63)),
+        "{:?}",
+        sum
+    );
+}
+
+#[test]
+fn other_integer_results_narrow_to_fit() {
+    let sum = Number::Integer64(40) + Number::Integer32(2);
+    assert!(sum.eq_exact(&Number::Integer8(42)), "{:?}", sum);
+    let difference = Number::Integer64(44) - Number::Integer64(2);
+    assert!(
+        difference.eq_exact(&Number::Integer8(42)),
+        "{:?}",
+        difference
+    );
+}
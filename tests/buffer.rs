@@ -0,0 +1,84 @@
+// bigint / extended 的格式化会分配，不在保证范围内
+#![cfg(all(feature = "format", not(feature = "bigint"), not(feature = "extended")))]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use my_r_num::{BufferTooSmall, Number};
+
+// 只统计当前线程的分配，测试框架其他线程的分配不影响结果
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn samples() -> Vec<Number> {
+    vec![
+        Number::PositiveInfinity,
+        Number::NegativeInfinity,
+        Number::NaN,
+        Number::Rational(-22, 7),
+        Number::Decimal(-123_456_789, 4),
+        Number::Fixed64 {
+            raw: -3 << 30,
+            frac_bits: 32,
+        },
+        Number::Integer128(i128::MIN),
+        Number::UnsignedInteger64(u64::MAX),
+        #[cfg(not(feature = "small"))]
+        Number::Integer64(i64::MIN),
+        Number::Integer32(-7),
+        Number::Integer16(300),
+        Number::Integer8(-1),
+        Number::Float64(-1.2345678901234567e-300),
+        Number::Float64(0.1),
+        Number::Float32(3.4028235e38),
+        Number::Float32(-0.0),
+    ]
+}
+
+#[test]
+fn write_to_buf_does_not_allocate() {
+    let samples = samples();
+    let mut buf = [0u8; 512];
+    // 第一次调用会初始化全局配置，之后不再分配
+    let _ = samples[0].write_to_buf(&mut buf);
+    for number in &samples {
+        let mut written = 0;
+        let count = allocations_during(|| written = number.write_to_buf(&mut buf).unwrap());
+        assert_eq!(count, 0, "{:?} allocated", number);
+        assert_eq!(&buf[..written], number.to_string().as_bytes());
+        let mut tiny = [0u8; 2];
+        let count = allocations_during(|| {
+            let err = number.write_to_buf(&mut tiny).err();
+            if written > tiny.len() {
+                assert_eq!(err, Some(BufferTooSmall { required: written }));
+            }
+        });
+        assert_eq!(count, 0, "{:?} allocated on a short buffer", number);
+    }
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "cbor")]
+
+use my_r_num::{DecodeError, Number};
+
+#[test]
+fn rejects_out_of_range_scale_and_frac_bits() {
+    // 标签 4 [-60, 1]、标签 5 [-200, 1] 和标签 5 [-64, 1]
+    for bytes in [
+        &[0xc4, 0x82, 0x38, 0x3b, 0x01][..],
+        &[0xc5, 0x82, 0x38, 0xc7, 0x01],
+        &[0xc5, 0x82, 0x38, 0x3f, 0x01],
+    ] {
+        assert_eq!(Number::from_cbor(bytes), Err(DecodeError::OutOfRange));
+    }
+    // 标签 4 [-38, 1] 和标签 5 [-63, 1] 在范围内
+    let decimal = Number::from_cbor(&[0xc4, 0x82, 0x38, 0x25, 0x01]).unwrap();
+    assert!(matches!(decimal, Number::Decimal(1, 38)));
+    assert_eq!(decimal.to_string(), format!("0.{}1", "0".repeat(37)));
+    let fixed = Number::from_cbor(&[0xc5, 0x82, 0x38, 0x3e, 0x01]).unwrap();
+    assert!(matches!(
+        fixed,
+        Number::Fixed64 {
+            raw: 1,
+            frac_bits: 63
+        }
+    ));
+    let _ = fixed.to_string();
+}
+
+#[test]
+fn bignum_tags_in_long_form_keep_their_sign() {
+    // 标签 3 用 d8 03 编码，内容 h'01' 表示 -1 - 1 = -2
+    assert_eq!(
+        Number::from_cbor(&[0xd8, 0x03, 0x41, 0x01]),
+        Ok(Number::from(-2))
+    );
+    assert_eq!(Number::from_cbor(&[0xc3, 0x41, 0x01]), Ok(Number::from(-2)));
+    assert_eq!(
+        Number::from_cbor(&[0xd8, 0x02, 0x41, 0x01]),
+        Ok(Number::from(1))
+    );
+    // 标签 4 [0, 标签 3 长格式 h'01']
+    let decimal = Number::from_cbor(&[0xc4, 0x82, 0x00, 0xd8, 0x03, 0x41, 0x01]).unwrap();
+    assert_eq!(decimal, Number::from(-2));
+}
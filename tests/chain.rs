@@ -0,0 +1,41 @@
+use my_r_num::{MathError, Number};
+
+fn round(n: Number, digits: u32) -> Number {
+    n.math().round_to(digits).done().unwrap()
+}
+
+#[test]
+fn exact_values_round_half_away_from_zero() {
+    assert!(round(Number::Rational(5, 2), 0).eq_exact(&Number::Decimal(3, 0)));
+    assert!(round(Number::Rational(-5, 2), 0).eq_exact(&Number::Decimal(-3, 0)));
+    assert!(round(Number::Decimal(25, 1), 0).eq_exact(&Number::Decimal(3, 0)));
+    assert!(round(Number::Rational(1, 8), 2).eq_exact(&Number::Decimal(13, 2)));
+    // 0.125 与 -2.375
+    let fixed = |raw| Number::Fixed64 { raw, frac_bits: 3 };
+    assert!(round(fixed(1), 2).eq_exact(&Number::Decimal(13, 2)));
+    assert!(round(fixed(-19), 2).eq_exact(&Number::Decimal(-238, 2)));
+}
+
+#[test]
+fn rationals_keep_all_requested_digits() {
+    assert!(
+        round(Number::Rational(1, 3), 20)
+            .eq_exact(&Number::Decimal(33_333_333_333_333_333_333, 20))
+    );
+    assert!(round(Number::Rational(2, 3), 30).eq_exact(&Number::Decimal(
+        666_666_666_666_666_666_666_666_666_667,
+        30
+    )));
+    // 能提前除尽的值不补尾随零
+    assert!(round(Number::Rational(1, 4), 10).eq_exact(&Number::Decimal(25, 2)));
+    assert_eq!(
+        Number::Rational(1, 3).math().round_to(40).done(),
+        Err(MathError::LossOfPrecision)
+    );
+}
+
+#[test]
+fn floats_round_their_binary_value() {
+    // 2.675 的二进制值略小于 2.675
+    assert!(round(Number::Float64(2.675), 2).eq_exact(&Number::Decimal(267, 2)));
+}
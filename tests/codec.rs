@@ -0,0 +1,113 @@
+use my_r_num::{DecodeError, Number};
+
+// xorshift64*，固定种子保证失败可以复现
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+    fn wide(&mut self) -> u128 {
+        (self.next() as u128) << 64 | self.next() as u128
+    }
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+fn random_number(rng: &mut Rng) -> Number {
+    match rng.below(17) {
+        0 => Number::PositiveInfinity,
+        1 => Number::NegativeInfinity,
+        2 => Number::NaN,
+        3 => Number::rational(rng.next() as i64, rng.next() as i64 >> rng.below(64)),
+        4 => Number::Decimal(rng.wide() as i128, rng.below(39) as u32),
+        5 => Number::Fixed64 {
+            raw: rng.next() as i64,
+            frac_bits: rng.below(64) as u8,
+        },
+        6 => Number::Integer128(rng.wide() as i128),
+        7 => Number::UnsignedInteger64(rng.next()),
+        #[cfg(not(feature = "small"))]
+        8 => Number::Integer64(rng.next() as i64),
+        9 => Number::Integer32(rng.next() as i32),
+        10 => Number::Integer16(rng.next() as i16),
+        11 => Number::Integer8(rng.next() as i8),
+        12 => Number::Float64(f64::from_bits(rng.next())),
+        13 => Number::Float32(f32::from_bits(rng.next() as u32)),
+        #[cfg(feature = "half")]
+        14 => Number::Float16(half::f16::from_bits(rng.next() as u16)),
+        #[cfg(feature = "half")]
+        15 => Number::BFloat16(half::bf16::from_bits(rng.next() as u16)),
+        #[cfg(feature = "bigint")]
+        16 => {
+            let bytes: Vec<u8> = (0..rng.below(40)).map(|_| rng.next() as u8).collect();
+            Number::BigInteger(num_bigint::BigInt::from_signed_bytes_le(&bytes))
+        }
+        _ => Number::Integer8(0),
+    }
+}
+
+#[test]
+fn round_trips_every_variant() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..200_000 {
+        let number = random_number(&mut rng);
+        let bytes = number.to_le_bytes();
+        let decoded = Number::from_le_bytes(&bytes)
+            .unwrap_or_else(|err| panic!("{:?} failed to decode: {}", number, err));
+        assert_eq!(decoded.to_le_bytes(), bytes, "{:?}", number);
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", number));
+        let (read, len) = Number::read_le_bytes(&bytes).unwrap();
+        assert_eq!((read.to_le_bytes(), len), (bytes.clone(), bytes.len()));
+    }
+}
+
+#[test]
+fn arbitrary_bytes_decode_or_fail_cleanly() {
+    let mut rng = Rng(0x0123_4567_89ab_cdef);
+    for _ in 0..200_000 {
+        let mut bytes = vec![rng.below(18) as u8];
+        bytes.extend((0..rng.below(33)).map(|_| rng.next() as u8));
+        if let Ok((number, len)) = Number::read_le_bytes(&bytes) {
+            let _ = number.to_string();
+            let encoded = number.to_le_bytes();
+            assert_eq!(
+                Number::from_le_bytes(&encoded).map(|n| n.to_le_bytes()),
+                Ok(encoded)
+            );
+            assert!(len <= bytes.len());
+        }
+    }
+}
+
+#[test]
+fn rejects_invalid_payloads() {
+    let rational = |n: i64, d: i64| {
+        let mut bytes = vec![3];
+        bytes.extend_from_slice(&n.to_le_bytes());
+        bytes.extend_from_slice(&d.to_le_bytes());
+        Number::from_le_bytes(&bytes)
+    };
+    assert_eq!(rational(1, 0), Err(DecodeError::OutOfRange));
+    assert_eq!(rational(0, 0), Err(DecodeError::OutOfRange));
+    assert!(matches!(rational(2, 4), Ok(Number::Rational(1, 2))));
+    assert!(matches!(rational(3, -6), Ok(Number::Rational(-1, 2))));
+    assert!(matches!(rational(4, 2), Ok(Number::Integer8(2))));
+
+    let mut decimal = vec![4];
+    decimal.extend_from_slice(&1i128.to_le_bytes());
+    decimal.extend_from_slice(&60u32.to_le_bytes());
+    assert_eq!(
+        Number::from_le_bytes(&decimal),
+        Err(DecodeError::OutOfRange)
+    );
+
+    let mut fixed = vec![5];
+    fixed.extend_from_slice(&1i64.to_le_bytes());
+    fixed.push(200);
+    assert_eq!(Number::from_le_bytes(&fixed), Err(DecodeError::OutOfRange));
+}
@@ -0,0 +1,34 @@
+use my_r_num::{ComplexNumber, Number, ParseErrorKind, ParseNumberError};
+
+#[test]
+fn complex_parse_accepts_the_usual_forms() {
+    let parsed = ComplexNumber::parse(" 3-4i ").unwrap();
+    assert_eq!(
+        parsed,
+        ComplexNumber::new(Number::from(3), Number::from(-4))
+    );
+    let parsed = ComplexNumber::parse("-i").unwrap();
+    assert_eq!(
+        parsed,
+        ComplexNumber::new(Number::from(0), Number::from(-1))
+    );
+    let parsed = ComplexNumber::parse("2.5").unwrap();
+    assert_eq!(
+        parsed,
+        ComplexNumber::new(Number::from_f32(2.5), Number::from(0))
+    );
+}
+
+#[test]
+fn complex_parse_errors_point_into_the_input() {
+    let err: ParseNumberError = ComplexNumber::parse("1+2xi").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::TrailingGarbage);
+    assert_eq!(err.position, 3);
+    let err = ComplexNumber::parse("  abc").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidDigit);
+    assert_eq!(err.position, 2);
+    assert_eq!(
+        ComplexNumber::parse("").unwrap_err().kind,
+        ParseErrorKind::Empty
+    );
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "parse")]
+
+use my_r_num::{Number, ParseErrorKind};
+
+fn error_at(s: &str) -> (ParseErrorKind, usize) {
+    let err = Number::parse_currency(s).unwrap_err();
+    (err.kind, err.position)
+}
+
+#[test]
+fn group_sizes_are_validated() {
+    assert_eq!(error_at("12,3,4"), (ParseErrorKind::InvalidDigit, 4));
+    assert_eq!(error_at("$1234,567.00"), (ParseErrorKind::InvalidDigit, 5));
+    assert_eq!(error_at("1,234,56,789"), (ParseErrorKind::InvalidDigit, 8));
+    // 最后一组不足 3 位时指向它前面的分组符
+    assert_eq!(error_at("1.234.56,00 €"), (ParseErrorKind::InvalidDigit, 5));
+    assert_eq!(error_at("1,,234"), (ParseErrorKind::InvalidDigit, 2));
+}
+
+#[test]
+fn well_formed_groups_are_accepted() {
+    for (text, expected) in [
+        ("$1,234,567.89", Number::decimal(123_456_789, 2)),
+        ("1.234,50 EUR", Number::decimal(123_450, 2)),
+        ("12 345", Number::from(12_345)),
+        ("CHF 1'000'000", Number::from(1_000_000)),
+        ("¥1000", Number::from(1000)),
+    ] {
+        assert_eq!(
+            Number::parse_currency(text).unwrap().value,
+            expected,
+            "{}",
+            text
+        );
+    }
+}
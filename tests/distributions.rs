@@ -0,0 +1,90 @@
+#![cfg(feature = "stats")]
+
+use my_r_num::Number;
+use my_r_num::distributions::{Binomial, Distribution, Poisson};
+
+#[test]
+fn poisson_cdf_matches_summed_pmf() {
+    // small 特性下结果为 Float32
+    let tolerance = if cfg!(feature = "small") { 1e-6 } else { 1e-12 };
+    for lambda in [0.01, 1.0, 3.7, 42.0, 900.0] {
+        let poisson = Poisson::new(&Number::from_f64(lambda)).unwrap();
+        let mut total = 0.0;
+        for k in 0..(lambda * 3.0 + 20.0) as i32 {
+            total += poisson.pdf(&Number::from(k)).to_f64();
+            let cdf = poisson.cdf(&Number::from(k)).to_f64();
+            assert!(
+                (cdf - total).abs() <= tolerance,
+                "λ={} k={}: {} vs {}",
+                lambda,
+                k,
+                cdf,
+                total
+            );
+            assert_eq!(poisson.cdf(&Number::from_f64(k as f64 + 0.5)).to_f64(), cdf);
+        }
+    }
+}
+
+#[test]
+fn poisson_cdf_is_constant_time_for_huge_arguments() {
+    let poisson = Poisson::new(&Number::from(4)).unwrap();
+    assert_eq!(poisson.cdf(&Number::from_f64(1e18)).to_f64(), 1.0);
+    assert_eq!(poisson.cdf(&Number::from_f64(-1.0)).to_f64(), 0.0);
+    assert!(poisson.cdf(&Number::NaN).is_nan());
+    // λ 很大时 P(X <= λ) ≈ 1/2 + 2 / (3√(2πλ))
+    for lambda in [1e6, 1e12, 1e15] {
+        let poisson = Poisson::new(&Number::from_f64(lambda)).unwrap();
+        let expected = 0.5 + 2.0 / (3.0 * (2.0 * std::f64::consts::PI * lambda).sqrt());
+        let cdf = poisson.cdf(&Number::from_f64(lambda)).to_f64();
+        assert!((cdf - expected).abs() < 1e-6, "λ={}: {}", lambda, cdf);
+    }
+}
+
+#[test]
+fn binomial_cdf_matches_summed_pmf() {
+    let tolerance = if cfg!(feature = "small") { 1e-6 } else { 1e-12 };
+    for (trials, p) in [(1, 0.5), (10, 0.3), (40, 0.05), (200, 0.7)] {
+        let binomial = Binomial::new(&Number::from(trials), &Number::from_f64(p)).unwrap();
+        let mut total = 0.0;
+        for k in 0..=trials {
+            total += binomial.pdf(&Number::from(k)).to_f64();
+            let cdf = binomial.cdf(&Number::from(k)).to_f64();
+            assert!(
+                (cdf - total).abs() <= tolerance,
+                "n={} p={} k={}: {} vs {}",
+                trials,
+                p,
+                k,
+                cdf,
+                total
+            );
+            // small 特性下 q 被舍入为 Float32，不做精确检查
+            if cfg!(feature = "small") {
+                continue;
+            }
+            // 反函数返回满足 P(X <= k) >= q 的最小 k
+            let q = binomial.cdf(&Number::from(k));
+            let inverse = binomial.inverse_cdf(&q).to_f64() as i32;
+            assert!(inverse <= k, "n={} p={} k={}", trials, p, k);
+            assert!(binomial.cdf(&Number::from(inverse)) >= q);
+            if inverse > 0 {
+                assert!(binomial.cdf(&Number::from(inverse - 1)) < q);
+            }
+        }
+    }
+}
+
+#[test]
+fn binomial_handles_huge_trial_counts() {
+    let trials = 2f64.powi(31);
+    let binomial = Binomial::new(&Number::from_f64(trials), &Number::from_f64(0.5)).unwrap();
+    let median = binomial.inverse_cdf(&Number::from_f64(0.5)).to_f64();
+    assert!((median - trials / 2.0).abs() <= 1.0, "{}", median);
+    let cdf = binomial.cdf(&Number::from_f64(trials / 2.0)).to_f64();
+    assert!((cdf - 0.5).abs() < 1e-3, "{}", cdf);
+    assert_eq!(binomial.inverse_cdf(&Number::from(0)).to_f64(), 0.0);
+    let last = binomial.inverse_cdf(&Number::from(1));
+    assert!(last.to_f64() <= trials);
+    assert_eq!(binomial.cdf(&last).to_f64(), 1.0);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "expr")]
+
+use std::collections::HashMap;
+
+use my_r_num::Number;
+use my_r_num::expr::{EvalError, Expr};
+
+fn eval(source: &str) -> Result<Number, EvalError> {
+    Expr::parse(source).unwrap().eval(&HashMap::new())
+}
+
+#[test]
+fn round_accepts_extreme_digit_counts() {
+    assert_eq!(eval("ROUND(1234, -2147483648)").unwrap(), Number::from(0));
+    // small 特性下 1e300 超出 Float32 范围
+    if !cfg!(feature = "small") {
+        assert_eq!(eval("ROUND(1e300, -400)").unwrap(), Number::from(0));
+    }
+    assert_eq!(eval("ROUND(600, -3)").unwrap(), Number::from(1000));
+    assert_eq!(eval("ROUND(-1234, -2)").unwrap(), Number::from(-1200));
+}
+
+#[test]
+fn ranges_are_bounded() {
+    assert!(matches!(
+        eval("SUM(A1:A4294967295)"),
+        Err(EvalError::RangeTooLarge(range)) if range == "A1:A4294967295"
+    ));
+    assert!(
+        Expr::parse("SUM(A1:A4294967295)")
+            .unwrap()
+            .variables()
+            .is_empty()
+    );
+    let names = Expr::parse("SUM(A1:A50000) + A3 + B1").unwrap().variables();
+    assert_eq!(names.len(), 50001);
+    assert_eq!(names[..3], ["A1", "A2", "A3"]);
+    assert_eq!(names[50000], "B1");
+}
@@ -0,0 +1,15 @@
+#![cfg(all(feature = "expr", feature = "json"))]
+
+use my_r_num::Number;
+use my_r_num::expr::Expr;
+
+#[test]
+fn expr_numbers_use_the_number_serde_format() {
+    let expr = Expr::Number(Number::from(42));
+    assert_eq!(serde_json::to_string(&expr).unwrap(), r#"{"Number":42}"#);
+    let expr = Expr::parse("x * 2.5 + 1").unwrap();
+    let json = serde_json::to_string(&expr).unwrap();
+    assert!(json.contains("2.5"), "{}", json);
+    let back: Expr = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, expr);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "format")]
+
+use my_r_num::{FormatOptions, Number, NumberConfig, ScientificThreshold};
+
+fn scientific() -> NumberConfig {
+    let mut config = NumberConfig::default();
+    config.format.scientific = Some(ScientificThreshold::PYTHON);
+    config
+}
+
+#[test]
+fn format_with_keeps_the_exponent() {
+    let en = FormatOptions::locale("en").unwrap();
+    let de = FormatOptions::locale("de").unwrap();
+    let padded = FormatOptions {
+        min_fraction_digits: 2,
+        ..en.clone()
+    };
+    Number::with_config(scientific(), || {
+        let cases = [
+            (1.5e20, "1.5e20", "1,5e20", "1.50e20"),
+            (-1.2345e-7, "-1.2345e-7", "-1,2345e-7", "-1.2345e-7"),
+            (1e16, "1e16", "1e16", "1.00e16"),
+            (2.5e300, "2.5e300", "2,5e300", "2.50e300"),
+            (123456.5, "123,456.5", "123.456,5", "123,456.50"),
+        ];
+        for (value, in_en, in_de, in_padded) in cases {
+            let number = Number::Float64(value);
+            assert_eq!(number.format_with(&en), in_en);
+            assert_eq!(number.format_with(&de), in_de);
+            assert_eq!(number.format_with(&padded), in_padded);
+        }
+    });
+}
+
+#[test]
+fn default_format_keeps_the_exponent() {
+    Number::with_config(scientific(), || {
+        let _guard = Number::set_default_format(FormatOptions::locale("de").unwrap());
+        assert_eq!(Number::Float64(1.5e20).to_string(), "1,5e20");
+        assert_eq!(Number::Float64(-3.25e-9).to_string(), "-3,25e-9");
+    });
+}
@@ -0,0 +1,81 @@
+// f64-only 构建按 f64 比较，不适用
+#![cfg(not(feature = "f64-only"))]
+
+use std::cmp::Ordering;
+
+use my_r_num::Number;
+
+const TWO_53: i128 = 1 << 53;
+
+// 2^53 ± 1 转为 f64 都会舍入到相邻的偶数，经过 f64 比较会误判为相等
+fn integers(value: i128) -> Vec<Number> {
+    let mut out = vec![Number::Integer128(value)];
+    if value > 0 {
+        out.push(Number::UnsignedInteger64(value as u64));
+    }
+    #[cfg(not(feature = "small"))]
+    out.push(Number::Integer64(value as i64));
+    out
+}
+
+#[test]
+fn integers_around_two_pow_53_compare_exactly_with_floats() {
+    for sign in [1, -1] {
+        let float = Number::Float64(sign as f64 * 2f64.powi(53));
+        for (offset, expected) in [
+            (-1, Ordering::Less),
+            (0, Ordering::Equal),
+            (1, Ordering::Greater),
+        ] {
+            let value = sign * (TWO_53 + offset);
+            let expected = if sign < 0 {
+                expected.reverse()
+            } else {
+                expected
+            };
+            for integer in integers(value) {
+                assert_eq!(integer.partial_cmp(&float), Some(expected), "{:?}", integer);
+                assert_eq!(
+                    float.partial_cmp(&integer),
+                    Some(expected.reverse()),
+                    "{:?}",
+                    integer
+                );
+                assert_eq!(
+                    integer == float,
+                    expected == Ordering::Equal,
+                    "{:?}",
+                    integer
+                );
+                assert_eq!(
+                    float == integer,
+                    expected == Ordering::Equal,
+                    "{:?}",
+                    integer
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn integers_around_two_pow_24_compare_exactly_with_float32() {
+    let float = Number::Float32(2f32.powi(24));
+    assert!(Number::Integer32((1 << 24) + 1) > float);
+    assert!(Number::Integer32((1 << 24) - 1) < float);
+    assert!(Number::Integer32(1 << 24) == float);
+    assert_ne!(float, Number::Integer32((1 << 24) + 1));
+}
+
+#[test]
+fn integers_around_two_pow_53_compare_exactly_with_each_other() {
+    let above = Number::Integer128(TWO_53 + 1);
+    let below = Number::UnsignedInteger64(TWO_53 as u64 - 1);
+    assert!(above > Number::UnsignedInteger64(TWO_53 as u64));
+    assert!(below < Number::Integer128(TWO_53));
+    assert_ne!(above, Number::Integer128(TWO_53));
+    // 不是整数的浮点数落在两个整数之间
+    let half = Number::Float64(2f64.powi(51) + 0.5);
+    assert!(Number::Integer128(1 << 51) < half);
+    assert!(Number::Integer128((1 << 51) + 1) > half);
+}
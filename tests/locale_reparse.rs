@@ -0,0 +1,62 @@
+#![cfg(feature = "parse")]
+
+use my_r_num::{Number, NumberConfig, ParseConfig};
+
+fn de() -> NumberConfig {
+    NumberConfig {
+        parse: ParseConfig::locale("de").unwrap(),
+        ..NumberConfig::default()
+    }
+}
+
+#[test]
+fn sniff_ignores_the_thread_locale() {
+    let (plain_1_5, plain_12_5, plain_1_234) = (
+        Number::sniff("1,5"),
+        Number::sniff("12.5"),
+        Number::sniff("1.234"),
+    );
+    Number::with_config(de(), || {
+        assert_eq!(Number::sniff("1,5"), plain_1_5);
+        assert_eq!(Number::sniff("12.5"), plain_12_5);
+        assert_eq!(Number::sniff("1.234"), plain_1_234);
+    });
+    assert_eq!(plain_1_5[0].value, Number::parse("1.5").unwrap());
+    assert_eq!(plain_12_5[0].value, Number::parse("12.5").unwrap());
+    assert!(
+        plain_1_234
+            .iter()
+            .any(|c| c.decimal_separator == Some('.') && c.value == Number::parse("1.234").unwrap())
+    );
+}
+
+#[test]
+fn parse_currency_ignores_the_thread_locale() {
+    for config in [NumberConfig::default(), de()] {
+        Number::with_config(config, || {
+            let amount = Number::parse_currency("1.234,56 €").unwrap();
+            assert!(matches!(amount.value, Number::Decimal(123456, 2)));
+            assert_eq!(amount.currency.as_deref(), Some("EUR"));
+            let amount = Number::parse_currency("$1,234.50").unwrap();
+            assert!(matches!(amount.value, Number::Decimal(123450, 2)));
+            let amount = Number::parse_currency("(45.00)").unwrap();
+            assert!(matches!(amount.value, Number::Decimal(-4500, 2)));
+            let amount = Number::parse_currency("¥1000").unwrap();
+            assert!(matches!(amount.value, Number::Decimal(1000, 0)));
+        });
+    }
+}
+
+#[cfg(feature = "format")]
+#[test]
+fn parse_chinese_ignores_the_thread_locale() {
+    let expected = Number::parse_chinese("一点五").unwrap();
+    Number::with_config(de(), || {
+        assert_eq!(Number::parse_chinese("一点五").unwrap(), expected);
+        assert_eq!(
+            Number::parse_chinese("负十二点二五").unwrap(),
+            Number::parse("-12,25").unwrap()
+        );
+    });
+    assert_eq!(expected, Number::parse("1.5").unwrap());
+}
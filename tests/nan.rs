@@ -0,0 +1,52 @@
+use my_r_num::{NanOrigin, Number, NumberConfig};
+
+fn invalid_ops() -> Vec<(Number, NanOrigin)> {
+    let mut rem = Number::from(1);
+    rem %= Number::from(0);
+    vec![
+        (
+            Number::from(0) / Number::Float32(0.0),
+            NanOrigin::DivZeroZero,
+        ),
+        (
+            Number::PositiveInfinity + Number::NegativeInfinity,
+            NanOrigin::InfMinusInf,
+        ),
+        (
+            Number::from(0) * Number::NegativeInfinity,
+            NanOrigin::ZeroTimesInf,
+        ),
+        (
+            Number::PositiveInfinity / Number::NegativeInfinity,
+            NanOrigin::InfDivInf,
+        ),
+        (rem, NanOrigin::RemByZero),
+    ]
+}
+
+#[test]
+fn invalid_ops_return_plain_nan_by_default() {
+    for (result, _) in invalid_ops() {
+        assert!(result.eq_exact(&Number::NaN), "{:?}", result);
+        assert_eq!(result.nan_origin(), Some(NanOrigin::Unspecified));
+    }
+}
+
+#[test]
+fn invalid_ops_record_their_origin_on_request() {
+    let config = NumberConfig {
+        nan_origins: true,
+        ..NumberConfig::default()
+    };
+    for (result, origin) in Number::with_config(config, invalid_ops) {
+        assert!(matches!(result, Number::Float64(v) if v.is_nan()));
+        assert_eq!(result.nan_origin(), Some(origin));
+    }
+}
+
+#[test]
+fn payloads_propagate_without_the_option() {
+    let tagged = Number::nan_with_origin(NanOrigin::InfDivInf);
+    let result = tagged + Number::from(1);
+    assert_eq!(result.nan_origin(), Some(NanOrigin::InfDivInf));
+}
@@ -0,0 +1,51 @@
+use my_r_num::Number;
+
+#[test]
+fn integer_overflow_follows_the_operand_width() {
+    let max32 = Number::Integer32(i32::MAX);
+    let one = Number::from(1);
+    // small 构建的范围总是 i32
+    if cfg!(feature = "small") {
+        assert!(max32.will_overflow_add(&one));
+        assert!(Number::Integer32(i32::MIN).will_overflow_sub(&one));
+        assert!(max32.will_overflow_mul(&Number::from(2)));
+        return;
+    }
+    assert!(!max32.will_overflow_add(&one));
+    assert!(!max32.will_overflow_mul(&max32));
+    let max64 = Number::UnsignedInteger64(u64::MAX);
+    assert!(max64.will_overflow_add(&one));
+    assert!(!max64.will_overflow_sub(&one));
+    assert!(max64.will_overflow_mul(&Number::from(2)));
+    // Integer128 参与时范围为 i128
+    let max128 = Number::Integer128(i128::MAX);
+    assert!(!max128.will_overflow_sub(&one));
+    assert!(max128.will_overflow_add(&one));
+    assert!(Number::Integer128(i128::MIN).will_overflow_mul(&Number::from(-1)));
+    let wide = Number::Integer128(u64::MAX as i128);
+    assert!(!wide.will_overflow_add(&one));
+    assert!(!wide.will_overflow_mul(&Number::from(2)));
+}
+
+#[cfg(not(feature = "small"))]
+#[test]
+fn integer64_overflow_is_reported_before_promotion() {
+    let max = Number::Integer64(i64::MAX);
+    let min = Number::Integer64(i64::MIN);
+    let one = Number::from(1);
+    assert!(max.will_overflow_add(&one));
+    assert!(!max.will_overflow_sub(&one));
+    assert!(min.will_overflow_sub(&one));
+    assert!(min.will_overflow_mul(&Number::from(-1)));
+    assert!(!min.will_overflow_add(&max));
+}
+
+#[test]
+fn float_overflow_checks_for_infinity() {
+    let big = Number::Float32(f32::MAX);
+    assert!(!big.will_overflow_add(&Number::Float32(1.0)));
+    assert!(!big.will_overflow_mul(&Number::Float32(2.0)));
+    assert!(!Number::PositiveInfinity.will_overflow_mul(&Number::Float32(2.0)));
+    assert!(Number::Float64(f64::MAX).will_overflow_mul(&Number::Float32(2.0)));
+    assert!(Number::Float64(f64::MAX).will_overflow_add(&Number::Float64(f64::MAX)));
+}
@@ -0,0 +1,110 @@
+use std::hint::black_box;
+
+use my_r_num::{CoerceError, Number, PackedNumber};
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+    // 随机宽度的有符号整数，让小值和大值都经常出现
+    fn int(&mut self) -> i64 {
+        let shift = self.next() % 64;
+        (self.next() as i64) >> shift
+    }
+}
+
+fn random_number(rng: &mut Rng) -> Number {
+    match rng.next() % 14 {
+        0 => Number::PositiveInfinity,
+        1 => Number::NegativeInfinity,
+        2 => Number::NaN,
+        3 => Number::rational(rng.int(), rng.int()),
+        4 => Number::Decimal(rng.int() as i128, (rng.next() % 39) as u32),
+        5 => Number::Fixed64 {
+            raw: rng.int(),
+            frac_bits: (rng.next() % 64) as u8,
+        },
+        6 => Number::Integer128((rng.int() as i128) << (rng.next() % 64)),
+        7 => Number::UnsignedInteger64(rng.int() as u64),
+        #[cfg(not(feature = "small"))]
+        8 => Number::Integer64(rng.int()),
+        9 => Number::Integer32(rng.next() as i32),
+        10 => Number::Integer16(rng.next() as i16),
+        11 => Number::Integer8(rng.next() as i8),
+        12 => Number::Float64(f64::from_bits(rng.next())),
+        _ => Number::Float32(f32::from_bits(rng.next() as u32)),
+    }
+}
+
+#[test]
+fn packing_is_lossless() {
+    assert_eq!(std::mem::size_of::<PackedNumber>(), 8);
+    let mut rng = Rng(0x5851_f42d_4c95_7f2d);
+    let mut packed = 0;
+    for _ in 0..200_000 {
+        let number = random_number(&mut rng);
+        let (bytes, debug) = (number.to_le_bytes(), format!("{:?}", number));
+        match PackedNumber::try_from(number) {
+            Ok(value) => {
+                packed += 1;
+                assert_eq!(value.to_number().to_le_bytes(), bytes, "{}", debug);
+                assert_eq!(format!("{:?}", value), debug);
+            }
+            Err(err) => assert!(
+                matches!(err, CoerceError::OutOfRange | CoerceError::Unsupported),
+                "{}: {:?}",
+                debug,
+                err
+            ),
+        }
+    }
+    assert!(packed > 150_000, "only {} values packed", packed);
+}
+
+#[test]
+fn limits_and_nan_payloads() {
+    let packs = |number: Number| PackedNumber::try_from(number).is_ok();
+    assert!(packs(Number::Integer128((1 << 45) - 1)));
+    assert!(packs(Number::Integer128(-(1 << 45))));
+    assert!(!packs(Number::Integer128(1 << 45)));
+    assert!(packs(Number::Rational(-1, 3)));
+    assert!(!packs(Number::Rational(1, 1 << 22)));
+    assert!(packs(Number::Decimal(-123456, 38)));
+    assert!(packs(Number::Fixed64 {
+        raw: 1,
+        frac_bits: 63
+    }));
+    // 常见的 NaN 原样保存，与装箱区重叠的 NaN 负载无法保存
+    for nan in [f64::NAN, -f64::NAN, black_box(0.0f64) / black_box(0.0)] {
+        let value = PackedNumber::from(nan);
+        assert_eq!(value.to_bits(), nan.to_bits());
+        assert!(packs(Number::Float64(nan)));
+    }
+    let boxed_nan = f64::from_bits(0x7ffc_0000_0000_0001);
+    assert_eq!(
+        PackedNumber::try_from(Number::Float64(boxed_nan)).err(),
+        Some(CoerceError::Unsupported)
+    );
+    assert_eq!(PackedNumber::from(boxed_nan).to_bits(), f64::NAN.to_bits());
+}
+
+#[test]
+fn arithmetic_goes_through_number() {
+    let a = PackedNumber::from(7);
+    let b = PackedNumber::from(2);
+    assert_eq!((a + b).to_number(), Number::from(9));
+    assert_eq!((a / b).to_number(), Number::from(7) / Number::from(2));
+    let mut sum = PackedNumber::from(0.5);
+    sum += PackedNumber::from(0.25);
+    assert_eq!(sum.to_number(), Number::from_f64(0.75));
+    // 放不下的结果降为浮点数（small 特性下乘法本身已经得到浮点数）
+    let big = PackedNumber::try_from(Number::Integer128(1 << 44)).unwrap();
+    let product = (big * big).to_number();
+    assert!(!matches!(product, Number::Integer128(_)), "{:?}", product);
+    assert_eq!(product.to_f64(), 2f64.powi(88));
+}
@@ -0,0 +1,13 @@
+#![cfg(feature = "parse")]
+#![allow(deprecated)]
+
+use my_r_num::Number;
+
+#[test]
+fn legacy_parse_keeps_the_string_error() {
+    assert_eq!(Number::parse_legacy("42"), Ok(Number::from(42)));
+    assert_eq!(
+        Number::parse_legacy("12abc"),
+        Err("Cannot parse '12abc' as a number".to_string())
+    );
+}
@@ -0,0 +1,24 @@
+use my_r_num::Number;
+
+#[test]
+fn abs_keeps_nan_unchanged() {
+    let negative_nan = Number::Float64(-f64::NAN);
+    assert!(negative_nan.abs().eq_exact(&negative_nan));
+    let negative_nan = Number::Float32(-f32::NAN);
+    assert!(negative_nan.abs().eq_exact(&negative_nan));
+    assert!(Number::NaN.abs().eq_exact(&Number::NaN));
+    assert!(Number::Float64(-0.0).abs().eq_exact(&Number::Float64(0.0)));
+    assert!(
+        Number::NegativeInfinity
+            .abs()
+            .eq_exact(&Number::PositiveInfinity)
+    );
+}
+
+#[test]
+fn copysign_still_flips_the_nan_sign_bit() {
+    let positive = Number::Float64(-f64::NAN).copysign(&Number::from(1));
+    assert!(matches!(positive, Number::Float64(v) if v.is_nan() && v.is_sign_positive()));
+    let negative = Number::Float32(f32::NAN).copysign(&Number::Float64(-0.0));
+    assert!(matches!(negative, Number::Float32(v) if v.is_nan() && v.is_sign_negative()));
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "small")]
+
+use my_r_num::{Number, NumberConfig, OverflowPolicy};
+
+fn with_policy(overflow: OverflowPolicy, value: f64) -> Number {
+    let config = NumberConfig {
+        overflow,
+        ..NumberConfig::default()
+    };
+    Number::with_config(config, || Number::from_float(value))
+}
+
+#[test]
+fn non_finite_floats_use_the_infinity_variants() {
+    assert!(Number::from_float(f64::INFINITY).eq_exact(&Number::PositiveInfinity));
+    assert!(Number::from_float(f64::NEG_INFINITY).eq_exact(&Number::NegativeInfinity));
+    assert!(matches!(Number::from_float(f64::NAN), Number::Float32(v) if v.is_nan()));
+    assert!(Number::from_float(0.1).eq_exact(&Number::Float32(0.1)));
+}
+
+#[test]
+fn out_of_range_floats_follow_the_overflow_policy() {
+    for policy in [OverflowPolicy::PromoteWide, OverflowPolicy::PromoteFloat] {
+        assert!(with_policy(policy, 1e300).eq_exact(&Number::PositiveInfinity));
+        assert!(with_policy(policy, -1e300).eq_exact(&Number::NegativeInfinity));
+    }
+    assert!(with_policy(OverflowPolicy::Saturate, 1e300).eq_exact(&Number::Float32(f32::MAX)));
+    assert!(with_policy(OverflowPolicy::Saturate, -1e300).eq_exact(&Number::Float32(f32::MIN)));
+    assert!(with_policy(OverflowPolicy::Error, 1e300).eq_exact(&Number::NaN));
+    // 非有限值本身不算溢出
+    assert!(with_policy(OverflowPolicy::Error, f64::INFINITY).eq_exact(&Number::PositiveInfinity));
+}
+
+#[test]
+fn const_from_f64_stores_float32() {
+    const TABLE: [Number; 3] = [
+        Number::from_f64(0.5),
+        Number::from_f64(1e300),
+        Number::from_f64(f64::NEG_INFINITY),
+    ];
+    assert!(TABLE[0].eq_exact(&Number::Float32(0.5)));
+    assert!(TABLE[1].eq_exact(&Number::PositiveInfinity));
+    assert!(TABLE[2].eq_exact(&Number::NegativeInfinity));
+    assert!(Number::from_f64(f64::NAN).eq_exact(&Number::NaN));
+    assert!(Number::from_f64(-1e300).eq_exact(&Number::NegativeInfinity));
+}
+
+#[test]
+fn wide_integers_narrow_to_float32() {
+    assert_eq!(Number::from(i32::MAX).type_name(), "Integer32");
+    assert_eq!(Number::from(u32::MAX).type_name(), "Float32");
+}
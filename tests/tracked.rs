@@ -0,0 +1,36 @@
+use my_r_num::Number;
+
+#[test]
+fn rem_tracked_detects_a_rounded_operand() {
+    let big = Number::Integer128((1 << 60) + 1);
+    let result = big.rem_tracked(&Number::from(3.0f32));
+    assert!(!result.exact, "{:?}", result);
+    let result = Number::from(7).rem_tracked(&Number::from(-3));
+    assert!(
+        result.exact && result.value == Number::from(1),
+        "{:?}",
+        result
+    );
+    let result = Number::from(5.5f32).rem_tracked(&Number::from(2));
+    assert!(
+        result.exact && result.value == Number::from(1.5f32),
+        "{:?}",
+        result
+    );
+}
+
+#[cfg(not(feature = "small"))]
+#[test]
+fn rem_tracked_from_the_review() {
+    let result = Number::from((1i64 << 60) + 1).rem_tracked(&Number::from(3.0f32));
+    assert!(!result.exact, "{:?}", result);
+}
+
+// f64-only 构建的分数取余经过 f64，本来就不精确
+#[cfg(not(feature = "f64-only"))]
+#[test]
+fn rem_tracked_keeps_exact_rational_remainders() {
+    let result = Number::rational(-7, 2).rem_tracked(&Number::rational(4, 3));
+    assert!(result.exact, "{:?}", result);
+    assert_eq!(result.value, Number::rational(-5, 6));
+}
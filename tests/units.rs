@@ -0,0 +1,53 @@
+#![cfg(feature = "units")]
+
+use my_r_num::Number;
+use my_r_num::units::{Quantity, Unit, UnitError};
+
+#[test]
+fn huge_exponents_are_rejected() {
+    let unit = Unit::parse("m^1000").unwrap();
+    assert_eq!(unit.powi(2), Err(UnitError::ExponentOverflow));
+    assert_eq!(unit.mul(&unit), Err(UnitError::ExponentOverflow));
+    assert_eq!(
+        Unit::parse("m^2000000000").err(),
+        Some(UnitError::ExponentOverflow)
+    );
+    assert_eq!(
+        Unit::parse("m^-2147483648").err(),
+        Some(UnitError::ExponentOverflow)
+    );
+}
+
+#[test]
+fn large_exponents_convert_without_looping() {
+    let from = Unit::parse("km^300").unwrap();
+    let to = Unit::parse("m^300").unwrap();
+    let q = Quantity::new(Number::from(1), from)
+        .convert_to(&to)
+        .unwrap();
+    assert!(q.value.to_f64().is_infinite() || q.value.to_f64() > 1e300);
+    let back = Quantity::new(Number::from(1), Unit::parse("km^-2").unwrap())
+        .convert_to(&Unit::parse("m^-2").unwrap())
+        .unwrap();
+    assert!((back.value.to_f64() - 1e-6).abs() < 1e-12);
+}
+
+#[cfg(feature = "expr")]
+#[test]
+fn power_overflow_in_expressions_is_an_error() {
+    use std::collections::HashMap;
+
+    use my_r_num::expr::{EvalError, Expr};
+
+    let err = Expr::parse("(m^1000)^2")
+        .unwrap()
+        .eval_quantity(&HashMap::new())
+        .unwrap_err();
+    assert_eq!(err, EvalError::Unit(UnitError::ExponentOverflow));
+    assert!(
+        Expr::parse("(m^2000000000)^2")
+            .unwrap()
+            .eval_quantity(&HashMap::new())
+            .is_err()
+    );
+}